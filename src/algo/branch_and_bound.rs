@@ -0,0 +1,363 @@
+use super::super::forms::options::{MilpOptions, NodeSelection};
+use super::super::forms::slack::{
+    Budget, InitializationResult, Interruption, SimplexResult, SlackFormLP,
+};
+use super::super::forms::standard::StandardFormLP;
+use super::super::types::error::SolverError;
+use super::branching::{BranchingRule, Pseudocosts};
+use super::gomory::gomory_rounds_within;
+
+/// Best integer solution found by the branch and bound.
+///
+/// objective_value is the objective of x, bound is the best objective value
+/// that an integer solution could still reach (both with the sign of the
+/// objective given to the builder, min or max). gap is the relative MIP gap:
+/// |bound - objective_value| / max(|objective_value|, 1.0), 0.0 once the
+/// solution is proven optimal. nodes is the number of solved relaxations.
+#[derive(Debug, PartialEq, Clone)]
+pub struct MilpSolution {
+    x: Vec<f64>,
+    objective_value: f64,
+    bound: f64,
+    gap: f64,
+    nodes: usize,
+}
+
+impl MilpSolution {
+    pub fn get_x(&self) -> &Vec<f64> { &self.x }
+
+    pub fn get_objective_value(&self) -> f64 { self.objective_value }
+
+    pub fn get_bound(&self) -> f64 { self.bound }
+
+    pub fn get_gap(&self) -> f64 { self.gap }
+
+    pub fn get_nodes(&self) -> usize { self.nodes }
+}
+
+/// Outcome of the branch and bound.
+///
+/// Unbounded means that the linear relaxation is unbounded: the integer
+/// program is then either unbounded or unfeasible. An interrupted search
+/// gives back the best integer solution found so far, if any.
+#[derive(Debug, PartialEq)]
+pub enum MilpResult {
+    Optimal(MilpSolution),
+    Unfeasible,
+    Unbounded,
+    Interrupted(Interruption, Option<MilpSolution>),
+}
+
+impl NodeSelection {
+    /// Index of the next node to process, 'open' must not be empty
+    fn select(self, open: &[Node], has_incumbent: bool) -> usize {
+        match self {
+            NodeSelection::DepthFirst => open.len() - 1,
+            NodeSelection::BestBound => select_max(open, |node| node.bound),
+            NodeSelection::BestEstimate => {
+                select_max(open, |node| node.estimate)
+            },
+            NodeSelection::Hybrid if has_incumbent => {
+                select_max(open, |node| node.bound)
+            },
+            NodeSelection::Hybrid => open.len() - 1,
+        }
+    }
+}
+
+/// Last node with the greatest score
+fn select_max<F>(open: &[Node], score: F) -> usize
+where
+    F: Fn(&Node) -> f64,
+{
+    let mut best = open.len() - 1;
+    for (index, node) in open.iter().enumerate().rev() {
+        if score(node) > score(&open[best]) {
+            best = index;
+        }
+    }
+
+    best
+}
+
+/// Open node of the search tree: the dictionary of its parent, with the
+/// bounds of the node, the objective value of the parent relaxation (bound),
+/// the estimate of its best integer solution and the bound change which
+/// created it (None for the root).
+struct Node {
+    slack_lp: SlackFormLP,
+    bound: f64,
+    estimate: f64,
+    change: Option<BoundChange>,
+}
+
+/// Bound change of a node: the variable, the direction (up for x_j >=
+/// ceil(v)) and the distance between v and the new bound.
+struct BoundChange {
+    index: usize,
+    up: bool,
+    distance: f64,
+}
+
+/// Branch and bound on the integer indices of the linear program.
+///
+/// The relaxation of each node is solved by the simplex: the root one from
+/// scratch, the others by the dual simplex from the optimal dictionary of
+/// their parent (warm start). The search branches on a fractional integer
+/// variable x_j = v, chosen by the branching rule of the MILP options (the
+/// most fractional one by default):
+/// ```ignore
+///     x_j <= floor(v)    or    x_j >= ceil(v)
+/// ```
+/// The branches are variable bounds, not rows. The nodes are processed in
+/// the order of the node selection of the MILP options, and a node is pruned
+/// once its bound can not improve the incumbent by more than their MIP gap
+/// tolerance. With their cut rounds, Gomory mixed integer cuts tighten the
+/// relaxation of each node (see algo::gomory).
+///
+/// The relaxations are solved with the solver options of the linear program,
+/// whose limits apply to the whole search.
+pub fn branch_and_bound(
+    standard_lp: &StandardFormLP,
+    options: &MilpOptions,
+) -> Result<MilpResult, SolverError> {
+    let mut rule = options.get_branching().into_rule();
+    branch_and_bound_with(standard_lp, options, rule.as_mut())
+}
+
+/// Branch and bound with a given branching rule (see branch_and_bound).
+pub fn branch_and_bound_with(
+    standard_lp: &StandardFormLP,
+    options: &MilpOptions,
+    rule: &mut dyn BranchingRule,
+) -> Result<MilpResult, SolverError> {
+    let integers = standard_lp.get_integer_indices();
+    let c = standard_lp.get_c();
+
+    // Only integer values are left between the bounds of the integer
+    // variables
+    let mut root = standard_lp.clone().into_slack_form()?;
+    for j in integers.iter() {
+        let lower = root.get_lower()[*j].ceil();
+        let upper = root.get_upper()[*j].floor();
+        if lower > upper {
+            return Ok(MilpResult::Unfeasible);
+        }
+        root.set_variable_bounds(*j, lower, upper)?;
+    }
+
+    let mut budget = Budget::new();
+    let mut pseudocosts = Pseudocosts::new(c.len());
+    let mut open = vec![Node {
+        slack_lp: root,
+        bound: f64::INFINITY,
+        estimate: f64::INFINITY,
+        change: None,
+    }];
+    // Objective of the incumbent and the best bound of the pruned nodes
+    // (slack form, max objective)
+    let mut incumbent: Option<(Vec<f64>, f64)> = None;
+    let mut pruned_bound = f64::NEG_INFINITY;
+    let mut nodes = 0;
+
+    while !open.is_empty() {
+        let index =
+            options.get_node_selection().select(&open, incumbent.is_some());
+        let mut node = open.remove(index);
+        if let Some((_, objective)) = &incumbent {
+            if !improves(
+                node.bound,
+                *objective,
+                options.get_mip_gap_tolerance(),
+            ) {
+                pruned_bound = pruned_bound.max(node.bound);
+                continue;
+            }
+        }
+
+        let solution = match solve_relaxation(
+            &mut node.slack_lp,
+            integers,
+            options,
+            &mut budget,
+        )? {
+            SimplexResult::Optimal(solution) => solution,
+            SimplexResult::Unfeasible(_) => {
+                nodes += 1;
+                continue;
+            },
+            SimplexResult::Unbounded(..) => return Ok(MilpResult::Unbounded),
+            SimplexResult::Interrupted(interruption, _) => {
+                let bound = open
+                    .iter()
+                    .map(|node| node.bound)
+                    .fold(node.bound.max(pruned_bound), f64::max);
+                let solution = incumbent.map(|(x, objective)| {
+                    milp_solution(standard_lp, x, objective, bound, nodes)
+                });
+
+                return Ok(MilpResult::Interrupted(interruption, solution));
+            },
+        };
+        nodes += 1;
+
+        let objective = solution.get_objective_value();
+        if let Some(change) = &node.change {
+            pseudocosts.update(
+                change.index,
+                change.up,
+                change.distance,
+                node.bound - objective,
+            );
+        }
+        if let Some((_, incumbent_objective)) = &incumbent {
+            if !improves(
+                objective,
+                *incumbent_objective,
+                options.get_mip_gap_tolerance(),
+            ) {
+                pruned_bound = pruned_bound.max(objective);
+                continue;
+            }
+        }
+
+        let x = solution.get_x();
+        let tolerance = options.get_integrality_tolerance();
+        let candidates: Vec<(usize, f64)> = integers
+            .iter()
+            .filter(|j| (x[**j] - x[**j].round()).abs() > tolerance)
+            .map(|j| (*j, x[*j]))
+            .collect();
+        if candidates.is_empty() {
+            let mut x = x.clone();
+            for j in integers.iter() {
+                x[*j] = x[*j].round();
+            }
+            let objective = c.iter().zip(x.iter()).map(|(c, x)| c * x).sum();
+            incumbent = Some((x, objective));
+            continue;
+        }
+
+        let choice = rule.select(&node.slack_lp, &candidates, &mut pseudocosts);
+        let (j, value) = candidates[choice];
+        let lower = node.slack_lp.get_lower()[j];
+        let upper = node.slack_lp.get_upper()[j];
+        let (down_degradation, up_degradation) =
+            pseudocosts.degradations(j, value);
+        let estimate = objective
+            - candidates
+                .iter()
+                .map(|(k, value)| {
+                    let (down, up) = pseudocosts.degradations(*k, *value);
+                    down.min(up)
+                })
+                .sum::<f64>()
+            + down_degradation.min(up_degradation);
+
+        let mut down = node.slack_lp.clone();
+        down.set_variable_bounds(j, lower, value.floor())?;
+        let mut up = node.slack_lp;
+        up.set_variable_bounds(j, value.ceil(), upper)?;
+
+        open.push(Node {
+            slack_lp: up,
+            bound: objective,
+            estimate: estimate - up_degradation,
+            change: Some(BoundChange {
+                index: j,
+                up: true,
+                distance: value.ceil() - value,
+            }),
+        });
+        open.push(Node {
+            slack_lp: down,
+            bound: objective,
+            estimate: estimate - down_degradation,
+            change: Some(BoundChange {
+                index: j,
+                up: false,
+                distance: value - value.floor(),
+            }),
+        });
+    }
+
+    #[cfg(feature = "log")]
+    log::debug!("Branch and bound: {} nodes", nodes);
+
+    Ok(match incumbent {
+        Some((x, objective)) => {
+            let bound = pruned_bound.max(objective);
+            MilpResult::Optimal(milp_solution(
+                standard_lp,
+                x,
+                objective,
+                bound,
+                nodes,
+            ))
+        },
+        None => MilpResult::Unfeasible,
+    })
+}
+
+/// Optimal solution of the relaxation of a node. The dual simplex is used as
+/// long as the dictionary is dual feasible, the two phases of the primal
+/// simplex otherwise (the root node for example). The rounds of Gomory cuts
+/// of the MILP options are added to the optimal relaxation, they are inherited by
+/// the children of the node.
+fn solve_relaxation(
+    slack_lp: &mut SlackFormLP,
+    integers: &[usize],
+    options: &MilpOptions,
+    budget: &mut Budget,
+) -> Result<SimplexResult, SolverError> {
+    let result = if slack_lp.is_dual_feasible() {
+        slack_lp.find_dual_optimal_within(budget)?
+    } else {
+        match slack_lp.initialize_simplex_within(budget)? {
+            InitializationResult::Done => {},
+            InitializationResult::Unfeasible(certificate) => {
+                return Ok(SimplexResult::Unfeasible(certificate));
+            },
+            InitializationResult::Interrupted(interruption, point) => {
+                return Ok(SimplexResult::Interrupted(interruption, point));
+            },
+        }
+
+        let mut rule = slack_lp.get_options().get_pricing().into_rule();
+        slack_lp.find_optimal_within(rule.as_mut(), budget)?
+    };
+
+    let rounds = options.get_cut_rounds();
+    match result {
+        SimplexResult::Optimal(_) if rounds > 0 => {
+            gomory_rounds_within(slack_lp, integers, rounds, options, budget)
+        },
+        result => Ok(result),
+    }
+}
+
+/// Whether a node with this bound may improve the incumbent by more than the
+/// relative gap tolerance
+fn improves(bound: f64, incumbent: f64, tolerance: f64) -> bool {
+    bound - incumbent > tolerance * incumbent.abs().max(1.)
+}
+
+/// Solution with the sign of the objective given to the builder.
+fn milp_solution(
+    standard_lp: &StandardFormLP,
+    x: Vec<f64>,
+    objective: f64,
+    bound: f64,
+    nodes: usize,
+) -> MilpSolution {
+    let gap = (bound - objective).abs() / objective.abs().max(1.);
+    let sign = if standard_lp.is_min_objective() { -1. } else { 1. };
+
+    MilpSolution {
+        x,
+        objective_value: sign * objective,
+        bound: sign * bound,
+        gap,
+        nodes,
+    }
+}
@@ -0,0 +1,284 @@
+use super::super::forms::options::Branching;
+use super::super::forms::slack::{SimplexResult, SlackFormLP};
+
+/// Degradation of the objective below which a branch is taken as free, so
+/// that the product score still compares the other branch.
+const MIN_DEGRADATION: f64 = 1e-6;
+
+/// Rule choosing the variable the branch and bound branches on.
+///
+/// The candidates are the integer variables with a fractional value in the
+/// optimal relaxation of the node, given as (index, value). slack_lp holds
+/// the optimal dictionary of the node, and the pseudocosts are the ones
+/// learned so far by the search.
+pub trait BranchingRule {
+    /// Index of the chosen candidate
+    fn select(
+        &mut self,
+        slack_lp: &SlackFormLP,
+        candidates: &[(usize, f64)],
+        pseudocosts: &mut Pseudocosts,
+    ) -> usize;
+}
+
+impl Branching {
+    /// Rule of the branch and bound implementing this branching.
+    pub fn into_rule(self) -> Box<dyn BranchingRule> {
+        match self {
+            Branching::MostFractional => Box::new(MostFractional),
+            Branching::Pseudocost => Box::new(PseudocostBranching),
+            Branching::Strong { iterations } => {
+                Box::new(StrongBranching { iterations })
+            },
+            Branching::Reliability { iterations, reliability } => {
+                Box::new(ReliabilityBranching { iterations, reliability })
+            },
+        }
+    }
+}
+
+/// Pseudocosts of the integer variables: average degradation of the
+/// objective per unit change of a variable, when it is rounded down and up.
+/// A variable which has not been rounded yet in a direction gets the average
+/// pseudocost of the others (1.0 if there is none).
+#[derive(Debug, PartialEq, Clone)]
+pub struct Pseudocosts {
+    down: Vec<(f64, usize)>, // sum of the degradations per unit, count
+    up: Vec<(f64, usize)>,
+}
+
+impl Pseudocosts {
+    /// Pseudocosts of n variables, without any observation
+    pub fn new(n: usize) -> Pseudocosts {
+        Pseudocosts { down: vec![(0., 0); n], up: vec![(0., 0); n] }
+    }
+
+    /// Record the degradation of the objective when x_index is moved by
+    /// 'distance' (up or down) to its new bound
+    pub fn update(
+        &mut self,
+        index: usize,
+        up: bool,
+        distance: f64,
+        degradation: f64,
+    ) {
+        let costs = if up { &mut self.up } else { &mut self.down };
+        let (sum, count) = &mut costs[index];
+        *sum += degradation.max(0.) / distance;
+        *count += 1;
+    }
+
+    /// Number of observations of x_index in a direction
+    pub fn get_count(&self, index: usize, up: bool) -> usize {
+        if up {
+            self.up[index].1
+        } else {
+            self.down[index].1
+        }
+    }
+
+    pub fn get(&self, index: usize, up: bool) -> f64 {
+        let costs = if up { &self.up } else { &self.down };
+        let (sum, count) = costs[index];
+        if count > 0 {
+            return sum / count as f64;
+        }
+
+        let (sum, count) = costs
+            .iter()
+            .filter(|(_, count)| *count > 0)
+            .fold((0., 0), |(s, c), (sum, count)| {
+                (s + sum / *count as f64, c + 1)
+            });
+        if count > 0 {
+            sum / count as f64
+        } else {
+            1.
+        }
+    }
+
+    /// Expected degradations (down, up) when x_index = value is rounded
+    pub fn degradations(&self, index: usize, value: f64) -> (f64, f64) {
+        (
+            self.get(index, false) * (value - value.floor()),
+            self.get(index, true) * (value.ceil() - value),
+        )
+    }
+}
+
+/// Product score of the degradations of both branches: the best variable
+/// degrades the objective of both children.
+fn score((down, up): (f64, f64)) -> f64 {
+    down.max(MIN_DEGRADATION) * up.max(MIN_DEGRADATION)
+}
+
+/// Candidate with the biggest score, ties are broken with the first one.
+fn select_max<F>(candidates: &[(usize, f64)], mut score: F) -> usize
+where
+    F: FnMut(usize, f64) -> f64,
+{
+    let mut best = 0;
+    let mut best_score = f64::NEG_INFINITY;
+    for (index, (j, value)) in candidates.iter().enumerate() {
+        let value = score(*j, *value);
+        if value > best_score {
+            best = index;
+            best_score = value;
+        }
+    }
+
+    best
+}
+
+/// Degradations (down, up) of the objective of the node, found by solving
+/// both children with the dual simplex, within 'iterations' pivots each. An
+/// unfinished child gives the objective value of its last dual feasible
+/// basis, which is still an upper bound of its relaxation, and an unfeasible
+/// child an infinite degradation. A trial which fails falls back on the
+/// pseudocost estimate of its direction. The pseudocosts record the
+/// degradations of the feasible children.
+fn strong_degradations(
+    slack_lp: &SlackFormLP,
+    index: usize,
+    value: f64,
+    iterations: usize,
+    pseudocosts: &mut Pseudocosts,
+) -> (f64, f64) {
+    let lower = slack_lp.get_lower()[index];
+    let upper = slack_lp.get_upper()[index];
+    let (estimate_down, estimate_up) = pseudocosts.degradations(index, value);
+
+    let down =
+        trial_degradation(slack_lp, index, lower, value.floor(), iterations);
+    let up =
+        trial_degradation(slack_lp, index, value.ceil(), upper, iterations);
+
+    if let Some(down) = down.filter(|d| d.is_finite()) {
+        pseudocosts.update(index, false, value - value.floor(), down);
+    }
+    if let Some(up) = up.filter(|u| u.is_finite()) {
+        pseudocosts.update(index, true, value.ceil() - value, up);
+    }
+
+    (down.unwrap_or(estimate_down), up.unwrap_or(estimate_up))
+}
+
+fn trial_degradation(
+    slack_lp: &SlackFormLP,
+    index: usize,
+    lower: f64,
+    upper: f64,
+    iterations: usize,
+) -> Option<f64> {
+    let mut trial = slack_lp.clone();
+    trial.set_options(
+        slack_lp.get_options().clone().set_iteration_limit(iterations),
+    );
+    if trial.set_variable_bounds(index, lower, upper).is_err()
+        || !trial.is_dual_feasible()
+    {
+        return None;
+    }
+
+    match trial.find_dual_optimal() {
+        Ok(SimplexResult::Unfeasible(_)) => Some(f64::INFINITY),
+        Ok(_) => Some(slack_lp.get_v() - trial.get_v()),
+        Err(_) => None,
+    }
+}
+
+/// Most fractional variable: the farthest from an integer value.
+#[derive(Debug, Default)]
+pub struct MostFractional;
+
+impl BranchingRule for MostFractional {
+    fn select(
+        &mut self,
+        _slack_lp: &SlackFormLP,
+        candidates: &[(usize, f64)],
+        _pseudocosts: &mut Pseudocosts,
+    ) -> usize {
+        select_max(candidates, |_, value| (value - value.round()).abs())
+    }
+}
+
+/// Pseudocost branching: the best product score of the expected
+/// degradations, learned from the previous nodes.
+#[derive(Debug, Default)]
+pub struct PseudocostBranching;
+
+impl BranchingRule for PseudocostBranching {
+    fn select(
+        &mut self,
+        _slack_lp: &SlackFormLP,
+        candidates: &[(usize, f64)],
+        pseudocosts: &mut Pseudocosts,
+    ) -> usize {
+        select_max(candidates, |j, value| {
+            score(pseudocosts.degradations(j, value))
+        })
+    }
+}
+
+/// Strong branching: the best product score of the degradations of the trial
+/// solves of both children of each candidate.
+#[derive(Debug)]
+pub struct StrongBranching {
+    pub iterations: usize,
+}
+
+impl BranchingRule for StrongBranching {
+    fn select(
+        &mut self,
+        slack_lp: &SlackFormLP,
+        candidates: &[(usize, f64)],
+        pseudocosts: &mut Pseudocosts,
+    ) -> usize {
+        let iterations = self.iterations;
+        select_max(candidates, |j, value| {
+            score(strong_degradations(
+                slack_lp,
+                j,
+                value,
+                iterations,
+                pseudocosts,
+            ))
+        })
+    }
+}
+
+/// Reliability branching: pseudocost branching, with the strong branching of
+/// the candidates whose pseudocosts have been observed less than
+/// 'reliability' times in a direction.
+#[derive(Debug)]
+pub struct ReliabilityBranching {
+    pub iterations: usize,
+    pub reliability: usize,
+}
+
+impl BranchingRule for ReliabilityBranching {
+    fn select(
+        &mut self,
+        slack_lp: &SlackFormLP,
+        candidates: &[(usize, f64)],
+        pseudocosts: &mut Pseudocosts,
+    ) -> usize {
+        let iterations = self.iterations;
+        let reliability = self.reliability;
+        select_max(candidates, |j, value| {
+            let reliable = pseudocosts.get_count(j, false) >= reliability
+                && pseudocosts.get_count(j, true) >= reliability;
+            if reliable {
+                score(pseudocosts.degradations(j, value))
+            } else {
+                score(strong_degradations(
+                    slack_lp,
+                    j,
+                    value,
+                    iterations,
+                    pseudocosts,
+                ))
+            }
+        })
+    }
+}
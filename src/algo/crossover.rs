@@ -0,0 +1,141 @@
+use super::super::forms::slack::{
+    InitializationResult, SimplexResult, SlackFormLP,
+};
+use super::super::forms::standard::StandardFormLP;
+use super::super::types::error::SolverError;
+
+/// Values smaller than this one (relatively to the biggest value) are
+/// considered as zero.
+const EPSILON: f64 = 1e-9;
+
+/// Crossover from an interior solution to an optimal basis.
+///
+/// 'x' is a (near) optimal solution of the standard form, as returned by the
+/// interior point method. It does not need to be a vertex.
+///
+/// 1. Basis identification: the variables (including the slack variables)
+///    are taken by decreasing distance to their nearest bound (free variables
+///    first), and each one away from its bounds is pivoted into the basis if
+///    its column is independent from the columns already chosen. The other
+///    variables are set to their nearest bound.
+/// 2. Cleanup: the basic solution of this basis is (almost) optimal, the
+///    simplex finishes the work. The primal simplex is used when the basis is
+///    primal feasible, the dual simplex when it is dual feasible, otherwise
+///    both phases of the simplex are run.
+///
+/// The returned slack form has an optimal N / B partition.
+pub fn crossover(
+    standard_lp: &StandardFormLP,
+    x: &[f64],
+) -> Result<SlackFormLP, SolverError> {
+    let n = standard_lp.get_c().len();
+    if x.len() != n {
+        return Err(SolverError::DimensionMismatch(
+            "solution".into(),
+            n,
+            x.len(),
+        ));
+    }
+
+    // Values of every variable, slack variables included
+    let mut values = x.to_vec();
+    for (row, b) in standard_lp.get_a().iter().zip(standard_lp.get_b().iter()) {
+        let activity: f64 = row.iter().zip(x.iter()).map(|(a, x)| a * x).sum();
+        values.push(b - activity);
+    }
+    let scale = values.iter().fold(1., |acc: f64, v| acc.max(v.abs()));
+
+    let mut slack_lp = standard_lp.clone().into_slack_form()?;
+
+    // Distance of each variable to its nearest bound, the variables nearer
+    // to their upper bound are flipped
+    let mut distances = Vec::with_capacity(values.len());
+    for (index, value) in values.iter().enumerate() {
+        let to_lower = value - slack_lp.get_lower()[index];
+        let to_upper = slack_lp.get_upper()[index] - value;
+        let flipped = slack_lp.get_flipped()[index];
+        if to_lower.is_finite() && to_upper < to_lower && !flipped {
+            slack_lp.flip(index)?;
+        }
+        distances.push(to_lower.min(to_upper));
+    }
+
+    let mut candidates: Vec<(f64, usize)> = distances
+        .iter()
+        .enumerate()
+        .filter(|(_, d)| **d > EPSILON * scale)
+        .map(|(index, d)| (*d, index))
+        .collect();
+    candidates.sort_by(|(d1, i1), (d2, i2)| {
+        d2.partial_cmp(d1).unwrap().then(i1.cmp(i2))
+    });
+
+    let mut locked = vec![false; slack_lp.get_B().len()];
+
+    for (_, index) in candidates.into_iter() {
+        if let Some(row) = slack_lp.get_B().iter().position(|b| *b == index) {
+            locked[row] = true;
+            continue;
+        }
+        let col = if let Some(col) =
+            slack_lp.get_N().iter().position(|nb| *nb == index)
+        {
+            col
+        } else {
+            return Err(SolverError::Internal(
+                "variable is neither basic nor non basic.".into(),
+            ));
+        };
+
+        // Biggest pivot element among the rows not chosen yet
+        let mut best: Option<(f64, usize)> = None;
+        for (row, line) in slack_lp.get_A().iter().enumerate() {
+            let alpha = line[col].abs();
+            let is_better = match best {
+                None => alpha > EPSILON,
+                Some((best_alpha, _)) => alpha > best_alpha,
+            };
+            if !locked[row] && is_better {
+                best = Some((alpha, row));
+            }
+        }
+
+        // Otherwise the column depends on the basic columns already chosen,
+        // the variable stays non basic
+        if let Some((_, row)) = best {
+            slack_lp.pivot(col, row);
+            locked[row] = true;
+        }
+    }
+
+    slack_lp.snap_to_zero(EPSILON * scale);
+
+    let result = if slack_lp.is_primal_feasible() {
+        slack_lp.find_optimal()?
+    } else if slack_lp.is_dual_feasible() {
+        slack_lp.find_dual_optimal()?
+    } else {
+        match slack_lp.initialize_simplex()? {
+            InitializationResult::Done => slack_lp.find_optimal()?,
+            InitializationResult::Unfeasible(certificate) => {
+                SimplexResult::Unfeasible(certificate)
+            },
+            InitializationResult::Interrupted(interruption, point) => {
+                SimplexResult::Interrupted(interruption, point)
+            },
+        }
+    };
+
+    match result {
+        SimplexResult::Optimal(_) => Ok(slack_lp),
+        SimplexResult::Unbounded(..) => Err(SolverError::Unsolvable(
+            "Crossover failed, the linear program is unbounded.".into(),
+        )),
+        SimplexResult::Unfeasible(_) => Err(SolverError::Unsolvable(
+            "Crossover failed, the linear program is unfeasible.".into(),
+        )),
+        SimplexResult::Interrupted(interruption, _) => {
+            Err(SolverError::Interrupted(interruption))
+        },
+    }
+}
@@ -0,0 +1,194 @@
+use super::super::forms::options::MilpOptions;
+use super::super::forms::slack::{Budget, SimplexResult, SlackFormLP};
+use super::super::shared::lu::LUFactorization;
+use super::super::types::error::SolverError;
+
+/// Gomory mixed integer cuts of the optimal dictionary of the slack form.
+///
+/// The dictionary uses non negative variables x_j' (see SlackFormLP), a row
+/// whose basic variable is integer reads:
+/// ```ignore
+///     x_B' - Sum(a_k . x_k') = f_0 + floor(b),    a_k = A_k, f_0 = frac(b)
+/// ```
+/// When f_0 is fractional, every integer solution satisfies the cut:
+/// ```ignore
+///     Sum(g_k . x_k') >= 1
+///     g_k = f_k / f_0                      x_k' integer, f_k <= f_0
+///     g_k = (1 - f_k) / (1 - f_0)          x_k' integer, f_k > f_0
+///     g_k = -a_k / f_0                     x_k' continuous, a_k <= 0
+///     g_k = a_k / (1 - f_0)                x_k' continuous, a_k > 0
+/// ```
+/// with f_k = frac(-a_k), while the current basic solution (x_k' = 0) does
+/// not. A variable x_j' is integer when x_j is one of the integer indices and
+/// its bound (see reference) is integer, the slack variables are continuous.
+/// The rows with a free non basic variable are skipped.
+///
+/// The cuts are given over the original variables, like add_constraint
+/// expects them: a . x <= b. The values closer to an integer than the
+/// integrality tolerance of the MILP options are taken as integer.
+pub fn gomory_cuts(
+    slack_lp: &SlackFormLP,
+    integers: &[usize],
+    options: &MilpOptions,
+) -> Result<Vec<(Vec<f64>, f64)>, SolverError> {
+    let n = slack_lp.get_dim();
+    let non_basic = slack_lp.get_N();
+    let tolerance = options.get_integrality_tolerance();
+    let is_integer = |j: usize| {
+        j < n && integers.contains(&j) && slack_lp.reference(j).0.fract() == 0.
+    };
+
+    let mut cuts = Vec::new();
+    for (row, basic) in slack_lp.get_B().iter().enumerate() {
+        let b = slack_lp.get_b()[row];
+        let f_0 = b - b.floor();
+        if !is_integer(*basic) || f_0 <= tolerance || f_0 >= 1. - tolerance {
+            continue;
+        }
+
+        let line = &slack_lp.get_A()[row];
+        let free = line
+            .iter()
+            .zip(non_basic.iter())
+            .any(|(a, k)| a.abs() > tolerance && slack_lp.is_free(*k));
+        if free {
+            continue;
+        }
+
+        let g: Vec<f64> = line
+            .iter()
+            .zip(non_basic.iter())
+            .map(|(a, k)| {
+                if is_integer(*k) {
+                    let f_k = -a - (-a).floor();
+                    if f_k <= f_0 {
+                        f_k / f_0
+                    } else {
+                        (1. - f_k) / (1. - f_0)
+                    }
+                } else if *a <= 0. {
+                    -a / f_0
+                } else {
+                    a / (1. - f_0)
+                }
+            })
+            .collect();
+
+        if let Some(cut) = original_cut(slack_lp, &g, 1.)? {
+            cuts.push(cut);
+        }
+    }
+
+    Ok(cuts)
+}
+
+/// Cut Sum(g_k . x_k') >= beta over the non basic variables, rewritten over
+/// the original variables as a . x <= b.
+///
+/// The variables x' of the dictionary are x' = p + M . x_N', where M holds
+/// the rows of the basic variables and the identity for the non basic ones,
+/// so x_N' = M^-1 . (x' - p) and the cut reads y . x' >= beta + y . p with
+/// M^T . y = g. None if the cut is not a finite one.
+fn original_cut(
+    slack_lp: &SlackFormLP,
+    g: &[f64],
+    beta: f64,
+) -> Result<Option<(Vec<f64>, f64)>, SolverError> {
+    let n = slack_lp.get_dim();
+    let non_basic = slack_lp.get_N();
+    if non_basic.len() != n {
+        return Err(SolverError::Internal(
+            "the dictionary has not one non basic variable per original \
+             variable."
+                .into(),
+        ));
+    }
+
+    let mut m = vec![vec![0.; n]; n];
+    let mut p = vec![0.; n];
+    for (col, k) in non_basic.iter().enumerate() {
+        if *k < n {
+            m[*k][col] = 1.;
+        }
+    }
+    for (row, basic) in slack_lp.get_B().iter().enumerate() {
+        if *basic < n {
+            m[*basic] = slack_lp.get_A()[row].clone();
+            p[*basic] = slack_lp.get_b()[row];
+        }
+    }
+    let y = LUFactorization::new(m)?.solve_transpose(g);
+
+    // x_j' = sign_j . (x_j - reference_j)
+    let mut a = Vec::with_capacity(n);
+    let mut b = -beta;
+    for (j, (y_j, p_j)) in y.iter().zip(p.iter()).enumerate() {
+        let (reference, sign) = slack_lp.reference(j);
+        a.push(-y_j * sign);
+        b -= y_j * (p_j + sign * reference);
+    }
+    if a.iter().all(|a_j| *a_j == 0.)
+        || !b.is_finite()
+        || a.iter().any(|a_j| !a_j.is_finite())
+    {
+        return Ok(None);
+    }
+
+    Ok(Some((a, b)))
+}
+
+/// Rounds of Gomory mixed integer cuts: the cuts of the optimal dictionary
+/// are added to the slack form (see add_constraint), and the dual simplex
+/// optimizes it again, at most 'rounds' times or until no cut is found.
+///
+/// The basis of the slack form must be optimal. The result is the solution
+/// of the last dual simplex (the current one if no cut is found), the
+/// linear program may turn out to be unfeasible for the integer indices.
+pub fn gomory_rounds(
+    slack_lp: &mut SlackFormLP,
+    integers: &[usize],
+    rounds: usize,
+    options: &MilpOptions,
+) -> Result<SimplexResult, SolverError> {
+    if !slack_lp.is_primal_feasible() || !slack_lp.is_dual_feasible() {
+        return Err(SolverError::InvalidState(
+            "The current basis is not optimal, the Gomory cuts cannot be \
+             derived."
+                .into(),
+        ));
+    }
+
+    gomory_rounds_within(
+        slack_lp,
+        integers,
+        rounds,
+        options,
+        &mut Budget::new(),
+    )
+}
+
+pub(crate) fn gomory_rounds_within(
+    slack_lp: &mut SlackFormLP,
+    integers: &[usize],
+    rounds: usize,
+    options: &MilpOptions,
+    budget: &mut Budget,
+) -> Result<SimplexResult, SolverError> {
+    let mut result = slack_lp.compute_solution()?;
+    for _ in 0..rounds {
+        let cuts = gomory_cuts(slack_lp, integers, options)?;
+        if cuts.is_empty() {
+            break;
+        }
+        for (a, b) in cuts.into_iter() {
+            slack_lp.add_constraint(a, b)?;
+        }
+
+        result = slack_lp.find_dual_optimal_within(budget)?;
+        if !matches!(result, SimplexResult::Optimal(_)) {
+            break;
+        }
+    }
+
+    Ok(result)
+}
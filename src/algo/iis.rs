@@ -0,0 +1,82 @@
+use super::super::forms::slack::InitializationResult;
+use super::super::forms::standard::StandardFormLP;
+use super::super::types::error::SolverError;
+
+/// Values smaller than this one are considered as zero.
+const EPSILON: f64 = 1e-9;
+
+/// Farkas certificate of the linear program restricted to the rows 'rows',
+/// None if it is feasible. Only the first phase of the simplex is run.
+fn find_certificate(
+    standard_lp: &StandardFormLP,
+    rows: &[usize],
+) -> Result<Option<Vec<f64>>, SolverError> {
+    // Without any row, only the variable bounds are left, which are
+    // consistent
+    if rows.is_empty() {
+        return Ok(None);
+    }
+
+    let mut slack_lp = standard_lp.select_rows(rows)?.into_slack_form()?;
+    match slack_lp.initialize_simplex()? {
+        InitializationResult::Done => Ok(None),
+        InitializationResult::Unfeasible(certificate) => Ok(Some(certificate)),
+        InitializationResult::Interrupted(interruption, _) => {
+            Err(SolverError::Interrupted(interruption))
+        },
+    }
+}
+
+/// Irreducible infeasible subsystem (IIS) of an unfeasible linear program.
+///
+/// Return the indices of a minimal set of rows which is still unfeasible
+/// (with the variable bounds): removing any of them makes it feasible. The
+/// rows of a standard form built with the builder are the constraints in the
+/// order they were added.
+///
+/// 1. The rows are first restricted to the support of the Farkas
+///    certificate, the other rows take no part in the contradiction.
+/// 2. Deletion filtering: each row is removed in turn, and put back if the
+///    remaining rows become feasible.
+pub fn find_iis(
+    standard_lp: &StandardFormLP,
+) -> Result<Vec<usize>, SolverError> {
+    let all_rows: Vec<usize> = (0..standard_lp.get_b().len()).collect();
+    let certificate = if let Some(y) = find_certificate(standard_lp, &all_rows)?
+    {
+        y
+    } else {
+        return Err(SolverError::Unsolvable(
+            "The linear program is feasible, there is no irreducible \
+             infeasible subsystem."
+                .into(),
+        ));
+    };
+
+    let support: Vec<usize> = certificate
+        .iter()
+        .enumerate()
+        .filter(|(_, y)| y.abs() > EPSILON)
+        .map(|(row, _)| row)
+        .collect();
+
+    // Round off errors may give a support which is not unfeasible on its own
+    let mut rows = if find_certificate(standard_lp, &support)?.is_some() {
+        support
+    } else {
+        all_rows
+    };
+
+    let mut index = 0;
+    while index < rows.len() {
+        let mut candidate = rows.clone();
+        candidate.remove(index);
+        if find_certificate(standard_lp, &candidate)?.is_some() {
+            rows = candidate;
+        } else {
+            index += 1;
+        }
+    }
+
+    Ok(rows)
+}
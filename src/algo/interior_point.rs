@@ -0,0 +1,635 @@
+use super::super::forms::slack::{Budget, SimplexResult};
+use super::super::forms::solution::Solution;
+use super::super::forms::standard::StandardFormLP;
+use super::super::shared::cholesky::CholeskyFactorization;
+use super::super::types::error::SolverError;
+
+/// Relative tolerance on the residuals and on the duality gap.
+const EPSILON: f64 = 1e-9;
+/// Fraction of the step to the boundary actually done.
+const STEP_FACTOR: f64 = 0.99;
+/// Iterations before giving up, when the options have no iteration limit.
+const MAX_ITERATIONS: usize = 200;
+/// Tolerance on the conditions of a certificate, once scaled.
+const CERTIFICATE_TOLERANCE: f64 = 1e-6;
+
+/// Outcome of the interior point method.
+///
+/// result has the same meaning as for the simplex, the optimal solution is
+/// the last (interior) iterate, which is not a vertex in general.
+/// duality_gap is |c^T . x - b^T . y| at termination (scaled by tau).
+#[derive(Debug, PartialEq)]
+pub struct InteriorPointResult {
+    result: SimplexResult,
+    duality_gap: f64,
+    iterations: usize,
+}
+
+impl InteriorPointResult {
+    pub fn get_result(&self) -> &SimplexResult { &self.result }
+
+    pub fn get_duality_gap(&self) -> f64 { self.duality_gap }
+
+    pub fn get_iterations(&self) -> usize { self.iterations }
+}
+
+/// Standard form with slack variables, as a system of equalities:
+/// ```ignore
+///     min -c^T . x
+///     with A . x + s = b
+///     x, s >= 0
+/// ```
+/// The n first variables are x, the m last ones are s.
+///
+/// It is solved through its homogeneous self dual embedding, which gives
+/// either an optimal solution (tau > 0) or a certificate that the problem is
+/// unfeasible or unbounded (kappa > 0):
+/// ```ignore
+///     A . x - b . tau = 0
+///     A^T . y + s - c . tau = 0
+///     -c^T . x + b^T . y - kappa = 0
+///     x, s, tau, kappa >= 0
+/// ```
+struct EqualityForm<'a> {
+    a: &'a Vec<Vec<f64>>,
+    m: usize,
+    n: usize,
+}
+
+impl<'a> EqualityForm<'a> {
+    /// [A I] . x
+    fn product(&self, x: &[f64]) -> Vec<f64> {
+        self.a
+            .iter()
+            .enumerate()
+            .map(|(i, row)| dot(row, &x[..self.n]) + x[self.n + i])
+            .collect()
+    }
+
+    /// [A I]^T . y
+    fn product_transpose(&self, y: &[f64]) -> Vec<f64> {
+        let mut result = vec![0.; self.n];
+        for (row, y_i) in self.a.iter().zip(y.iter()) {
+            for (r_j, a_ij) in result.iter_mut().zip(row.iter()) {
+                *r_j += a_ij * y_i;
+            }
+        }
+        result.extend_from_slice(y);
+
+        result
+    }
+
+    /// Normal equations matrix: [A I] . D . [A I]^T
+    fn normal_matrix(&self, d: &[f64]) -> Vec<Vec<f64>> {
+        let mut matrix = vec![vec![0.; self.m]; self.m];
+        for (i, row_i) in self.a.iter().enumerate() {
+            for (k, row_k) in self.a.iter().enumerate().take(i + 1) {
+                let mut sum: f64 = row_i
+                    .iter()
+                    .zip(row_k.iter())
+                    .zip(d.iter())
+                    .map(|((a_ij, a_kj), d_j)| a_ij * a_kj * d_j)
+                    .sum();
+                if i == k {
+                    sum += d[self.n + i];
+                }
+                matrix[i][k] = sum;
+                matrix[k][i] = sum;
+            }
+        }
+
+        matrix
+    }
+}
+
+fn dot(u: &[f64], v: &[f64]) -> f64 {
+    u.iter().zip(v.iter()).map(|(a, b)| a * b).sum()
+}
+
+fn norm(u: &[f64]) -> f64 { dot(u, u).sqrt() }
+
+/// Largest step such that v + step . dv >= 0
+fn max_step(v: &[f64], dv: &[f64]) -> f64 {
+    v.iter().zip(dv.iter()).fold(f64::INFINITY, |acc: f64, (v_j, dv_j)| {
+        if *dv_j < 0. {
+            acc.min(-v_j / dv_j)
+        } else {
+            acc
+        }
+    })
+}
+
+/// v scaled such that its largest absolute value is 1
+fn normalize(v: &[f64]) -> Vec<f64> {
+    let scale =
+        v.iter().fold(f64::MIN_POSITIVE, |acc: f64, v| acc.max(v.abs()));
+    v.iter().map(|v_i| v_i / scale).collect()
+}
+
+/// Farkas certificate of the plain form: y >= 0, A^T . y >= 0 and
+/// b^T . y < 0 (checked on y scaled to 1, with CERTIFICATE_TOLERANCE).
+fn is_farkas_certificate(plain: &PlainForm, y: &[f64]) -> bool {
+    let y = normalize(y);
+    let mut at_y = vec![0.; plain.c.len()];
+    for (row, y_i) in plain.a.iter().zip(y.iter()) {
+        for (r_j, a_ij) in at_y.iter_mut().zip(row.iter()) {
+            *r_j += a_ij * y_i;
+        }
+    }
+
+    y.iter().all(|y_i| *y_i >= -CERTIFICATE_TOLERANCE)
+        && at_y.iter().all(|v| *v >= -CERTIFICATE_TOLERANCE)
+        && dot(&plain.b, &y) < -CERTIFICATE_TOLERANCE
+}
+
+/// Ray of unbounded improvement of the plain form: A . d <= 0, d >= 0 and
+/// c^T . d > 0 (checked on d scaled to 1, with CERTIFICATE_TOLERANCE).
+fn is_unbounded_ray(plain: &PlainForm, d: &[f64]) -> bool {
+    let d = normalize(d);
+
+    d.iter().all(|d_j| *d_j >= -CERTIFICATE_TOLERANCE)
+        && plain.a.iter().all(|row| dot(row, &d) <= CERTIFICATE_TOLERANCE)
+        && dot(&plain.c, &d) > CERTIFICATE_TOLERANCE
+}
+
+#[derive(Debug, Clone)]
+struct Point {
+    x: Vec<f64>,
+    y: Vec<f64>,
+    s: Vec<f64>,
+    tau: f64,
+    kappa: f64,
+}
+
+struct Residuals {
+    primal: Vec<f64>, // b . tau - A . x
+    dual: Vec<f64>,   // c . tau - A^T . y - s
+    gap: f64,         // kappa + c^T . x - b^T . y
+}
+
+impl<'a> EqualityForm<'a> {
+    fn residuals(&self, b: &[f64], cost: &[f64], point: &Point) -> Residuals {
+        let ax = self.product(&point.x);
+        let primal = b
+            .iter()
+            .zip(ax.iter())
+            .map(|(b_i, v)| b_i * point.tau - v)
+            .collect();
+        let at_y = self.product_transpose(&point.y);
+        let dual = (0..cost.len())
+            .map(|j| cost[j] * point.tau - at_y[j] - point.s[j])
+            .collect();
+        let gap = point.kappa + dot(cost, &point.x) - dot(b, &point.y);
+
+        Residuals { primal, dual, gap }
+    }
+
+    /// Solve the Newton system of the embedding:
+    /// ```ignore
+    ///     A . dx - b . dtau = eta . r_p
+    ///     A^T . dy + ds - c . dtau = eta . r_d
+    ///     -c^T . dx + b^T . dy - dkappa = eta . r_g
+    ///     S . dx + X . ds = r_xs
+    ///     kappa . dtau + tau . dkappa = r_tk
+    /// ```
+    /// with the normal equations, already factorized for D = X . S^-1.
+    /// (q, v) are the parts of dy and dx proportional to dtau, they only
+    /// depend on the factorization.
+    #[allow(clippy::too_many_arguments)]
+    fn solve_newton(
+        &self,
+        factorization: &CholeskyFactorization,
+        (b, cost): (&[f64], &[f64]),
+        (q, v): (&[f64], &[f64]),
+        point: &Point,
+        residuals: &Residuals,
+        eta: f64,
+        r_xs: &[f64],
+        r_tk: f64,
+    ) -> Point {
+        let x = &point.x;
+        let s = &point.s;
+
+        // dx = D . (A^T . dy - c . dtau + X^-1 . r_xs - eta . r_d)
+        let t: Vec<f64> = (0..x.len())
+            .map(|j| x[j] / s[j] * (r_xs[j] / x[j] - eta * residuals.dual[j]))
+            .collect();
+        let at = self.product(&t);
+        let rhs: Vec<f64> = residuals
+            .primal
+            .iter()
+            .zip(at.iter())
+            .map(|(r, p)| eta * r - p)
+            .collect();
+        let p = factorization.solve(&rhs);
+        let at_p = self.product_transpose(&p);
+        let u: Vec<f64> =
+            (0..x.len()).map(|j| x[j] / s[j] * at_p[j] + t[j]).collect();
+
+        let dtau = (eta * residuals.gap + dot(cost, &u) - dot(b, &p)
+            + r_tk / point.tau)
+            / (-dot(cost, v) + dot(b, q) + point.kappa / point.tau);
+        let dx: Vec<f64> =
+            u.iter().zip(v.iter()).map(|(u_j, v_j)| u_j + v_j * dtau).collect();
+        let dy: Vec<f64> =
+            p.iter().zip(q.iter()).map(|(p_i, q_i)| p_i + q_i * dtau).collect();
+        let ds: Vec<f64> =
+            (0..x.len()).map(|j| (r_xs[j] - s[j] * dx[j]) / x[j]).collect();
+        let dkappa = (r_tk - point.kappa * dtau) / point.tau;
+
+        Point { x: dx, y: dy, s: ds, tau: dtau, kappa: dkappa }
+    }
+}
+
+/// Largest step such that point + step . direction stays positive
+fn max_point_step(point: &Point, direction: &Point) -> f64 {
+    max_step(&point.x, &direction.x).min(max_step(&point.s, &direction.s)).min(
+        max_step(&[point.tau, point.kappa], &[direction.tau, direction.kappa]),
+    )
+}
+
+/// Linear program without bounds nor ranges, only inequalities and non
+/// negative variables:
+/// ```ignore
+///     max c^T . x
+///     with A . x <= b
+///     x >= 0
+/// ```
+/// Each variable of the standard form is x_j = reference_j + Sum(sign . x_k)
+/// over its columns:
+/// - lower_j finite: x_j = lower_j + x_k, and x_k <= upper_j - lower_j if the
+///   upper bound is finite,
+/// - only upper_j finite: x_j = upper_j - x_k,
+/// - free: x_j = x_k - x_k+1.
+///
+/// Rows with a finite range are split in two inequalities:
+/// ```ignore
+///     a_i . x <= b_i
+///     -a_i . x <= range_i - b_i
+/// ```
+#[derive(Clone)]
+struct PlainForm {
+    c: Vec<f64>,
+    a: Vec<Vec<f64>>,
+    b: Vec<f64>,
+    variables: Vec<(f64, Vec<(usize, f64)>)>, // reference, (column, sign)
+    range_rows: Vec<(usize, usize)>,          // row, its second inequality
+}
+
+impl PlainForm {
+    fn new(standard_lp: &StandardFormLP) -> PlainForm {
+        let lower = standard_lp.get_lower();
+        let upper = standard_lp.get_upper();
+
+        let mut columns: Vec<(usize, f64)> = Vec::new();
+        let mut variables = Vec::with_capacity(lower.len());
+        let mut upper_rows = Vec::new();
+        for (j, (l, u)) in lower.iter().zip(upper.iter()).enumerate() {
+            let k = columns.len();
+            if l.is_finite() {
+                columns.push((j, 1.));
+                variables.push((*l, vec![(k, 1.)]));
+                if u.is_finite() {
+                    upper_rows.push((k, u - l));
+                }
+            } else if u.is_finite() {
+                columns.push((j, -1.));
+                variables.push((*u, vec![(k, -1.)]));
+            } else {
+                columns.push((j, 1.));
+                columns.push((j, -1.));
+                variables.push((0., vec![(k, 1.), (k + 1, -1.)]));
+            }
+        }
+
+        let c_lp = standard_lp.get_c();
+        let c = columns.iter().map(|(j, sign)| sign * c_lp[*j]).collect();
+
+        let mut a: Vec<Vec<f64>> = Vec::new();
+        let mut b = Vec::new();
+        for (row, b_i) in standard_lp.get_a().iter().zip(standard_lp.get_b()) {
+            a.push(columns.iter().map(|(j, sign)| sign * row[*j]).collect());
+            let shift: f64 = row
+                .iter()
+                .zip(variables.iter())
+                .map(|(a_ij, (reference, _))| a_ij * reference)
+                .sum();
+            b.push(b_i - shift);
+        }
+        let mut range_rows = Vec::new();
+        for (i, range) in standard_lp.get_ranges().iter().enumerate() {
+            if range.is_finite() {
+                range_rows.push((i, a.len()));
+                a.push(a[i].iter().map(|v| -v).collect());
+                b.push(range - b[i]);
+            }
+        }
+        for (k, width) in upper_rows.into_iter() {
+            let mut row = vec![0.; columns.len()];
+            row[k] = 1.;
+            a.push(row);
+            b.push(width);
+        }
+
+        PlainForm { c, a, b, variables, range_rows }
+    }
+
+    /// Prices of the rows of the standard form, from the prices of the rows
+    /// of the plain form (the rows of the variable bounds are dropped).
+    fn fold_prices(&self, m: usize, prices: &[f64]) -> Vec<f64> {
+        // The second inequality of a range row has the opposite direction
+        let mut folded = prices[..m].to_vec();
+        for (row, second) in self.range_rows.iter() {
+            folded[*row] -= prices[*second];
+        }
+
+        folded
+    }
+
+    /// Direction in the variables of the standard form, from a direction in
+    /// the variables of the plain form (references are not added).
+    fn fold_direction(&self, dx: &[f64]) -> Vec<f64> {
+        self.variables
+            .iter()
+            .map(|(_, columns)| {
+                columns.iter().map(|(k, sign)| sign * dx[*k]).sum::<f64>()
+            })
+            .collect()
+    }
+
+    /// Point in the variables of the standard form, from a point in the
+    /// variables of the plain form.
+    fn fold_point(&self, x: &[f64]) -> Vec<f64> {
+        self.variables
+            .iter()
+            .map(|(reference, columns)| {
+                reference
+                    + columns.iter().map(|(k, sign)| sign * x[*k]).sum::<f64>()
+            })
+            .collect()
+    }
+
+    /// Solution of the standard form from the one of the plain form, and
+    /// from the shadow prices of its rows (for the objective given to the
+    /// builder, min or max)
+    fn fold_solution(
+        &self,
+        standard_lp: &StandardFormLP,
+        x: &[f64],
+        prices: &[f64],
+    ) -> Result<Solution, SolverError> {
+        let x = self.fold_point(x);
+
+        let c = standard_lp.get_c();
+        let a = standard_lp.get_a();
+        let row_activities: Vec<f64> =
+            a.iter().map(|row| dot(row, &x)).collect();
+        let slacks = standard_lp
+            .get_b()
+            .iter()
+            .zip(row_activities.iter())
+            .map(|(b, activity)| b - activity)
+            .collect();
+
+        let duals = self.fold_prices(a.len(), prices);
+        let reduced_costs = (0..c.len())
+            .map(|j| {
+                c[j] - a
+                    .iter()
+                    .zip(duals.iter())
+                    .map(|(row, y)| row[j] * y)
+                    .sum::<f64>()
+            })
+            .collect();
+
+        let solution = Solution::new(
+            dot(c, &x),
+            x,
+            row_activities,
+            slacks,
+            duals,
+            reduced_costs,
+        )?;
+
+        if standard_lp.is_min_objective() {
+            Ok(solution.into_min_objective())
+        } else {
+            Ok(solution)
+        }
+    }
+}
+
+/// Primal dual interior point method, with the Mehrotra predictor corrector.
+///
+/// Each iteration solves twice the Newton system, using the same Cholesky
+/// factorization of the normal equations (m x m matrix, m is the number of
+/// constraints). The method does not return a basic solution: the optimal
+/// solution is interior to the optimal face (see crossover for a vertex).
+///
+/// The homogeneous self dual embedding is used, so that unfeasible and
+/// unbounded problems are detected: tau goes to zero while kappa does not.
+/// A ray of an unbounded problem comes with a feasible point, found by
+/// solving the problem again without objective.
+///
+/// The limits of the options are checked before each iteration, an
+/// interrupted solve comes with the current iterate (x / tau), which is
+/// neither feasible nor optimal in general.
+pub fn interior_point_mehrotra(
+    standard_lp: &StandardFormLP,
+) -> Result<InteriorPointResult, SolverError> {
+    mehrotra(standard_lp, &PlainForm::new(standard_lp), &mut Budget::new())
+}
+
+fn mehrotra(
+    standard_lp: &StandardFormLP,
+    plain: &PlainForm,
+    budget: &mut Budget,
+) -> Result<InteriorPointResult, SolverError> {
+    let b = &plain.b;
+    let n = plain.c.len();
+    let m = b.len();
+    let form = EqualityForm { a: &plain.a, m, n };
+
+    let mut cost: Vec<f64> = plain.c.iter().map(|v| -v).collect();
+    cost.append(&mut vec![0.; m]);
+
+    let scale_b = 1. + norm(b);
+    let scale_c = 1. + norm(&cost);
+    let total = (n + m + 1) as f64;
+
+    let mut point = Point {
+        x: vec![1.; n + m],
+        y: vec![0.; m],
+        s: vec![1.; n + m],
+        tau: 1.,
+        kappa: 1.,
+    };
+    let mu_0 = 1.;
+    let mut duality_gap = f64::INFINITY;
+
+    // The last round only checks the iterate, the budget interrupts the
+    // solve there when the options have an iteration limit
+    let iteration_limit = standard_lp
+        .get_options()
+        .get_iteration_limit()
+        .unwrap_or(MAX_ITERATIONS);
+    for iteration in 0..=iteration_limit {
+        let residuals = form.residuals(b, &cost, &point);
+        let primal_objective = dot(&cost, &point.x);
+        let dual_objective = dot(b, &point.y);
+        duality_gap = (primal_objective - dual_objective).abs() / point.tau;
+        let mu = (dot(&point.x, &point.s) + point.tau * point.kappa) / total;
+
+        let primal_feasible = norm(&residuals.primal) / point.tau / scale_b;
+        let dual_feasible = norm(&residuals.dual) / point.tau / scale_c;
+        let relative_gap =
+            duality_gap / (1. + (dual_objective / point.tau).abs());
+        if primal_feasible <= EPSILON
+            && dual_feasible <= EPSILON
+            && relative_gap <= EPSILON
+        {
+            let x: Vec<f64> =
+                point.x[..n].iter().map(|v| v / point.tau).collect();
+            let prices: Vec<f64> =
+                point.y.iter().map(|y| -y / point.tau).collect();
+            let solution = plain.fold_solution(standard_lp, &x, &prices)?;
+            return Ok(InteriorPointResult {
+                result: SimplexResult::Optimal(solution),
+                duality_gap,
+                iterations: iteration,
+            });
+        }
+
+        // tau vanishes: there is no optimal solution, the iterate gives a
+        // ray for the primal or for the dual problem
+        if mu / mu_0 <= EPSILON && point.tau <= EPSILON * point.kappa.max(1.) {
+            let prices: Vec<f64> = point.y.iter().map(|y| -y).collect();
+            let direction = &point.x[..n];
+            let mut iterations = iteration;
+            let result = if is_farkas_certificate(plain, &prices) {
+                // y >= 0, A^T . y >= 0 and b^T . y < 0
+                let certificate =
+                    plain.fold_prices(standard_lp.get_a().len(), &prices);
+                SimplexResult::Unfeasible(normalize(&certificate))
+            } else if is_unbounded_ray(plain, direction) {
+                // A . x <= 0, x >= 0 and c^T . x > 0: x is a direction of
+                // unbounded improvement, if the problem is feasible (the
+                // problem without objective cannot have such a ray)
+                let direction = normalize(&plain.fold_direction(direction));
+                let feasibility = PlainForm { c: vec![0.; n], ..plain.clone() };
+                let feasible = mehrotra(standard_lp, &feasibility, budget)?;
+                iterations += feasible.iterations;
+                match feasible.result {
+                    SimplexResult::Optimal(solution) => {
+                        SimplexResult::Unbounded(
+                            solution.get_x().clone(),
+                            direction,
+                        )
+                    },
+                    result => result,
+                }
+            } else {
+                return Err(SolverError::NumericalBreakdown(
+                    "Interior point method has found neither a solution, nor \
+                     a certificate."
+                        .into(),
+                ));
+            };
+
+            return Ok(InteriorPointResult { result, duality_gap, iterations });
+        }
+
+        if let Some(interruption) = budget.spend(standard_lp.get_options()) {
+            let x: Vec<f64> =
+                point.x[..n].iter().map(|v| v / point.tau).collect();
+            return Ok(InteriorPointResult {
+                result: SimplexResult::Interrupted(
+                    interruption,
+                    plain.fold_point(&x),
+                ),
+                duality_gap,
+                iterations: iteration,
+            });
+        }
+
+        let d: Vec<f64> =
+            point.x.iter().zip(point.s.iter()).map(|(x, s)| x / s).collect();
+        let factorization =
+            CholeskyFactorization::new(&form.normal_matrix(&d))?;
+
+        // Part of the direction proportional to dtau
+        let ad: Vec<f64> = (0..n + m).map(|j| d[j] * cost[j]).collect();
+        let rhs: Vec<f64> = b
+            .iter()
+            .zip(form.product(&ad).iter())
+            .map(|(b, v)| b + v)
+            .collect();
+        let q = factorization.solve(&rhs);
+        let at_q = form.product_transpose(&q);
+        let v: Vec<f64> =
+            (0..n + m).map(|j| d[j] * (at_q[j] - cost[j])).collect();
+
+        // Predictor (affine scaling direction)
+        let r_xs: Vec<f64> =
+            point.x.iter().zip(point.s.iter()).map(|(x, s)| -x * s).collect();
+        let r_tk = -point.tau * point.kappa;
+        let affine = form.solve_newton(
+            &factorization,
+            (b, &cost),
+            (&q, &v),
+            &point,
+            &residuals,
+            1.,
+            &r_xs,
+            r_tk,
+        );
+        let alpha = max_point_step(&point, &affine).min(1.);
+        let mu_affine = ((0..n + m)
+            .map(|j| {
+                (point.x[j] + alpha * affine.x[j])
+                    * (point.s[j] + alpha * affine.s[j])
+            })
+            .sum::<f64>()
+            + (point.tau + alpha * affine.tau)
+                * (point.kappa + alpha * affine.kappa))
+            / total;
+        let sigma = (mu_affine / mu).powi(3);
+
+        // Corrector (and centering)
+        let r_xs: Vec<f64> = (0..n + m)
+            .map(|j| {
+                -point.x[j] * point.s[j] - affine.x[j] * affine.s[j]
+                    + sigma * mu
+            })
+            .collect();
+        let r_tk =
+            -point.tau * point.kappa - affine.tau * affine.kappa + sigma * mu;
+        let direction = form.solve_newton(
+            &factorization,
+            (b, &cost),
+            (&q, &v),
+            &point,
+            &residuals,
+            1. - sigma,
+            &r_xs,
+            r_tk,
+        );
+        let alpha = (STEP_FACTOR * max_point_step(&point, &direction)).min(1.);
+
+        for j in 0..n + m {
+            point.x[j] += alpha * direction.x[j];
+            point.s[j] += alpha * direction.s[j];
+        }
+        for (y_i, dy_i) in point.y.iter_mut().zip(direction.y.iter()) {
+            *y_i += alpha * dy_i;
+        }
+        point.tau += alpha * direction.tau;
+        point.kappa += alpha * direction.kappa;
+    }
+
+    Err(SolverError::NumericalBreakdown(format!(
+        "Interior point method did not converge after {} iterations (duality \
+         gap: {})",
+        iteration_limit, duality_gap
+    )))
+}
@@ -1 +1,10 @@
+pub mod branch_and_bound;
+pub mod branching;
+pub mod crossover;
+pub mod gomory;
+pub mod iis;
+pub mod interior_point;
+pub mod pricing;
+pub mod relaxation;
+pub mod revised_simplex;
 pub mod simplex;
@@ -0,0 +1,184 @@
+use super::super::forms::options::Pricing;
+use super::super::forms::slack::SlackFormLP;
+
+/// Rule choosing the entering variable of the primal simplex.
+///
+/// The candidates are the non basic variables which improve the objective,
+/// given as (column, delta): delta is the step length of the ratio test, the
+/// objective increases by c_col . delta when the variable enters.
+pub trait PricingRule {
+    /// Index of the chosen candidate
+    fn select(
+        &mut self,
+        slack_lp: &SlackFormLP,
+        candidates: &[(usize, f64)],
+    ) -> usize;
+
+    /// Called before each pivot: the non basic variable at 'col' replaces the
+    /// basic variable at 'row'
+    fn update(&mut self, _slack_lp: &SlackFormLP, _col: usize, _row: usize) {}
+}
+
+impl Pricing {
+    /// Rule of the simplex implementing this pricing.
+    pub fn into_rule(self) -> Box<dyn PricingRule> {
+        match self {
+            Pricing::Bland => Box::new(Bland),
+            Pricing::Dantzig => Box::new(Dantzig),
+            Pricing::LargestImprovement => Box::new(LargestImprovement),
+            Pricing::SteepestEdge => Box::new(SteepestEdge),
+            Pricing::Devex => Box::new(Devex::default()),
+        }
+    }
+}
+
+/// Candidate with the biggest score, ties are broken with the smallest non
+/// basic index.
+fn select_max<F>(
+    slack_lp: &SlackFormLP,
+    candidates: &[(usize, f64)],
+    score: F,
+) -> usize
+where
+    F: Fn(usize, f64) -> f64,
+{
+    let non_basic = slack_lp.get_N();
+    let mut best = 0;
+    let mut best_score = f64::NEG_INFINITY;
+    for (index, (col, delta)) in candidates.iter().enumerate() {
+        let value = score(*col, *delta);
+        let best_col = candidates[best].0;
+        if value > best_score
+            || (value == best_score && non_basic[*col] < non_basic[best_col])
+        {
+            best = index;
+            best_score = value;
+        }
+    }
+
+    best
+}
+
+/// Bland's rule: the smallest non basic index, it never cycles.
+#[derive(Debug, Default)]
+pub struct Bland;
+
+impl PricingRule for Bland {
+    fn select(
+        &mut self,
+        slack_lp: &SlackFormLP,
+        candidates: &[(usize, f64)],
+    ) -> usize {
+        let non_basic = slack_lp.get_N();
+        candidates
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, (col, _))| non_basic[*col])
+            .map(|(index, _)| index)
+            .unwrap_or(0)
+    }
+}
+
+/// Dantzig's rule: the largest coefficient c_j.
+#[derive(Debug, Default)]
+pub struct Dantzig;
+
+impl PricingRule for Dantzig {
+    fn select(
+        &mut self,
+        slack_lp: &SlackFormLP,
+        candidates: &[(usize, f64)],
+    ) -> usize {
+        let c = slack_lp.get_c();
+        select_max(slack_lp, candidates, |col, _| c[col])
+    }
+}
+
+/// Largest improvement of the objective: c_j . delta_j.
+#[derive(Debug, Default)]
+pub struct LargestImprovement;
+
+impl PricingRule for LargestImprovement {
+    fn select(
+        &mut self,
+        slack_lp: &SlackFormLP,
+        candidates: &[(usize, f64)],
+    ) -> usize {
+        let c = slack_lp.get_c();
+        select_max(slack_lp, candidates, |col, delta| c[col] * delta)
+    }
+}
+
+/// Steepest edge: the largest c_j^2 / ||edge_j||^2, the edge being the move
+/// of every variable when x_j increases by 1.0, i.e. 1 + Sum(a_ij^2).
+/// The dictionary holds the whole column, so the norms are exact.
+#[derive(Debug, Default)]
+pub struct SteepestEdge;
+
+impl PricingRule for SteepestEdge {
+    fn select(
+        &mut self,
+        slack_lp: &SlackFormLP,
+        candidates: &[(usize, f64)],
+    ) -> usize {
+        let c = slack_lp.get_c();
+        let a = slack_lp.get_A();
+        select_max(slack_lp, candidates, |col, _| {
+            let norm: f64 = a.iter().map(|line| line[col] * line[col]).sum();
+            c[col] * c[col] / (1. + norm)
+        })
+    }
+}
+
+/// Devex: approximation of the steepest edge, c_j^2 / w_j where the weights
+/// w_j are updated at each pivot (reference framework: the non basic
+/// variables of the first basis, whose weights are 1.0).
+#[derive(Debug, Default)]
+pub struct Devex {
+    weights: Vec<f64>, // one weight per variable index
+}
+
+impl Devex {
+    /// The weights are reset when the number of variables changes (sup x
+    /// variable of the initialization)
+    fn check_weights(&mut self, slack_lp: &SlackFormLP) {
+        let size = slack_lp.get_N().len() + slack_lp.get_B().len();
+        if self.weights.len() != size {
+            self.weights = vec![1.; size];
+        }
+    }
+}
+
+impl PricingRule for Devex {
+    fn select(
+        &mut self,
+        slack_lp: &SlackFormLP,
+        candidates: &[(usize, f64)],
+    ) -> usize {
+        self.check_weights(slack_lp);
+        let c = slack_lp.get_c();
+        let non_basic = slack_lp.get_N();
+        let weights = &self.weights;
+        select_max(slack_lp, candidates, |col, _| {
+            c[col] * c[col] / weights[non_basic[col]]
+        })
+    }
+
+    fn update(&mut self, slack_lp: &SlackFormLP, col: usize, row: usize) {
+        self.check_weights(slack_lp);
+        let line = &slack_lp.get_A()[row];
+        let non_basic = slack_lp.get_N();
+        let alpha = line[col];
+        let entering_weight = self.weights[non_basic[col]];
+
+        for (j, a) in line.iter().enumerate() {
+            if j != col {
+                let ratio = a / alpha;
+                let weight = &mut self.weights[non_basic[j]];
+                *weight = weight.max(ratio * ratio * entering_weight);
+            }
+        }
+        self.weights[slack_lp.get_B()[row]] =
+            (entering_weight / (alpha * alpha)).max(1.);
+    }
+}
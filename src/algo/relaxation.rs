@@ -0,0 +1,151 @@
+use super::super::algo::simplex::simplex_lp_chvatal;
+use super::super::forms::slack::SimplexResult;
+use super::super::forms::standard::StandardFormLP;
+use super::super::types::error::SolverError;
+
+/// Outcome of the feasibility relaxation.
+///
+/// violations has one value per row: a_i . x - b_i when the row is violated
+/// above b_i, a_i . x - (b_i - range_i) (negative) when a range row is
+/// violated below its lower bound, 0.0 otherwise. penalty is
+/// Sum(penalty_i . |violation_i|) and x is the repaired solution.
+#[derive(Debug, PartialEq)]
+pub struct Relaxation {
+    violations: Vec<f64>,
+    penalty: f64,
+    x: Vec<f64>,
+}
+
+impl Relaxation {
+    pub fn get_violations(&self) -> &Vec<f64> { &self.violations }
+
+    pub fn get_penalty(&self) -> f64 { self.penalty }
+
+    pub fn get_x(&self) -> &Vec<f64> { &self.x }
+}
+
+/// Feasibility relaxation (minimum violation) of a linear program.
+///
+/// Each row gets an elastic variable e_i >= 0, which relaxes it:
+/// ```ignore
+///     a_i . x - e_i <= b_i
+/// ```
+/// A range row gets a second one f_i >= 0, for its lower bound:
+/// ```ignore
+///     b_i - range_i <= a_i . x - e_i + f_i <= b_i
+/// ```
+/// The relaxed linear program minimizes Sum(penalty_i . (e_i + f_i)), the
+/// original objective is ignored. A row with an infinite penalty is a hard
+/// constraint, it can not be violated. The variable bounds are kept.
+///
+/// A feasible linear program gives no violation.
+pub fn feasibility_relaxation(
+    standard_lp: &StandardFormLP,
+    penalties: &[f64],
+) -> Result<Relaxation, SolverError> {
+    let a = standard_lp.get_a();
+    let b = standard_lp.get_b();
+    let ranges = standard_lp.get_ranges();
+    let n = standard_lp.get_c().len();
+
+    if penalties.len() != b.len() {
+        return Err(SolverError::DimensionMismatch(
+            "penalties".into(),
+            b.len(),
+            penalties.len(),
+        ));
+    }
+    if penalties.iter().any(|p| p.is_nan() || *p < 0.) {
+        return Err(SolverError::InvalidInput(
+            "Penalties should be non negative numbers.".into(),
+        ));
+    }
+
+    // Elastic variables: row, direction (1.0 above b_i, -1.0 below b_i -
+    // range_i) and penalty
+    let mut elastics = Vec::new();
+    for (row, penalty) in penalties.iter().enumerate() {
+        if penalty.is_finite() {
+            elastics.push((row, 1., *penalty));
+            if ranges[row].is_finite() {
+                elastics.push((row, -1., *penalty));
+            }
+        }
+    }
+
+    let mut c = vec![0.; n];
+    c.extend(elastics.iter().map(|(_, _, penalty)| -penalty));
+    let relaxed_a = a
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            let mut line = line.clone();
+            line.extend(elastics.iter().map(|(row, direction, _)| {
+                if *row == i {
+                    -direction
+                } else {
+                    0.
+                }
+            }));
+            line
+        })
+        .collect();
+
+    let mut relaxed_lp = StandardFormLP::new(
+        c,
+        relaxed_a,
+        b.clone(),
+        standard_lp.get_non_negative_indices().clone(),
+    )?
+    .set_options(standard_lp.get_options().clone());
+    let nni =
+        standard_lp.get_non_negative_indices().clone().unwrap_or_default();
+    for (j, (lower, upper)) in standard_lp
+        .get_lower()
+        .iter()
+        .zip(standard_lp.get_upper().iter())
+        .enumerate()
+    {
+        // The non negative indices are already free variables
+        if !nni.contains(&j) && (*lower != 0. || upper.is_finite()) {
+            relaxed_lp = relaxed_lp.add_variable_bounds(j, *lower, *upper)?;
+        }
+    }
+    for (row, range) in ranges.iter().enumerate() {
+        if range.is_finite() {
+            relaxed_lp = relaxed_lp.add_row_range(row, *range)?;
+        }
+    }
+
+    let mut slack_lp = relaxed_lp.into_slack_form()?;
+    let solution = match simplex_lp_chvatal(&mut slack_lp)? {
+        SimplexResult::Optimal(solution) => solution,
+        SimplexResult::Unfeasible(_) => {
+            return Err(SolverError::Unsolvable(
+                "The hard constraints are unfeasible, the linear program can \
+                 not be relaxed."
+                    .into(),
+            ));
+        },
+        SimplexResult::Unbounded(..) => {
+            return Err(SolverError::Internal(
+                "the relaxed linear program is unbounded.".into(),
+            ));
+        },
+        SimplexResult::Interrupted(interruption, _) => {
+            return Err(SolverError::Interrupted(interruption));
+        },
+    };
+
+    let x = solution.get_x();
+    let mut violations = vec![0.; b.len()];
+    for ((row, direction, _), value) in elastics.iter().zip(x[n..].iter()) {
+        violations[*row] += direction * value;
+    }
+
+    Ok(Relaxation {
+        violations,
+        penalty: -solution.get_objective_value(),
+        x: x[..n].to_vec(),
+    })
+}
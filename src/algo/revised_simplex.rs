@@ -0,0 +1,847 @@
+use super::super::forms::options::{Pricing, SolverOptions};
+use super::super::forms::slack::{
+    Budget, InitializationResult, Interruption, SimplexResult, SlackFormLP,
+    MAX_DEGENERATE_ROUNDS,
+};
+use super::super::forms::solution::Solution;
+use super::super::shared::lu::LUFactorization;
+use super::super::types::error::SolverError;
+
+/// Number of basis updates (eta matrices) before the basis is factorized
+/// again from scratch.
+const REFACTORIZATION_PERIOD: usize = 50;
+/// Number of candidates (the best ones for Dantzig's rule) whose ratio test
+/// is done by the largest improvement rule.
+const IMPROVEMENT_CANDIDATES: usize = 8;
+
+type SparseColumn = Vec<(usize, f64)>; // (row, value) of the non zeros
+
+#[derive(Debug, PartialEq)]
+enum RevisedRound {
+    Finished,
+    Unbounded(usize, f64), // Column of the entering variable, its move (+/-1)
+    Interrupted(Interruption),
+}
+
+/// Revised simplex state.
+///
+/// The dictionary of the slack form is rewritten as a system of equalities:
+/// ```ignore
+///     x_B - A . x_N = b
+/// ```
+/// Each variable owns a sparse column of this system. The columns and the
+/// right hand side are never modified, only the basis B is factorized (sparse
+/// LU) and only the vectors needed by the pricing and the ratio test are
+/// computed: one BTRAN for the reduced costs and one FTRAN for the entering
+/// column at each iteration, plus the ones updating the pricing weights.
+///
+/// The variables are the ones of the dictionary, between 0 and their width
+/// (upper_j - lower_j), free ones excepted. Instead of being flipped, a non
+/// basic variable stays at its upper bound: x holds its value, 0 or width.
+struct RevisedSimplex {
+    n: usize,                   // number of variables of the lp problem
+    columns: Vec<SparseColumn>, // one column per variable
+    rhs: Vec<f64>,
+    non_basic: Vec<usize>,
+    basic: Vec<usize>,
+    x: Vec<f64>,                 // value of each variable
+    widths: Vec<f64>, // upper bound of each variable, infinity if none
+    free: Vec<bool>,  // a free variable can be negative, it never leaves
+    weights: Vec<f64>, // steepest edge or Devex weight of each variable
+    references: Vec<(f64, f64)>, // see SlackFormLP::reference
+    objective: Vec<f64>, // objective of the dictionary, over every variable
+    v: f64,
+    row_rhs: Vec<f64>, // right hand side of the rows of the lp problem
+    factorization: LUFactorization,
+    options: SolverOptions, // tolerances and limits of the slack form
+}
+
+impl RevisedSimplex {
+    fn new(slack_lp: &SlackFormLP) -> Result<RevisedSimplex, SolverError> {
+        let non_basic = slack_lp.get_N().clone();
+        let basic = slack_lp.get_B().clone();
+        let a = slack_lp.get_A();
+        let rows = basic.len();
+        let variables = non_basic.len() + basic.len();
+
+        let mut columns = vec![Vec::new(); variables];
+        let mut objective = vec![0.; variables];
+        for (col, index) in non_basic.iter().enumerate() {
+            if *index >= variables {
+                return Err(SolverError::Internal(
+                    "non basic index is out of bound.".into(),
+                ));
+            }
+            columns[*index] = a
+                .iter()
+                .enumerate()
+                .filter(|(_, line)| line[col] != 0.)
+                .map(|(row, line)| (row, -line[col]))
+                .collect();
+            objective[*index] = slack_lp.get_c()[col];
+        }
+        let mut x = vec![0.; variables];
+        for (row, index) in basic.iter().enumerate() {
+            if *index >= variables {
+                return Err(SolverError::Internal(
+                    "basic index is out of bound.".into(),
+                ));
+            }
+            columns[*index] = vec![(row, 1.)];
+            x[*index] = slack_lp.get_b()[row];
+        }
+
+        let widths = slack_lp
+            .get_lower()
+            .iter()
+            .zip(slack_lp.get_upper().iter())
+            .map(|(lower, upper)| upper - lower)
+            .collect();
+        let free = (0..variables).map(|j| slack_lp.is_free(j)).collect();
+
+        // The initial basis is the identity: B^-1 . a_j = a_j
+        let options = slack_lp.get_options().clone();
+        let weights = if options.get_pricing() == Pricing::SteepestEdge {
+            columns
+                .iter()
+                .map(|column| {
+                    1. + column.iter().map(|(_, a)| a * a).sum::<f64>()
+                })
+                .collect()
+        } else {
+            vec![1.; variables]
+        };
+        let basis: Vec<&[(usize, f64)]> =
+            basic.iter().map(|index| columns[*index].as_slice()).collect();
+        let factorization = LUFactorization::from_columns(rows, &basis)?;
+
+        Ok(RevisedSimplex {
+            n: slack_lp.get_dim(),
+            rhs: slack_lp.get_b().clone(),
+            non_basic,
+            basic,
+            x,
+            widths,
+            free,
+            weights,
+            references: (0..variables).map(|j| slack_lp.reference(j)).collect(),
+            objective,
+            v: slack_lp.get_v(),
+            row_rhs: slack_lp.get_rhs().clone(),
+            factorization,
+            options,
+            columns,
+        })
+    }
+
+    fn dot(column: &[(usize, f64)], y: &[f64]) -> f64 {
+        column.iter().map(|(row, a)| a * y[*row]).sum()
+    }
+
+    fn norm(column: &[f64]) -> f64 { column.iter().map(|a| a * a).sum() }
+
+    /// Column of the variable 'index', as a dense vector
+    fn dense(&self, index: usize) -> Vec<f64> {
+        let mut column = vec![0.; self.basic.len()];
+        for (row, a) in self.columns[index].iter() {
+            column[*row] = *a;
+        }
+
+        column
+    }
+
+    /// B^-1 . a_index (FTRAN)
+    fn transformed(&self, index: usize) -> Vec<f64> {
+        self.factorization.solve(&self.dense(index))
+    }
+
+    /// Row 'row' of B^-1 (BTRAN)
+    fn inverse_row(&self, row: usize) -> Vec<f64> {
+        let mut unit = vec![0.; self.basic.len()];
+        unit[row] = 1.;
+        self.factorization.solve_transpose(&unit)
+    }
+
+    /// Right hand side once the non basic variables are moved to the right:
+    /// rhs - Sum(a_j . x_j) over the non basic variables
+    fn shifted_rhs(&self) -> Vec<f64> {
+        let mut shifted = self.rhs.clone();
+        for index in self.non_basic.iter() {
+            let value = self.x[*index];
+            if value != 0. {
+                for (row, a) in self.columns[*index].iter() {
+                    shifted[*row] -= a * value;
+                }
+            }
+        }
+
+        shifted
+    }
+
+    fn refactorize(&mut self) -> Result<(), SolverError> {
+        let basis: Vec<&[(usize, f64)]> = self
+            .basic
+            .iter()
+            .map(|index| self.columns[*index].as_slice())
+            .collect();
+        self.factorization =
+            LUFactorization::from_columns(self.basic.len(), &basis)?;
+        let x_basic = self.factorization.solve(&self.shifted_rhs());
+        for (index, value) in self.basic.iter().zip(x_basic) {
+            self.x[*index] = value;
+        }
+
+        Ok(())
+    }
+
+    /// Simplex multipliers: y = B^-T . c_B
+    fn compute_duals(&self, cost: &[f64]) -> Vec<f64> {
+        let cost_basic: Vec<f64> =
+            self.basic.iter().map(|i| cost[*i]).collect();
+        self.factorization.solve_transpose(&cost_basic)
+    }
+
+    fn reduced_cost(&self, cost: &[f64], y: &[f64], index: usize) -> f64 {
+        cost[index] - Self::dot(&self.columns[index], y)
+    }
+
+    /// Move of the non basic variable 'index' which improves the objective
+    /// (+1 or -1), given its reduced cost: up from its lower bound, down from
+    /// its upper bound, both ways if it is free. A fixed variable never moves.
+    fn improving_move(&self, index: usize, reduced_cost: f64) -> Option<f64> {
+        let tolerance = self.options.get_dual_feasibility_tolerance();
+        if self.free[index] {
+            if reduced_cost.abs() > tolerance {
+                Some(reduced_cost.signum())
+            } else {
+                None
+            }
+        } else if self.widths[index] == 0. {
+            None
+        } else if self.x[index] > 0. {
+            if reduced_cost < -tolerance {
+                Some(-1.)
+            } else {
+                None
+            }
+        } else if reduced_cost > tolerance {
+            Some(1.)
+        } else {
+            None
+        }
+    }
+
+    /// Move the non basic variable at 'col' by 'step', the basic variables
+    /// follow: x_B = x_B - step . direction. 'direction' is B^-1 . a_q where
+    /// q is the moving variable.
+    fn shift(&mut self, col: usize, step: f64, direction: &[f64]) {
+        for (index, d) in self.basic.iter().zip(direction.iter()) {
+            self.x[*index] -= step * d;
+        }
+        self.x[self.non_basic[col]] += step;
+    }
+
+    /// Swap the non basic variable at 'col' with the basic variable at 'row',
+    /// once moved by 'step'. The leaving variable is set at 'bound', the one
+    /// it has reached. The pricing weights are updated before the basis.
+    fn pivot(
+        &mut self,
+        col: usize,
+        row: usize,
+        step: f64,
+        bound: f64,
+        direction: Vec<f64>,
+    ) -> Result<(), SolverError> {
+        self.update_weights(col, row, &direction);
+        self.shift(col, step, &direction);
+        self.x[self.basic[row]] = bound;
+
+        std::mem::swap(&mut self.non_basic[col], &mut self.basic[row]);
+        self.factorization.update(row, direction)?;
+
+        if self.factorization.get_eta_count() >= REFACTORIZATION_PERIOD {
+            self.refactorize()?;
+        }
+
+        Ok(())
+    }
+
+    /// Ratio test, when the entering variable moves by 'sign' (+1 or -1):
+    /// the basic variable which reaches one of its bounds first, as (step,
+    /// row, bound). Ties are broken with the smallest basic index.
+    fn find_leaving(
+        &self,
+        direction: &[f64],
+        sign: f64,
+    ) -> Option<(f64, usize, f64)> {
+        let pivot_tolerance = self.options.get_pivot_tolerance();
+        let mut leaving: Option<(f64, usize, f64)> = None;
+        for (row, d) in direction.iter().enumerate() {
+            let index = self.basic[row];
+            let alpha = sign * d;
+            let (ratio, bound) = if self.free[index] {
+                continue;
+            } else if alpha > pivot_tolerance {
+                // The basic variable decreases down to 0
+                (self.x[index].max(0.) / alpha, 0.)
+            } else if alpha < -pivot_tolerance && self.widths[index].is_finite()
+            {
+                // The basic variable increases up to its width
+                let width = self.widths[index];
+                ((width - self.x[index]).max(0.) / -alpha, width)
+            } else {
+                continue;
+            };
+            let is_better = match leaving {
+                None => true,
+                Some((best, best_row, _)) => {
+                    ratio < best - pivot_tolerance
+                        || (ratio <= best + pivot_tolerance
+                            && index < self.basic[best_row])
+                },
+            };
+            if is_better {
+                leaving = Some((ratio, row, bound));
+            }
+        }
+
+        leaving
+    }
+
+    /// Width of the variable 'index', infinity if it is free
+    fn width(&self, index: usize) -> f64 {
+        if self.free[index] {
+            f64::INFINITY
+        } else {
+            self.widths[index]
+        }
+    }
+
+    /// Step of the entering variable 'index' moving by 'sign', until a basic
+    /// variable or its own other bound blocks it (infinity if none does).
+    fn step_length(&self, index: usize, direction: &[f64], sign: f64) -> f64 {
+        match self.find_leaving(direction, sign) {
+            Some((step, _, _)) => step.min(self.width(index)),
+            None => self.width(index),
+        }
+    }
+
+    /// Entering variable, as (col, index, sign, reduced cost): the candidate
+    /// with the biggest score, ties being broken with the smallest index.
+    ///
+    /// The scores are the ones of the pricing rules of the tableau version,
+    /// computed from the reduced costs and the weights: steepest edge and
+    /// Devex divide the squared reduced cost by the weight of the variable.
+    /// The largest improvement rule only runs the ratio test of the best
+    /// candidates for Dantzig's rule.
+    fn find_entering(
+        &self,
+        cost: &[f64],
+        pricing: Pricing,
+    ) -> Option<(usize, usize, f64, f64)> {
+        let y = self.compute_duals(cost);
+
+        let mut candidates: Vec<(usize, usize, f64, f64, f64)> = Vec::new();
+        for (col, index) in self.non_basic.iter().enumerate() {
+            let reduced_cost = self.reduced_cost(cost, &y, *index);
+            if let Some(sign) = self.improving_move(*index, reduced_cost) {
+                let score = match pricing {
+                    Pricing::Bland => 0.,
+                    Pricing::Dantzig | Pricing::LargestImprovement => {
+                        reduced_cost.abs()
+                    },
+                    Pricing::SteepestEdge | Pricing::Devex => {
+                        reduced_cost * reduced_cost / self.weights[*index]
+                    },
+                };
+                candidates.push((col, *index, sign, reduced_cost, score));
+            }
+        }
+
+        if pricing == Pricing::LargestImprovement {
+            candidates.sort_by(|l, r| r.4.total_cmp(&l.4).then(l.1.cmp(&r.1)));
+            candidates.truncate(IMPROVEMENT_CANDIDATES);
+            for candidate in candidates.iter_mut() {
+                let direction = self.transformed(candidate.1);
+                let step =
+                    self.step_length(candidate.1, &direction, candidate.2);
+                candidate.4 = candidate.3.abs() * step;
+            }
+        }
+
+        candidates
+            .into_iter()
+            .fold(None, |best: Option<(usize, usize, f64, f64, f64)>, c| {
+                match best {
+                    Some(b) if c.4 < b.4 || (c.4 == b.4 && c.1 > b.1) => {
+                        Some(b)
+                    },
+                    _ => Some(c),
+                }
+            })
+            .map(|(col, index, sign, reduced_cost, _)| {
+                (col, index, sign, reduced_cost)
+            })
+    }
+
+    /// Steepest edge or Devex weights, before the non basic variable at 'col'
+    /// replaces the basic variable at 'row', from the row 'row' of B^-1 . A_N
+    /// (one BTRAN).
+    ///
+    /// The steepest edge weights 1 + ||B^-1 . a_j||^2 are updated exactly
+    /// (Goldfarb and Reid, one more BTRAN), the Devex ones are approximated
+    /// with the same update as the tableau version.
+    fn update_weights(&mut self, col: usize, row: usize, direction: &[f64]) {
+        let pricing = self.options.get_pricing();
+        if pricing != Pricing::SteepestEdge && pricing != Pricing::Devex {
+            return;
+        }
+
+        let rho = self.inverse_row(row);
+        let alpha = direction[row];
+        let entering_weight = if pricing == Pricing::SteepestEdge {
+            1. + Self::norm(direction)
+        } else {
+            self.weights[self.non_basic[col]]
+        };
+        let tau = if pricing == Pricing::SteepestEdge {
+            self.factorization.solve_transpose(direction)
+        } else {
+            Vec::new()
+        };
+
+        for (j, index) in self.non_basic.iter().enumerate() {
+            let ratio = Self::dot(&self.columns[*index], &rho) / alpha;
+            if j == col || ratio == 0. {
+                continue;
+            }
+            let weight = &mut self.weights[*index];
+            *weight = if pricing == Pricing::SteepestEdge {
+                let product = Self::dot(&self.columns[*index], &tau);
+                (*weight - 2. * ratio * product
+                    + ratio * ratio * entering_weight)
+                    .max(1. + ratio * ratio)
+            } else {
+                weight.max(ratio * ratio * entering_weight)
+            };
+        }
+        self.weights[self.basic[row]] =
+            (entering_weight / (alpha * alpha)).max(1.);
+    }
+
+    /// Primal simplex, the entering variable is chosen by the pricing rule of
+    /// the options among the eligible ones, ties in the ratio test are broken
+    /// with the smallest basic index. The entering variable may reach its
+    /// other bound before any basic variable (bound flip, no pivot).
+    ///
+    /// As for the tableau version, Bland's rule replaces the pricing rule
+    /// after too many rounds without improvement of the objective. Each pivot
+    /// and each bound flip is counted against the limits of the options.
+    fn find_optimal(
+        &mut self,
+        cost: &[f64],
+        budget: &mut Budget,
+    ) -> Result<RevisedRound, SolverError> {
+        let mut degenerate_rounds = 0;
+
+        loop {
+            let pricing = if degenerate_rounds < MAX_DEGENERATE_ROUNDS {
+                self.options.get_pricing()
+            } else {
+                Pricing::Bland
+            };
+            let (col, index, sign, reduced_cost) =
+                if let Some(entering) = self.find_entering(cost, pricing) {
+                    entering
+                } else {
+                    return Ok(RevisedRound::Finished);
+                };
+            if let Some(interruption) = budget.spend(&self.options) {
+                return Ok(RevisedRound::Interrupted(interruption));
+            }
+
+            let direction = self.transformed(index);
+            let width = self.width(index);
+            let step = match self.find_leaving(&direction, sign) {
+                Some((step, _, _)) if width < step => {
+                    self.shift(col, sign * width, &direction);
+                    width
+                },
+                Some((step, row, bound)) => {
+                    self.pivot(col, row, sign * step, bound, direction)?;
+                    step
+                },
+                None if width.is_finite() => {
+                    self.shift(col, sign * width, &direction);
+                    width
+                },
+                None => return Ok(RevisedRound::Unbounded(col, sign)),
+            };
+
+            if reduced_cost.abs() * step > 0. {
+                degenerate_rounds = 0;
+            } else {
+                degenerate_rounds += 1;
+            }
+        }
+    }
+
+    /// Phase one, with one auxiliary variable (same as the tableau version).
+    ///
+    /// When the auxiliary variable increases, every basic variable out of its
+    /// bounds goes back between them, and the ones without upper bound are
+    /// increased by the same value.
+    ///
+    /// When a limit of the options is reached, the auxiliary variable is left
+    /// in place: the state can not be used any more.
+    fn initialize(
+        &mut self,
+        budget: &mut Budget,
+    ) -> Result<InitializationResult, SolverError> {
+        let primal_tolerance = self.options.get_primal_feasibility_tolerance();
+        let violations: Vec<f64> = self
+            .basic
+            .iter()
+            .map(|index| {
+                let value = self.x[*index];
+                if self.free[*index] {
+                    0.
+                } else if value < 0. {
+                    -value
+                } else {
+                    (value - self.widths[*index]).max(0.)
+                }
+            })
+            .collect();
+        let mut largest_row = 0;
+        for (row, violation) in violations.iter().enumerate() {
+            if *violation > violations[largest_row] {
+                largest_row = row;
+            }
+        }
+        let largest = violations[largest_row];
+        if largest <= primal_tolerance {
+            return Ok(InitializationResult::Done);
+        }
+
+        // Add the auxiliary variable at the end of the variables
+        let column = self
+            .basic
+            .iter()
+            .zip(violations.iter())
+            .enumerate()
+            .filter_map(|(row, (index, violation))| {
+                let value = self.x[*index];
+                let coef = if self.free[*index] {
+                    0.
+                } else if value > self.widths[*index] {
+                    *violation / largest
+                } else if !self.widths[*index].is_finite() {
+                    -1.
+                } else {
+                    -*violation / largest
+                };
+                if coef != 0. {
+                    Some((row, coef))
+                } else {
+                    None
+                }
+            })
+            .collect();
+        let sup_x = self.columns.len();
+        self.columns.push(column);
+        self.non_basic.push(sup_x);
+        self.x.push(0.);
+        self.widths.push(f64::INFINITY);
+        self.free.push(false);
+        let weight = if self.options.get_pricing() == Pricing::SteepestEdge {
+            1. + Self::norm(&self.transformed(sup_x))
+        } else {
+            1.
+        };
+        self.weights.push(weight);
+        let mut cost = vec![0.; sup_x + 1];
+        cost[sup_x] = -1.;
+
+        // First pivot to make the auxilliary lp problem feasible, the
+        // leaving variable reaches the bound it was violating
+        let leaving = self.basic[largest_row];
+        let bound =
+            if self.x[leaving] < 0. { 0. } else { self.widths[leaving] };
+        let direction = self.transformed(sup_x);
+        let col = self.non_basic.len() - 1;
+        self.pivot(col, largest_row, largest, bound, direction)?;
+
+        match self.find_optimal(&cost, budget)? {
+            RevisedRound::Finished => {},
+            RevisedRound::Interrupted(interruption) => {
+                return Ok(InitializationResult::Interrupted(
+                    interruption,
+                    self.current_point(),
+                ));
+            },
+            RevisedRound::Unbounded(_, _) => {
+                return Err(SolverError::Internal(
+                    "auxiliary problem is unbounded.".into(),
+                ));
+            },
+        }
+
+        let rows = self.basic.len();
+        let sup_x_row = self.basic.iter().position(|index| *index == sup_x);
+        if let Some(row) = sup_x_row {
+            if self.x[sup_x] > primal_tolerance {
+                // Farkas certificate: opposite of the reduced costs of the
+                // slack variables in the auxiliary problem
+                let y = self.compute_duals(&cost);
+                let certificate = (sup_x - rows..sup_x)
+                    .map(|index| Self::dot(&self.columns[index], &y))
+                    .collect();
+                return Ok(InitializationResult::Unfeasible(certificate));
+            }
+
+            // Perform one more pivot in order to put the sup x in non basic,
+            // using the row 'row' of B^-1 . A_N
+            let rho = self.inverse_row(row);
+            let mut best: Option<(f64, usize)> = None;
+            for (col, index) in self.non_basic.iter().enumerate() {
+                let alpha = Self::dot(&self.columns[*index], &rho).abs();
+                let is_better = match best {
+                    None => alpha > self.options.get_pivot_tolerance(),
+                    Some((best_alpha, _)) => alpha > best_alpha,
+                };
+                if is_better {
+                    best = Some((alpha, col));
+                }
+            }
+            if let Some((_, col)) = best {
+                let direction = self.transformed(self.non_basic[col]);
+                let step = self.x[sup_x] / direction[row];
+                self.pivot(col, row, step, 0., direction)?;
+            } else {
+                return Err(SolverError::Internal(
+                    "all values at the degenerated row equal zero.".into(),
+                ));
+            }
+        }
+
+        let sup_x_col = self.non_basic.iter().position(|index| *index == sup_x);
+        if let Some(col) = sup_x_col {
+            self.non_basic.swap_remove(col);
+        } else {
+            return Err(SolverError::Internal(
+                "sup x variable has not been found in non basic variables."
+                    .into(),
+            ));
+        }
+        self.columns.pop();
+        self.x.pop();
+        self.widths.pop();
+        self.free.pop();
+        self.weights.pop();
+
+        Ok(InitializationResult::Done)
+    }
+
+    /// Values of all the variables (slacks included) in the current basis,
+    /// back from the dictionary to the bounded variables.
+    fn compute_values(&self) -> Vec<f64> {
+        self.references
+            .iter()
+            .zip(self.x.iter())
+            .map(|((reference, sign), x)| reference + sign * x)
+            .collect()
+    }
+
+    /// Values of the variables of the lp problem (slacks excepted)
+    fn current_point(&self) -> Vec<f64> {
+        let mut point = self.compute_values();
+        point.truncate(self.n);
+
+        point
+    }
+
+    /// Optimal solution of the current basis, the same as the one of the
+    /// dictionary (see SlackFormLP::compute_solution): the gain of a non
+    /// basic variable is its reduced cost, with the sign of its reference.
+    fn compute_solution(&self) -> Result<SimplexResult, SolverError> {
+        let mut values = self.compute_values();
+        let objective_value = self.v
+            + self
+                .objective
+                .iter()
+                .zip(self.x.iter())
+                .map(|(c, x)| c * x)
+                .sum::<f64>();
+
+        let y = self.compute_duals(&self.objective);
+        let mut gains = vec![0.; values.len()];
+        for index in self.non_basic.iter() {
+            let (_, sign) = self.references[*index];
+            gains[*index] =
+                sign * self.reduced_cost(&self.objective, &y, *index);
+        }
+
+        let slacks = values.split_off(self.n);
+        let row_activities = self
+            .row_rhs
+            .iter()
+            .zip(slacks.iter())
+            .map(|(rhs, slack)| rhs - slack)
+            .collect();
+        let duals = gains.split_off(self.n).into_iter().map(|g| -g).collect();
+
+        let solution = Solution::new(
+            objective_value,
+            values,
+            row_activities,
+            slacks,
+            duals,
+            gains,
+        )?;
+
+        Ok(SimplexResult::Optimal(solution))
+    }
+
+    /// Unbounded ray: the current basic solution and the direction followed
+    /// when the non basic variable at 'col' moves by 'sign', no basic
+    /// variable being blocked.
+    fn compute_ray(&self, col: usize, sign: f64) -> SimplexResult {
+        let entering = self.non_basic[col];
+        let direction = self.transformed(entering);
+
+        let mut moves = vec![0.; self.n];
+        if entering < self.n {
+            moves[entering] = self.references[entering].1 * sign;
+        }
+        for (index, d) in self.basic.iter().zip(direction.iter()) {
+            if *index < self.n {
+                moves[*index] = -self.references[*index].1 * sign * d;
+            }
+        }
+
+        SimplexResult::Unbounded(self.current_point(), moves)
+    }
+
+    /// Both phases. The boolean tells whether the phase two has been reached,
+    /// so that the basis can be written back into the slack form.
+    fn solve(
+        &mut self,
+        budget: &mut Budget,
+    ) -> Result<(SimplexResult, bool), SolverError> {
+        match self.initialize(budget)? {
+            InitializationResult::Done => {},
+            InitializationResult::Unfeasible(certificate) => {
+                // In the variable space, not in the dictionary one
+                let certificate = certificate
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, y)| self.references[self.n + i].1 * y)
+                    .collect();
+                return Ok((SimplexResult::Unfeasible(certificate), false));
+            },
+            InitializationResult::Interrupted(interruption, point) => {
+                return Ok((
+                    SimplexResult::Interrupted(interruption, point),
+                    false,
+                ));
+            },
+        }
+
+        let objective = self.objective.clone();
+        let result = match self.find_optimal(&objective, budget)? {
+            RevisedRound::Finished => self.compute_solution()?,
+            RevisedRound::Unbounded(col, sign) => self.compute_ray(col, sign),
+            RevisedRound::Interrupted(interruption) => {
+                SimplexResult::Interrupted(interruption, self.current_point())
+            },
+        };
+
+        Ok((result, true))
+    }
+
+    /// Write the current basis back into the slack form, as the tableau
+    /// version would have left it: the non basic variables at their upper
+    /// bound are flipped. It costs one FTRAN per non basic variable and the
+    /// whole dense dictionary.
+    fn into_slack_form(
+        mut self,
+        slack_lp: &mut SlackFormLP,
+    ) -> Result<(), SolverError> {
+        self.refactorize()?;
+
+        let rows = self.basic.len();
+        let y = self.compute_duals(&self.objective);
+
+        let mut a = vec![Vec::with_capacity(self.non_basic.len()); rows];
+        let mut c = Vec::with_capacity(self.non_basic.len());
+        for index in self.non_basic.iter() {
+            let alpha = self.transformed(*index);
+            for (row, line) in a.iter_mut().enumerate() {
+                line.push(-alpha[row]);
+            }
+            c.push(self.reduced_cost(&self.objective, &y, *index));
+        }
+
+        // Every non basic variable at 0, as in the dictionary
+        let b = self.factorization.solve(&self.rhs);
+        let v = self.v
+            + self
+                .basic
+                .iter()
+                .zip(b.iter())
+                .map(|(index, x)| self.objective[*index] * x)
+                .sum::<f64>();
+
+        let at_upper: Vec<usize> = self
+            .non_basic
+            .iter()
+            .filter(|index| !self.free[**index] && self.x[**index] > 0.)
+            .cloned()
+            .collect();
+        slack_lp.set_dictionary(self.non_basic, self.basic, a, b, c, v);
+        for index in at_upper.into_iter() {
+            slack_lp.flip(index)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Revised simplex, with the pricing rule of the options.
+///
+/// Same result as simplex_lp_chvatal, but the constraint matrix is never
+/// pivoted: the columns are stored sparse, and a sparse LU factorization of
+/// the basis is maintained instead (updated with eta matrices, and
+/// periodically recomputed). The slack form is not modified, see
+/// simplex_lp_revised_in_place to get the final dictionary.
+///
+/// Variable bounds are handled by the ratio test, as in the tableau version
+/// (bound flips included), and free variables never leave the basis. The
+/// limits of the options are checked before each pivot and each bound flip.
+pub fn simplex_lp_revised(
+    slack_lp: &SlackFormLP,
+) -> Result<SimplexResult, SolverError> {
+    let mut revised = RevisedSimplex::new(slack_lp)?;
+    let (result, _) = revised.solve(&mut Budget::new())?;
+
+    Ok(result)
+}
+
+/// Same as simplex_lp_revised, then the final basis is written back into the
+/// slack form as a dense dictionary (to go on with the dual simplex, or from
+/// an interrupted solve for example).
+///
+/// An unfeasible problem, or an interruption during the initialization,
+/// leaves the slack form untouched.
+pub fn simplex_lp_revised_in_place(
+    slack_lp: &mut SlackFormLP,
+) -> Result<SimplexResult, SolverError> {
+    let mut revised = RevisedSimplex::new(slack_lp)?;
+    let (result, reached_phase_two) = revised.solve(&mut Budget::new())?;
+    if reached_phase_two {
+        revised.into_slack_form(slack_lp)?;
+    }
+
+    Ok(result)
+}
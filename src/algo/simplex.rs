@@ -1,15 +1,53 @@
 use super::super::forms::slack::{
-    InitializationResult, SimplexResult, SlackFormLP,
+    Budget, InitializationResult, SimplexResult, SlackFormLP,
 };
+use super::super::types::error::SolverError;
 
+/// Simplex on the dictionary of the slack form, with the pricing rule of the
+/// solver options (Bland's rule by default). Bland's rule also replaces the
+/// chosen rule after too many degenerate rounds, so that the simplex cannot
+/// cycle.
+///
+/// Variable bounds are handled by the ratio test: a basic variable may leave
+/// at its upper bound, and the entering variable may reach its own upper
+/// bound before any basic variable (bound flip, no pivot). Bounds never
+/// become rows of the dictionary.
+///
+/// The limits of the solver options apply to both phases together.
 pub fn simplex_lp_chvatal(
     slack_lp: &mut SlackFormLP,
-) -> Result<SimplexResult, String> {
-    let init_result = slack_lp.initialize_simplex()?;
+) -> Result<SimplexResult, SolverError> {
+    let mut budget = Budget::new();
+    match slack_lp.initialize_simplex_within(&mut budget)? {
+        InitializationResult::Done => {},
+        InitializationResult::Unfeasible(certificate) => {
+            return Ok(SimplexResult::Unfeasible(certificate));
+        },
+        InitializationResult::Interrupted(interruption, point) => {
+            return Ok(SimplexResult::Interrupted(interruption, point));
+        },
+    }
+
+    let mut rule = slack_lp.get_options().get_pricing().into_rule();
+    slack_lp.find_optimal_within(rule.as_mut(), &mut budget)
+}
 
-    if init_result == InitializationResult::Unfeasible {
-        return Ok(SimplexResult::Unfeasible);
+/// Dual simplex, starting from the current basis of the slack form.
+///
+/// The basis must be dual feasible (c_j <= 0 for every non basic variable),
+/// which is the case of an optimal basis after adding a constraint or
+/// changing the right hand side. The dual simplex then pivots until the basis
+/// is primal feasible, thus optimal.
+pub fn simplex_lp_dual(
+    slack_lp: &mut SlackFormLP,
+) -> Result<SimplexResult, SolverError> {
+    if !slack_lp.is_dual_feasible() {
+        return Err(SolverError::InvalidState(
+            "The current basis is not dual feasible, the dual simplex cannot \
+             start."
+                .into(),
+        ));
     }
 
-    slack_lp.find_optimal()
+    slack_lp.find_dual_optimal()
 }
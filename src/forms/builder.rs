@@ -1,5 +1,9 @@
 use super::super::shared::utils::all_zeroes;
-use super::standard::{check_non_negative_indices, StandardFormLP};
+use super::super::types::error::SolverError;
+use super::standard::{
+    check_integer_indices, check_non_negative_indices, check_variable_bounds,
+    StandardFormLP,
+};
 
 /// Builder for the standard form.
 #[derive(Debug, PartialEq)]
@@ -7,8 +11,16 @@ pub struct StandardFormBuilder {
     c: Option<Vec<f64>>,
     a: Option<Vec<Vec<f64>>>,
     b: Vec<f64>,
+    ranges: Vec<f64>, // infinity for an inequality, 0.0 for an equality
     non_negative_indices: Option<Vec<usize>>,
+    variable_bounds: Vec<(usize, f64, f64)>, // index, lower, upper
+    integer_indices: Option<Vec<usize>>,
     dimension_size: Option<usize>,
+    min_objective: bool,
+}
+
+impl Default for StandardFormBuilder {
+    fn default() -> Self { Self::new() }
 }
 
 impl StandardFormBuilder {
@@ -17,8 +29,12 @@ impl StandardFormBuilder {
             c: None,
             a: None,
             b: Vec::new(),
+            ranges: Vec::new(),
             non_negative_indices: None,
+            variable_bounds: Vec::new(),
+            integer_indices: None,
             dimension_size: None,
+            min_objective: false,
         }
     }
 
@@ -28,30 +44,57 @@ impl StandardFormBuilder {
 
     pub fn get_b(&self) -> &Vec<f64> { &self.b }
 
+    pub fn get_ranges(&self) -> &Vec<f64> { &self.ranges }
+
     pub fn get_non_negative_indices(&self) -> &Option<Vec<usize>> {
         &self.non_negative_indices
     }
 
+    pub fn get_integer_indices(&self) -> &Option<Vec<usize>> {
+        &self.integer_indices
+    }
+
+    pub fn get_variable_bounds(&self) -> &Vec<(usize, f64, f64)> {
+        &self.variable_bounds
+    }
+
     pub fn get_dimension_size(&self) -> &Option<usize> { &self.dimension_size }
 
-    fn check_dimension_size(&mut self, current: usize) -> Result<(), String> {
+    pub fn is_min_objective(&self) -> bool { self.min_objective }
+
+    fn check_dimension_size(
+        &mut self,
+        current: usize,
+    ) -> Result<(), SolverError> {
         if current == 0 {
-            return Err("It is not possible to add an empty vector.".into());
+            return Err(SolverError::EmptyInput("added vector".into()));
         }
         if let Some(nni) = &self.non_negative_indices {
             if nni[nni.len() - 1] >= current {
-                return Err("Non negative indices are out of bound of the \
-                            added vector."
-                    .into());
+                return Err(SolverError::InvalidColumn(nni[nni.len() - 1]));
+            }
+        }
+        if let Some((index, _, _)) =
+            self.variable_bounds.iter().find(|(index, _, _)| *index >= current)
+        {
+            return Err(SolverError::InvalidColumn(*index));
+        }
+        if let Some(indices) = &self.integer_indices {
+            if indices[indices.len() - 1] >= current {
+                return Err(SolverError::InvalidColumn(
+                    indices[indices.len() - 1],
+                ));
             }
         }
         if let Some(size) = self.dimension_size {
             if size == current {
                 Ok(())
             } else {
-                Err("The added vector does not match the current dimension \
-                     size."
-                    .into())
+                Err(SolverError::DimensionMismatch(
+                    "added vector".into(),
+                    size,
+                    current,
+                ))
             }
         } else {
             self.dimension_size = Some(current);
@@ -59,9 +102,11 @@ impl StandardFormBuilder {
         }
     }
 
-    fn check_objective_added(&self) -> Result<(), String> {
+    fn check_objective_added(&self) -> Result<(), SolverError> {
         if self.c.is_some() {
-            return Err("Objective function is already added.".into());
+            return Err(SolverError::InvalidState(
+                "Objective function is already added.".into(),
+            ));
         }
 
         Ok(())
@@ -69,22 +114,27 @@ impl StandardFormBuilder {
 
     fn check_objective_not_zeroes(
         &self,
-        objective: &Vec<f64>,
-    ) -> Result<(), String> {
+        objective: &[f64],
+    ) -> Result<(), SolverError> {
         if all_zeroes(objective) {
-            return Err("It is not possible to add objective with only zero \
-                        values."
-                .into());
+            return Err(SolverError::InvalidInput(
+                "It is not possible to add objective with only zero values."
+                    .into(),
+            ));
         }
 
         Ok(())
     }
 
-    fn check_constraint_not_zeroes(&self, a: &Vec<f64>) -> Result<(), String> {
+    fn check_constraint_not_zeroes(
+        &self,
+        a: &[f64],
+    ) -> Result<(), SolverError> {
         if all_zeroes(a) {
-            return Err("It is not possible to add constraint with only zero \
-                        values."
-                .into());
+            return Err(SolverError::InvalidInput(
+                "It is not possible to add constraint with only zero values."
+                    .into(),
+            ));
         }
 
         Ok(())
@@ -93,13 +143,17 @@ impl StandardFormBuilder {
     /// Add min objective function:
     ///
     /// min c_1 . x_1 + ... + c_n . x_n
-    pub fn add_min_objective(mut self, c: Vec<f64>) -> Result<Self, String> {
+    pub fn add_min_objective(
+        mut self,
+        c: Vec<f64>,
+    ) -> Result<Self, SolverError> {
         self.check_objective_added()?;
         self.check_dimension_size(c.len())?;
         self.check_objective_not_zeroes(&c)?;
 
         let minus_c = c.into_iter().map(|v| -v).collect();
         self.c = Some(minus_c);
+        self.min_objective = true;
 
         Ok(self)
     }
@@ -107,7 +161,10 @@ impl StandardFormBuilder {
     /// Add max objective function
     ///
     /// max c_1 . x_1 + ... + c_n . x_n
-    pub fn add_max_objective(mut self, c: Vec<f64>) -> Result<Self, String> {
+    pub fn add_max_objective(
+        mut self,
+        c: Vec<f64>,
+    ) -> Result<Self, SolverError> {
         self.check_objective_added()?;
         self.check_dimension_size(c.len())?;
         self.check_objective_not_zeroes(&c)?;
@@ -125,7 +182,7 @@ impl StandardFormBuilder {
     pub fn add_non_negative_indices(
         mut self,
         nni: Vec<usize>,
-    ) -> Result<Self, String> {
+    ) -> Result<Self, SolverError> {
         let nni_opt = Some(nni);
         check_non_negative_indices(&nni_opt, self.dimension_size)?;
 
@@ -133,30 +190,85 @@ impl StandardFormBuilder {
             self.non_negative_indices = nni_opt;
             Ok(self)
         } else {
-            Err("Non negative indices have already been set.".into())
+            Err(SolverError::InvalidState(
+                "Non negative indices have already been set.".into(),
+            ))
+        }
+    }
+
+    /// Add integer indices (sorted in ascending order)
+    ///
+    /// [j such as x_j in Integer]
+    /// Indices start at 0
+    /// For example: vec![0, 1];
+    pub fn add_integer_indices(
+        mut self,
+        indices: Vec<usize>,
+    ) -> Result<Self, SolverError> {
+        check_integer_indices(&indices, self.dimension_size)?;
+
+        if self.integer_indices.is_none() {
+            self.integer_indices = Some(indices);
+            Ok(self)
+        } else {
+            Err(SolverError::InvalidState(
+                "Integer indices have already been set.".into(),
+            ))
+        }
+    }
+
+    /// Add variable bounds
+    ///
+    /// lower <= x_index <= upper
+    /// Index starts at 0, both bounds can be infinite (-infinity for the lower
+    /// one). Bounds are not added as constraints, the simplex handles them
+    /// directly. A variable of the non negative indices gets the bounds and
+    /// is no longer free.
+    pub fn add_variable_bounds(
+        mut self,
+        index: usize,
+        lower: f64,
+        upper: f64,
+    ) -> Result<Self, SolverError> {
+        check_variable_bounds(lower, upper)?;
+
+        if let Some(size) = self.dimension_size {
+            if index >= size {
+                return Err(SolverError::InvalidColumn(index));
+            }
+        }
+        if self.variable_bounds.iter().any(|(i, _, _)| *i == index) {
+            return Err(SolverError::InvalidState(format!(
+                "Bounds have already been set for the variable {}.",
+                index
+            )));
         }
+
+        self.variable_bounds.push((index, lower, upper));
+
+        Ok(self)
     }
 
     /// Add equality constraint
     ///
     /// a_1 . x_1 + ... + a_n . x_n = b
+    /// The constraint is a single row, whose slack variable is fixed to 0.0
     pub fn add_equality_constraint(
         mut self,
         a: Vec<f64>,
         b: f64,
-    ) -> Result<Self, String> {
+    ) -> Result<Self, SolverError> {
         self.check_dimension_size(a.len())?;
         self.check_constraint_not_zeroes(&a)?;
 
-        let minus_a = a.iter().map(|v| -v).collect();
-
-        if let Some(mat) = self.a {
-            self.a = Some(vec![mat, vec![a, minus_a]].concat());
+        if let Some(mat) = &mut self.a {
+            mat.push(a);
         } else {
-            self.a = Some(vec![a, minus_a]);
+            self.a = Some(vec![a]);
         }
 
-        self.b.append(&mut vec![b, -b]);
+        self.b.push(b);
+        self.ranges.push(0.);
 
         Ok(self)
     }
@@ -168,7 +280,7 @@ impl StandardFormBuilder {
         mut self,
         a: Vec<f64>,
         b: f64,
-    ) -> Result<Self, String> {
+    ) -> Result<Self, SolverError> {
         self.check_dimension_size(a.len())?;
         self.check_constraint_not_zeroes(&a)?;
 
@@ -179,6 +291,7 @@ impl StandardFormBuilder {
         }
 
         self.b.push(b);
+        self.ranges.push(f64::INFINITY);
 
         Ok(self)
     }
@@ -190,7 +303,7 @@ impl StandardFormBuilder {
         mut self,
         a: Vec<f64>,
         b: f64,
-    ) -> Result<Self, String> {
+    ) -> Result<Self, SolverError> {
         self.check_dimension_size(a.len())?;
         self.check_constraint_not_zeroes(&a)?;
 
@@ -203,65 +316,126 @@ impl StandardFormBuilder {
         }
 
         self.b.push(-b);
+        self.ranges.push(f64::INFINITY);
 
         Ok(self)
     }
 
+    /// Add range constraint
+    ///
+    /// lower <= a_1 . x_1 + ... + a_n . x_n <= upper
+    /// The constraint is a single row, whose slack variable is bounded by
+    /// upper - lower. One of the bounds can be infinite (then it is a less
+    /// than or equal constraint, or a greater than or equal one).
+    pub fn add_range_constraint(
+        self,
+        a: Vec<f64>,
+        lower: f64,
+        upper: f64,
+    ) -> Result<Self, SolverError> {
+        if lower.is_nan() || upper.is_nan() {
+            return Err(SolverError::InvalidInput(
+                "Range bounds should be numbers.".into(),
+            ));
+        }
+        if lower > upper {
+            return Err(SolverError::InvalidInput(format!(
+                "Lower bound is greater than upper bound ({} > {})",
+                lower, upper,
+            )));
+        }
+
+        match (lower.is_finite(), upper.is_finite()) {
+            (false, false) => Err(SolverError::InvalidInput(
+                "At least one of the range bounds should be finite.".into(),
+            )),
+            (false, true) => self.add_less_than_or_equal_constraint(a, upper),
+            (true, false) => {
+                self.add_greater_than_or_equal_constraint(a, lower)
+            },
+            (true, true) => {
+                let mut builder =
+                    self.add_less_than_or_equal_constraint(a, upper)?;
+                if let Some(range) = builder.ranges.last_mut() {
+                    *range = upper - lower;
+                }
+
+                Ok(builder)
+            },
+        }
+    }
+
     /// Build the current linear program into the standard form.
-    pub fn build(self) -> Result<StandardFormLP, String> {
-        let mut c = if let Some(c) = self.c {
+    pub fn build(self) -> Result<StandardFormLP, SolverError> {
+        let c = if let Some(c) = self.c {
             c
         } else {
-            return Err("Cannot build standard form for LP problem, missing \
-                        objective function."
-                .into());
+            return Err(SolverError::InvalidState(
+                "Cannot build standard form for LP problem, missing objective \
+                 function."
+                    .into(),
+            ));
         };
 
-        let mut a = if let Some(a) = self.a {
+        let a = if let Some(a) = self.a {
             a
         } else {
-            return Err("Cannot build standard form for LP problem, missing \
-                        constraints."
-                .into());
+            return Err(SolverError::InvalidState(
+                "Cannot build standard form for LP problem, missing \
+                 constraints."
+                    .into(),
+            ));
         };
         if a.is_empty() {
-            return Err("The impossible happened, matrix 'a' is empty.".into());
+            return Err(SolverError::Internal("matrix 'a' is empty.".into()));
         }
         if a[0].is_empty() {
-            return Err(
-                "The impossible happened, row is matrix 'a' is empty.".into()
-            );
+            return Err(SolverError::Internal(
+                "row is matrix 'a' is empty.".into(),
+            ));
         }
 
         for row in a.iter() {
             if row.len() != c.len() {
-                return Err("The impossible happened, there is a mismatch \
-                            between row in matrix 'a' and vector 'c'."
-                    .into());
+                return Err(SolverError::Internal(
+                    "there is a mismatch between row in matrix 'a' and vector \
+                     'c'."
+                        .into(),
+                ));
             }
         }
 
         let b = self.b;
         if b.is_empty() {
-            return Err("The impossible happened, vector 'b' is empty.".into());
+            return Err(SolverError::Internal("vector 'b' is empty.".into()));
         }
 
         if a.len() != b.len() {
-            return Err("The impossible happened, there is a mismatch \
-                        between the matrix 'a' and the vector 'b'."
-                .into());
+            return Err(SolverError::Internal(
+                "there is a mismatch between the matrix 'a' and the vector \
+                 'b'."
+                    .into(),
+            ));
         }
 
-        if let Some(nni) = &self.non_negative_indices {
-            for i in nni.iter() {
-                c.push(c[*i]);
-
-                for row in a.iter_mut() {
-                    row.push(row[*i]);
-                }
+        let mut standard_lp =
+            StandardFormLP::new(c, a, b, self.non_negative_indices)?;
+        for (index, lower, upper) in self.variable_bounds.into_iter() {
+            standard_lp =
+                standard_lp.add_variable_bounds(index, lower, upper)?;
+        }
+        for (row, range) in self.ranges.into_iter().enumerate() {
+            if range.is_finite() {
+                standard_lp = standard_lp.add_row_range(row, range)?;
             }
         }
+        if let Some(indices) = self.integer_indices {
+            standard_lp = standard_lp.add_integer_indices(indices)?;
+        }
+        if self.min_objective {
+            standard_lp = standard_lp.set_min_objective();
+        }
 
-        Ok(StandardFormLP::new(c, a, b, self.non_negative_indices)?)
+        Ok(standard_lp)
     }
 }
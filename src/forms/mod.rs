@@ -1,3 +1,5 @@
 pub mod builder;
+pub mod options;
 pub mod slack;
+pub mod solution;
 pub mod standard;
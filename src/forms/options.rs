@@ -0,0 +1,435 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering as AtomicOrdering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use super::super::types::error::SolverError;
+
+/// Default value of every tolerance.
+const DEFAULT_TOLERANCE: f64 = 1e-9;
+
+/// Default integrality tolerance of the MILP solvers.
+const DEFAULT_INTEGRALITY_TOLERANCE: f64 = 1e-6;
+
+/// Numerical tolerances, pricing rule and anti degeneracy options of the
+/// simplex.
+///
+/// - primal_feasibility_tolerance: a basic variable is between its bounds if
+///   it is not out of them by more than this value
+/// - dual_feasibility_tolerance: a non basic variable improves the objective
+///   if its gain is greater than this value (optimality test)
+/// - pivot_tolerance: smaller elements of the entering column (or of the
+///   leaving row) are not taken as pivot
+/// - pricing: rule choosing the entering variable of the primal simplex
+///   (Bland's rule by default)
+/// - harris_ratio_test: the ratio test accepts basic variables out of their
+///   bounds by the primal feasibility tolerance, in order to choose the
+///   largest pivot element among the (almost) minimal ratios
+/// - perturbation: the bounds of the basic variables (primal simplex) or the
+///   costs (dual simplex) are slightly perturbed during the solve, so that
+///   degenerate pivots are avoided. The perturbation is removed at the end,
+///   and a few more pivots clean up the solution.
+/// - iteration_limit: maximal number of pivots (and bound flips) of a solve,
+///   of iterations for the interior point method
+/// - time_limit: maximal duration of a solve
+/// - cancel_flag: a solve stops as soon as the flag is cancelled (from
+///   another thread for example)
+/// - iteration_callback: called after each pivot (and bound flip) of the
+///   simplex. With the 'log' feature, the iterations are also logged (trace
+///   level) as well as the end of each solve (debug level).
+///
+/// A solve reaching one of these limits stops with the current basic
+/// solution (see SimplexResult::Interrupted). There is no limit by default.
+#[derive(Debug, PartialEq, PartialOrd, Clone)]
+pub struct SolverOptions {
+    primal_feasibility_tolerance: f64,
+    dual_feasibility_tolerance: f64,
+    pivot_tolerance: f64,
+    pricing: Pricing,
+    harris_ratio_test: bool,
+    perturbation: bool,
+    iteration_limit: Option<usize>,
+    time_limit: Option<Duration>,
+    cancel_flag: Option<CancelFlag>,
+    iteration_callback: Option<IterationCallback>,
+}
+
+impl Default for SolverOptions {
+    fn default() -> Self { Self::new() }
+}
+
+impl SolverOptions {
+    pub fn new() -> SolverOptions {
+        SolverOptions {
+            primal_feasibility_tolerance: DEFAULT_TOLERANCE,
+            dual_feasibility_tolerance: DEFAULT_TOLERANCE,
+            pivot_tolerance: DEFAULT_TOLERANCE,
+            pricing: Pricing::default(),
+            harris_ratio_test: false,
+            perturbation: false,
+            iteration_limit: None,
+            time_limit: None,
+            cancel_flag: None,
+            iteration_callback: None,
+        }
+    }
+
+    pub fn get_primal_feasibility_tolerance(&self) -> f64 {
+        self.primal_feasibility_tolerance
+    }
+
+    pub fn get_dual_feasibility_tolerance(&self) -> f64 {
+        self.dual_feasibility_tolerance
+    }
+
+    pub fn get_pivot_tolerance(&self) -> f64 { self.pivot_tolerance }
+
+    pub fn get_pricing(&self) -> Pricing { self.pricing }
+
+    pub fn set_pricing(mut self, pricing: Pricing) -> Self {
+        self.pricing = pricing;
+        self
+    }
+
+    pub fn is_harris_ratio_test(&self) -> bool { self.harris_ratio_test }
+
+    pub fn set_harris_ratio_test(mut self, enabled: bool) -> Self {
+        self.harris_ratio_test = enabled;
+        self
+    }
+
+    pub fn is_perturbation(&self) -> bool { self.perturbation }
+
+    pub fn set_perturbation(mut self, enabled: bool) -> Self {
+        self.perturbation = enabled;
+        self
+    }
+
+    pub fn get_iteration_limit(&self) -> Option<usize> { self.iteration_limit }
+
+    pub fn set_iteration_limit(mut self, limit: usize) -> Self {
+        self.iteration_limit = Some(limit);
+        self
+    }
+
+    pub fn get_time_limit(&self) -> Option<Duration> { self.time_limit }
+
+    pub fn set_time_limit(mut self, limit: Duration) -> Self {
+        self.time_limit = Some(limit);
+        self
+    }
+
+    pub fn get_cancel_flag(&self) -> Option<&CancelFlag> {
+        self.cancel_flag.as_ref()
+    }
+
+    pub fn set_cancel_flag(mut self, flag: CancelFlag) -> Self {
+        self.cancel_flag = Some(flag);
+        self
+    }
+
+    pub fn get_iteration_callback(&self) -> Option<&IterationCallback> {
+        self.iteration_callback.as_ref()
+    }
+
+    pub fn set_iteration_callback(
+        mut self,
+        callback: IterationCallback,
+    ) -> Self {
+        self.iteration_callback = Some(callback);
+        self
+    }
+
+    pub fn set_primal_feasibility_tolerance(
+        mut self,
+        tolerance: f64,
+    ) -> Result<Self, SolverError> {
+        check_tolerance(tolerance)?;
+        self.primal_feasibility_tolerance = tolerance;
+
+        Ok(self)
+    }
+
+    pub fn set_dual_feasibility_tolerance(
+        mut self,
+        tolerance: f64,
+    ) -> Result<Self, SolverError> {
+        check_tolerance(tolerance)?;
+        self.dual_feasibility_tolerance = tolerance;
+
+        Ok(self)
+    }
+
+    pub fn set_pivot_tolerance(
+        mut self,
+        tolerance: f64,
+    ) -> Result<Self, SolverError> {
+        check_tolerance(tolerance)?;
+        self.pivot_tolerance = tolerance;
+
+        Ok(self)
+    }
+}
+
+fn check_tolerance(tolerance: f64) -> Result<(), SolverError> {
+    if !tolerance.is_finite() || tolerance < 0. {
+        return Err(SolverError::InvalidInput(
+            "Tolerance should be a non negative number.".into(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Pricing rules available through the solver options (see
+/// algo::pricing for the rules themselves).
+#[derive(Debug, Default, PartialEq, PartialOrd, Clone, Copy)]
+pub enum Pricing {
+    #[default]
+    Bland,
+    Dantzig,
+    LargestImprovement,
+    SteepestEdge,
+    Devex,
+}
+
+/// Options of the MILP solvers (branch and bound and Gomory cuts), the
+/// relaxations are solved with the solver options of the linear program.
+///
+/// - integrality_tolerance: a value is taken as integer if it is not farther
+///   from the nearest integer than this value
+/// - mip_gap_tolerance: the branch and bound prunes the nodes which can not
+///   improve the incumbent by more than this relative gap
+/// - node_selection: order in which the branch and bound processes the open
+///   nodes (depth first by default)
+/// - branching: rule choosing the variable the branch and bound branches on
+///   (the most fractional one by default)
+/// - cut_rounds: rounds of Gomory mixed integer cuts added to the relaxation
+///   of each node of the branch and bound (none by default)
+#[derive(Debug, PartialEq, PartialOrd, Clone)]
+pub struct MilpOptions {
+    integrality_tolerance: f64,
+    mip_gap_tolerance: f64,
+    node_selection: NodeSelection,
+    branching: Branching,
+    cut_rounds: usize,
+}
+
+impl Default for MilpOptions {
+    fn default() -> Self { Self::new() }
+}
+
+impl MilpOptions {
+    pub fn new() -> MilpOptions {
+        MilpOptions {
+            integrality_tolerance: DEFAULT_INTEGRALITY_TOLERANCE,
+            mip_gap_tolerance: DEFAULT_TOLERANCE,
+            node_selection: NodeSelection::default(),
+            branching: Branching::default(),
+            cut_rounds: 0,
+        }
+    }
+
+    pub fn get_integrality_tolerance(&self) -> f64 {
+        self.integrality_tolerance
+    }
+
+    pub fn get_mip_gap_tolerance(&self) -> f64 { self.mip_gap_tolerance }
+
+    pub fn get_node_selection(&self) -> NodeSelection { self.node_selection }
+
+    pub fn set_node_selection(mut self, selection: NodeSelection) -> Self {
+        self.node_selection = selection;
+        self
+    }
+
+    pub fn get_branching(&self) -> Branching { self.branching }
+
+    pub fn set_branching(mut self, branching: Branching) -> Self {
+        self.branching = branching;
+        self
+    }
+
+    pub fn get_cut_rounds(&self) -> usize { self.cut_rounds }
+
+    pub fn set_cut_rounds(mut self, rounds: usize) -> Self {
+        self.cut_rounds = rounds;
+        self
+    }
+
+    pub fn set_integrality_tolerance(
+        mut self,
+        tolerance: f64,
+    ) -> Result<Self, SolverError> {
+        check_tolerance(tolerance)?;
+        self.integrality_tolerance = tolerance;
+
+        Ok(self)
+    }
+
+    pub fn set_mip_gap_tolerance(
+        mut self,
+        tolerance: f64,
+    ) -> Result<Self, SolverError> {
+        check_tolerance(tolerance)?;
+        self.mip_gap_tolerance = tolerance;
+
+        Ok(self)
+    }
+}
+
+/// Order in which the branch and bound processes the open nodes.
+///
+/// - DepthFirst: the last created node first (the down branch before the up
+///   one), which finds a first integer solution quickly
+/// - BestBound: the node with the greatest bound first, which proves the
+///   optimality with fewer nodes
+/// - BestEstimate: the node with the greatest estimate of its best integer
+///   solution first: the objective value of its parent relaxation minus the
+///   cheapest expected degradation (see algo::branching::Pseudocosts) to
+///   round each fractional variable
+/// - Hybrid: depth first until an integer solution is found, best bound
+///   afterwards
+///
+/// Ties are broken with the last created node.
+#[derive(Debug, Default, PartialEq, PartialOrd, Clone, Copy)]
+pub enum NodeSelection {
+    #[default]
+    DepthFirst,
+    BestBound,
+    BestEstimate,
+    Hybrid,
+}
+
+/// Branching rules available through the MILP options (see algo::branching
+/// for the rules themselves).
+///
+/// iterations is the maximal number of pivots of each trial solve of the
+/// strong branching, reliability the number of observations after which
+/// the pseudocosts of a variable are trusted.
+#[derive(Debug, Default, PartialEq, PartialOrd, Clone, Copy)]
+pub enum Branching {
+    #[default]
+    MostFractional,
+    Pseudocost,
+    Strong {
+        iterations: usize,
+    },
+    Reliability {
+        iterations: usize,
+        reliability: usize,
+    },
+}
+
+/// Cancellation flag shared between a solve and its caller.
+///
+/// The clones of a flag share the same state: cancelling one of them cancels
+/// every solve using any of them.
+#[derive(Debug, Clone, Default)]
+pub struct CancelFlag(Arc<AtomicBool>);
+
+impl CancelFlag {
+    pub fn new() -> CancelFlag { CancelFlag::default() }
+
+    pub fn cancel(&self) { self.0.store(true, AtomicOrdering::SeqCst); }
+
+    pub fn is_cancelled(&self) -> bool { self.0.load(AtomicOrdering::SeqCst) }
+}
+
+/// Two flags are equal if they share the same state.
+impl PartialEq for CancelFlag {
+    fn eq(&self, other: &Self) -> bool { Arc::ptr_eq(&self.0, &other.0) }
+}
+
+impl PartialOrd for CancelFlag {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self == other {
+            Some(Ordering::Equal)
+        } else {
+            None
+        }
+    }
+}
+
+/// State of the simplex after an iteration.
+///
+/// - iteration: number of iterations since the beginning of the solve
+/// - objective: objective value 'v' of the dictionary
+/// - entering: index of the variable entering the basis (or of the variable
+///   moving to its other bound, for a bound flip)
+/// - leaving: index of the variable leaving the basis, None for a bound flip
+/// - primal_infeasibility: sum of the bound violations of the basic
+///   variables, 0.0 once the basis is primal feasible
+#[derive(Debug, PartialEq, Clone)]
+pub struct IterationInfo {
+    iteration: usize,
+    objective: f64,
+    entering: usize,
+    leaving: Option<usize>,
+    primal_infeasibility: f64,
+}
+
+impl IterationInfo {
+    pub fn new(
+        iteration: usize,
+        objective: f64,
+        entering: usize,
+        leaving: Option<usize>,
+        primal_infeasibility: f64,
+    ) -> IterationInfo {
+        IterationInfo {
+            iteration,
+            objective,
+            entering,
+            leaving,
+            primal_infeasibility,
+        }
+    }
+
+    pub fn get_iteration(&self) -> usize { self.iteration }
+
+    pub fn get_objective(&self) -> f64 { self.objective }
+
+    pub fn get_entering(&self) -> usize { self.entering }
+
+    pub fn get_leaving(&self) -> Option<usize> { self.leaving }
+
+    pub fn get_primal_infeasibility(&self) -> f64 { self.primal_infeasibility }
+}
+
+/// Function called after each iteration of the simplex.
+#[derive(Clone)]
+pub struct IterationCallback(Arc<dyn Fn(&IterationInfo) + Send + Sync>);
+
+impl IterationCallback {
+    pub fn new<F>(callback: F) -> IterationCallback
+    where
+        F: Fn(&IterationInfo) + Send + Sync + 'static,
+    {
+        IterationCallback(Arc::new(callback))
+    }
+
+    pub fn call(&self, info: &IterationInfo) { (self.0)(info) }
+}
+
+impl fmt::Debug for IterationCallback {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "IterationCallback")
+    }
+}
+
+/// Two callbacks are equal if they share the same function.
+impl PartialEq for IterationCallback {
+    fn eq(&self, other: &Self) -> bool { Arc::ptr_eq(&self.0, &other.0) }
+}
+
+impl PartialOrd for IterationCallback {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self == other {
+            Some(Ordering::Equal)
+        } else {
+            None
+        }
+    }
+}
@@ -1,30 +1,133 @@
 use std::cmp::PartialOrd;
 use std::fmt;
+use std::time::Instant;
+
+use super::super::algo::pricing::{Bland, PricingRule};
+use super::super::types::error::SolverError;
+use super::options::{IterationInfo, SolverOptions};
+use super::solution::Solution;
+use super::standard::check_variable_bounds;
+
+/// Number of consecutive rounds without any improvement of the objective
+/// after which Bland's rule replaces the pricing rule (anti cycling).
+pub(crate) const MAX_DEGENERATE_ROUNDS: usize = 50;
+/// Relative size of the perturbation of the bounds and of the costs.
+const PERTURBATION: f64 = 1e-7;
 
 #[derive(Debug, PartialEq)]
 pub enum InitializationResult {
     Done,
-    Unfeasible,
+    Unfeasible(Vec<f64>), // Farkas certificate
+    Interrupted(Interruption, Vec<f64>), // Reason, current point
 }
 
 #[derive(Debug, PartialEq)]
 pub enum Leaving {
     Unbounded,
     Info(f64, usize, usize), // delta, row index, basic index
+    Flip(f64),               // delta, the entering variable reaches its bound
 }
 
 #[derive(Debug, PartialEq)]
 pub enum SimplexRound {
     Finished,
-    Unbounded,
+    Unbounded(usize),     // Column
+    Switch(usize, usize), // Column, Row
+    Flip(usize),          // Column
+}
+
+#[derive(Debug, PartialEq)]
+pub enum DualSimplexRound {
+    Finished,
+    Unfeasible(usize),    // Row
     Switch(usize, usize), // Column, Row
 }
 
+/// End of the rounds of the primal or of the dual simplex.
+#[derive(Debug, PartialEq)]
+enum Rounds {
+    Finished,
+    Unbounded(usize),  // Column (primal simplex)
+    Unfeasible(usize), // Row (dual simplex)
+    Interrupted(Interruption),
+}
+
+/// Limit of the solver options reached before the end of a solve.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Interruption {
+    IterationLimit,
+    TimeLimit,
+    Cancelled,
+}
+
+impl fmt::Display for Interruption {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Interruption::IterationLimit => write!(f, "iteration limit"),
+            Interruption::TimeLimit => write!(f, "time limit"),
+            Interruption::Cancelled => write!(f, "cancelled"),
+        }
+    }
+}
+
+/// Iterations done since the beginning of a solve (pivots and bound flips of
+/// the simplex, steps of the interior point method), checked against the
+/// limits of the solver options.
+pub(crate) struct Budget {
+    iterations: usize,
+    start: Instant,
+}
+
+impl Budget {
+    pub(crate) fn new() -> Budget {
+        Budget { iterations: 0, start: Instant::now() }
+    }
+
+    /// Count one more iteration, unless a limit is already reached.
+    pub(crate) fn spend(
+        &mut self,
+        options: &SolverOptions,
+    ) -> Option<Interruption> {
+        if options.get_cancel_flag().is_some_and(|flag| flag.is_cancelled()) {
+            return Some(Interruption::Cancelled);
+        }
+        if options.get_iteration_limit().is_some_and(|l| self.iterations >= l) {
+            return Some(Interruption::IterationLimit);
+        }
+        if options.get_time_limit().is_some_and(|l| self.start.elapsed() >= l) {
+            return Some(Interruption::TimeLimit);
+        }
+        self.iterations += 1;
+
+        None
+    }
+}
+
+/// Outcome of the simplex.
+///
+/// An unfeasible linear program comes with a Farkas certificate y, one value
+/// per row of the standard form (a . x <= b): y >= 0, y^T . a >= 0 and
+/// y^T . b < 0, so that no x >= 0 satisfies y^T . a . x <= y^T . b.
+/// With variable bounds or range rows, y_i is negative for a row which is
+/// tight at its lower bound, and the contradiction is: the minimum of
+/// y^T . a . x over the variable bounds is greater than y^T . b (b being the
+/// bound of the row which is tight).
+///
+/// An unbounded linear program comes with a feasible point x and a direction
+/// d (both over the variables of the standard form): x + t . d is feasible
+/// for every t >= 0 and the objective increases with t. The variables with
+/// d_j != 0 are the ones missing a bound. The point is empty when the method
+/// does not know any feasible point (interior point method).
+///
+/// A solve reaching a limit of the solver options (iterations, time or
+/// cancellation) is interrupted, and comes with the values of the variables
+/// in the current basic solution, which may be neither feasible nor optimal.
 #[derive(Debug, PartialEq)]
 pub enum SimplexResult {
-    Unbounded,
-    Unfeasible,
-    Optimal(Vec<f64>),
+    Unbounded(Vec<f64>, Vec<f64>), // Feasible point, direction
+    Unfeasible(Vec<f64>),          // Farkas certificate
+    Optimal(Solution),
+    Interrupted(Interruption, Vec<f64>), // Reason, current point
 }
 
 /// Linear Programming, Slack form.
@@ -73,6 +176,17 @@ pub enum SimplexResult {
 ///         c = ( -1/6, -1/6 + 1/3)
 ///         v = 28
 /// ```
+/// Bounded variables: lower_j <= x_j <= upper_j
+///
+/// The dictionary does not use x_j directly, but x_j - lower_j (or
+/// upper_j - x_j when the variable is flipped), which is between 0 and
+/// upper_j - lower_j. So a non basic variable always equals 0 in the
+/// dictionary, whether it is at its lower or its upper bound. By default
+/// lower_j = 0 and upper_j = infinity, the dictionary uses x_j.
+///
+/// A variable without lower bound is always flipped, unless it is free (no
+/// bound at all). A free variable is x_j (or -x_j when flipped) in the
+/// dictionary, it can be negative, and it never leaves the basis.
 #[derive(Debug, PartialEq, PartialOrd, Clone)]
 pub struct SlackFormLP {
     N: Vec<usize>, // Non Basic variables
@@ -81,7 +195,12 @@ pub struct SlackFormLP {
     b: Vec<f64>,
     c: Vec<f64>,
     v: f64,
-    n: usize, // dimension problem (number of cols)
+    n: usize,           // dimension problem (number of cols)
+    lower: Vec<f64>,    // lower bound of each variable (slack ones included)
+    upper: Vec<f64>,    // upper bound of each variable, infinity if none
+    flipped: Vec<bool>, // x_j is replaced by upper_j - x_j in the dictionary
+    rhs: Vec<f64>,      // x_n+i = rhs_i - Sum(a_ij . x_j) for each row i
+    options: SolverOptions,
 }
 
 impl SlackFormLP {
@@ -89,107 +208,370 @@ impl SlackFormLP {
         A: Vec<Vec<f64>>,
         b: Vec<f64>,
         c: Vec<f64>,
-    ) -> Result<SlackFormLP, String> {
+    ) -> Result<SlackFormLP, SolverError> {
         // The first instance must comply to these following constraints:
         if A.is_empty() {
-            return Err("Matrix 'A' should not be empty".into());
+            return Err(SolverError::EmptyInput("matrix 'A'".into()));
         }
         if b.is_empty() {
-            return Err("Vector 'b' should not be empty".into());
+            return Err(SolverError::EmptyInput("vector 'b'".into()));
         }
         if c.is_empty() {
-            return Err("Vector 'c' should not be empty".into());
-        }
-        if c.len() != A[0].len() {
-            return Err(format!(
-                "Matrix 'A' rows count does not match with vector 'c' size \
-                 ({} != {})",
-                A[0].len(),
-                c.len(),
-            ));
+            return Err(SolverError::EmptyInput("vector 'c'".into()));
         }
-        for line in A.iter() {
+        // Each and every row in the matrix 'A' should have the size of 'c'
+        for (row, line) in A.iter().enumerate() {
             if line.len() != c.len() {
-                return Err("Each and every row in the matrix 'A' should \
-                            have the same size"
-                    .into());
+                return Err(SolverError::DimensionMismatch(
+                    format!("row {} of the matrix 'A'", row),
+                    c.len(),
+                    line.len(),
+                ));
             }
         }
         if A.len() != b.len() {
-            return Err(format!(
-                "Matrix 'A' columns count does not match with vector 'b' size \
-                 ({} != {})",
+            return Err(SolverError::DimensionMismatch(
+                "vector 'b'".into(),
                 A.len(),
                 b.len(),
             ));
         }
 
-        let N: Vec<usize> = (0..c.len()).into_iter().collect();
-        let B: Vec<usize> = (c.len()..c.len() + b.len()).into_iter().collect();
+        let N: Vec<usize> = (0..c.len()).collect();
+        let B: Vec<usize> = (c.len()..c.len() + b.len()).collect();
         let v = 0.;
         let n = c.len();
-
-        Ok(SlackFormLP { N, B, A, b, c, v, n })
+        let lower = vec![0.; n + b.len()];
+        let upper = vec![f64::INFINITY; n + b.len()];
+        let flipped = vec![false; n + b.len()];
+        let rhs = b.clone();
+        let options = SolverOptions::default();
+
+        Ok(SlackFormLP {
+            N,
+            B,
+            A,
+            b,
+            c,
+            v,
+            n,
+            lower,
+            upper,
+            flipped,
+            rhs,
+            options,
+        })
     }
 
     pub fn get_dim(&self) -> usize { self.n }
 
+    pub fn get_N(&self) -> &Vec<usize> { &self.N }
+
+    pub fn get_B(&self) -> &Vec<usize> { &self.B }
+
+    pub fn get_A(&self) -> &Vec<Vec<f64>> { &self.A }
+
+    pub fn get_b(&self) -> &Vec<f64> { &self.b }
+
+    pub fn get_c(&self) -> &Vec<f64> { &self.c }
+
+    pub fn get_v(&self) -> f64 { self.v }
+
+    pub fn get_lower(&self) -> &Vec<f64> { &self.lower }
+
+    pub fn get_upper(&self) -> &Vec<f64> { &self.upper }
+
+    pub fn get_flipped(&self) -> &Vec<bool> { &self.flipped }
+
+    pub fn get_rhs(&self) -> &Vec<f64> { &self.rhs }
+
+    pub fn get_options(&self) -> &SolverOptions { &self.options }
+
+    /// Tolerances used by the simplex from now on
+    pub fn set_options(&mut self, options: SolverOptions) {
+        self.options = options;
+    }
+
+    /// Range of the variable in the dictionary: upper_j - lower_j
+    fn width(&self, index: usize) -> f64 {
+        self.upper[index] - self.lower[index]
+    }
+
+    /// A free variable has neither lower bound nor upper bound
+    pub fn is_free(&self, index: usize) -> bool {
+        self.lower[index] == f64::NEG_INFINITY
+            && self.upper[index] == f64::INFINITY
+    }
+
+    /// Shift of the dictionary when the variable is flipped: its width, or 0
+    /// for a free variable (the flip is a negation then)
+    fn flip_width(&self, index: usize) -> f64 {
+        if self.is_free(index) {
+            0.
+        } else {
+            self.width(index)
+        }
+    }
+
+    /// The variable x_j equals reference + sign . x_j' where x_j' is the
+    /// variable used in the dictionary
+    pub(crate) fn reference(&self, index: usize) -> (f64, f64) {
+        if self.flipped[index] {
+            let upper = self.upper[index];
+            (if upper.is_finite() { upper } else { 0. }, -1.)
+        } else {
+            let lower = self.lower[index];
+            (if lower.is_finite() { lower } else { 0. }, 1.)
+        }
+    }
+
+    /// Replace the bounds of the variable x_index (slack variables included).
+    ///
+    /// The variable stays at the same side (the upper one if it is flipped,
+    /// the lower one otherwise) when the new bounds allow it, and its value
+    /// in the dictionary is shifted according to the new bound. The
+    /// dictionary stays dual feasible unless the variable changes side, but
+    /// the basic variables may be out of their bounds.
+    pub fn set_variable_bounds(
+        &mut self,
+        index: usize,
+        lower: f64,
+        upper: f64,
+    ) -> Result<(), SolverError> {
+        check_variable_bounds(lower, upper)?;
+        if index >= self.lower.len() {
+            return Err(SolverError::InvalidColumn(index));
+        }
+
+        let (reference, sign) = self.reference(index);
+        self.flipped[index] = if self.flipped[index] {
+            upper.is_finite() || !lower.is_finite()
+        } else {
+            upper.is_finite() && !lower.is_finite()
+        };
+        self.lower[index] = lower;
+        self.upper[index] = upper;
+        let (new_reference, new_sign) = self.reference(index);
+
+        // x_j' (new) = k . x_j' (old) + delta
+        let k = sign * new_sign;
+        let delta = new_sign * (reference - new_reference);
+        if let Some(row) = self.B.iter().position(|basic| *basic == index) {
+            self.b[row] = k * self.b[row] + delta;
+            for elem in self.A[row].iter_mut() {
+                *elem *= k;
+            }
+        } else if let Some(col) = self.N.iter().position(|nb| *nb == index) {
+            for (b, line) in self.b.iter_mut().zip(self.A.iter_mut()) {
+                *b -= k * delta * line[col];
+                line[col] *= k;
+            }
+            self.v -= k * delta * self.c[col];
+            self.c[col] *= k;
+        } else {
+            return Err(SolverError::Internal(
+                "variable is neither basic nor non basic.".into(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Replace x_index by its complement in the dictionary (see flipped).
+    pub(crate) fn flip(&mut self, index: usize) -> Result<(), SolverError> {
+        if let Some(row) = self.B.iter().position(|basic| *basic == index) {
+            self.flip_basic(row);
+        } else if let Some(col) = self.N.iter().position(|nb| *nb == index) {
+            self.flip_non_basic(col);
+        } else {
+            return Err(SolverError::Internal(
+                "variable is neither basic nor non basic.".into(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// x_B' = width - x_B = (width - b) - Sum(a_j . x_j)
+    fn flip_basic(&mut self, row: usize) {
+        let basic = self.B[row];
+        self.b[row] = self.flip_width(basic) - self.b[row];
+        for elem in self.A[row].iter_mut() {
+            *elem = -*elem;
+        }
+        self.flipped[basic] = !self.flipped[basic];
+    }
+
+    /// x_N = width - x_N', the non basic variable moves to its other bound
+    fn flip_non_basic(&mut self, col: usize) {
+        let non_basic = self.N[col];
+        let width = self.flip_width(non_basic);
+        for (b, line) in self.b.iter_mut().zip(self.A.iter_mut()) {
+            *b += line[col] * width;
+            line[col] = -line[col];
+        }
+        self.v += self.c[col] * width;
+        self.c[col] = -self.c[col];
+        self.flipped[non_basic] = !self.flipped[non_basic];
+    }
+
+    /// Flip the free non basic variables which would improve the objective
+    /// by decreasing, so that they improve it by increasing.
+    fn flip_free_non_basic(&mut self) {
+        for col in 0..self.N.len() {
+            if self.c[col] < -self.options.get_dual_feasibility_tolerance()
+                && self.is_free(self.N[col])
+            {
+                self.flip_non_basic(col);
+            }
+        }
+    }
+
+    /// Flip the basic variables above their upper bound, so that they are
+    /// below their (new) lower bound instead.
+    fn flip_basic_above_upper(&mut self) {
+        for row in 0..self.B.len() {
+            if self.b[row]
+                > self.width(self.B[row])
+                    + self.options.get_primal_feasibility_tolerance()
+            {
+                self.flip_basic(row);
+            }
+        }
+    }
+
+    /// Replace the whole dictionary, the dimension is left untouched.
+    /// Used by the algorithms which do not work on the tableau directly.
+    pub(crate) fn set_dictionary(
+        &mut self,
+        N: Vec<usize>,
+        B: Vec<usize>,
+        A: Vec<Vec<f64>>,
+        b: Vec<f64>,
+        c: Vec<f64>,
+        v: f64,
+    ) {
+        self.N = N;
+        self.B = B;
+        self.A = A;
+        self.b = b;
+        self.c = c;
+        self.v = v;
+    }
+
+    /// First phase of the simplex: find a feasible basis.
+    ///
+    /// An interrupted initialization leaves a consistent dictionary, but its
+    /// basis may not be feasible yet. The initialization can be run again.
     pub fn initialize_simplex(
         &mut self,
-    ) -> Result<InitializationResult, String> {
-        // Find the smallest b_j and its index
-        let mut smallest_b = self.b[0];
+    ) -> Result<InitializationResult, SolverError> {
+        self.initialize_simplex_within(&mut Budget::new())
+    }
+
+    pub(crate) fn initialize_simplex_within(
+        &mut self,
+        budget: &mut Budget,
+    ) -> Result<InitializationResult, SolverError> {
+        // A basic variable above its upper bound is infeasible as well
+        self.flip_basic_above_upper();
+
+        // Find the smallest b_j and its index (a free basic variable can be
+        // negative)
+        let primal_tolerance = self.options.get_primal_feasibility_tolerance();
+        let mut smallest_b = 0.;
         let mut smallest_row = 0;
-        for (j, b) in self.b.iter().enumerate().skip(1) {
-            if *b < smallest_b {
+        for (j, b) in self.b.iter().enumerate() {
+            if *b < smallest_b && !self.is_free(self.B[j]) {
                 smallest_b = *b;
                 smallest_row = j;
             }
         }
 
-        // If all b_j >= 0. there is a basic feasible solution, nothing to do
-        // then
-        if smallest_b >= 0. {
+        // If all b_j >= 0. (up to the tolerance) there is a basic feasible
+        // solution, nothing to do then
+        if smallest_b >= -primal_tolerance {
             return Ok(InitializationResult::Done);
         }
 
         // Otherwise, we have to find a non basic feasible solution
         // Thus, we add a new variable, increasing dimension by 1
 
-        // Let's save the current objective
-        let saved_c = self.c.clone();
+        // Let's save the current objective, expressed over every variable
+        let saved_objective = self.objective_over_variables();
+        let saved_v = self.v;
+        let saved_flipped = self.flipped.clone();
 
         // And update c accordingly with the algorithm to find a non basic
         // solution
         self.c = vec![0.; self.n];
         self.c.push(-1.); // Add sup x variable
 
-        // Update Basic and Non Basic indices
-        self.N.push(self.n); // Add sup x variable
-        for elem in self.B.iter_mut() {
-            *elem += 1;
+        // Update Basic and Non Basic indices, the sup x variable takes the
+        // index n (the first slack variable index)
+        for elem in self.N.iter_mut().chain(self.B.iter_mut()) {
+            if *elem >= self.n {
+                *elem += 1;
+            }
         }
+        self.N.push(self.n); // Add sup x variable
+        self.lower.insert(self.n, 0.);
+        self.upper.insert(self.n, f64::INFINITY);
+        self.flipped.insert(self.n, false);
 
         // Increase dimension by 1
         self.n += 1;
 
-        // Update A
-        for row in self.A.iter_mut() {
-            row.push(1.); // Add sup x variable
+        // Update A, add sup x variable. When sup x enters the basis (with the
+        // value -smallest_b), the basic variables without upper bound are
+        // increased by the same value. Those with an upper bound (the slack
+        // variable of an equality for example) are increased up to 0 if they
+        // are negative, and are left untouched otherwise, as well as the free
+        // ones.
+        for (row, line) in self.A.iter_mut().enumerate() {
+            let basic = self.B[row];
+            let width = self.upper[basic] - self.lower[basic];
+            let is_free = self.lower[basic] == f64::NEG_INFINITY
+                && self.upper[basic] == f64::INFINITY;
+            let coef = if is_free {
+                0.
+            } else if !width.is_finite() {
+                1.
+            } else if self.b[row] < -primal_tolerance {
+                self.b[row] / smallest_b
+            } else {
+                0.
+            };
+            line.push(coef);
         }
 
         // First pivot to make the auxilliary lp problem feasible
         self.pivot(self.n - 1, smallest_row);
 
-        let result = match self.find_optimal()? {
-            SimplexResult::Optimal(solution) if solution[self.n - 1] == 0. => {
-                InitializationResult::Done
+        let mut rule = self.options.get_pricing().into_rule();
+        let mut interrupted = None;
+        match self.find_optimal_within(rule.as_mut(), budget)? {
+            SimplexResult::Optimal(solution)
+                if solution.get_x()[self.n - 1] > primal_tolerance =>
+            {
+                // The shadow prices of the auxiliary problem combine the rows
+                // into a contradiction: the sup x variable can not be 0
+                return Ok(InitializationResult::Unfeasible(
+                    solution.get_duals().clone(),
+                ));
+            },
+            SimplexResult::Optimal(_) => {},
+            SimplexResult::Interrupted(interruption, mut point) => {
+                // The sup x variable is removed all the same, so that the
+                // dictionary matches the initial lp problem again
+                point.truncate(self.n - 1);
+                interrupted = Some((interruption, point));
+            },
+            _ => {
+                return Err(SolverError::Internal(
+                    "auxiliary problem has no optimal solution.".into(),
+                ));
             },
-            _ => InitializationResult::Unfeasible,
-        };
-
-        if result == InitializationResult::Unfeasible {
-            return Ok(InitializationResult::Unfeasible);
         }
 
         let sup_x_index_row =
@@ -198,14 +580,17 @@ impl SlackFormLP {
         if let Some((row_index, _)) = sup_x_index_row {
             // Perform one more pivot in order to put the sup x in non basic
             // where a_row_index_i != 0.0
-            let col_degenerate_opt =
-                self.A[row_index].iter().enumerate().find(|(_, a)| **a != 0.);
+            let pivot_tolerance = self.options.get_pivot_tolerance();
+            let col_degenerate_opt = self.A[row_index]
+                .iter()
+                .enumerate()
+                .find(|(_, a)| a.abs() > pivot_tolerance);
             if let Some((i, _)) = col_degenerate_opt {
                 self.pivot(i, row_index);
             } else {
-                return Err("All values at degenerated row equal zero, this \
-                            should not happen."
-                    .into());
+                return Err(SolverError::Internal(
+                    "all values at the degenerated row equal zero.".into(),
+                ));
             }
         }
 
@@ -218,9 +603,10 @@ impl SlackFormLP {
         let x_col = if let Some((col, _)) = sup_x_index_col {
             col
         } else {
-            return Err("Sup x variable has not been found in non basic \
-                        variable, this should not happen"
-                .into());
+            return Err(SolverError::Internal(
+                "sup x variable has not been found in non basic variables."
+                    .into(),
+            ));
         };
 
         // Remove sup var in N
@@ -236,6 +622,9 @@ impl SlackFormLP {
 
         // Update dimension
         self.n -= 1;
+        self.lower.remove(self.n);
+        self.upper.remove(self.n);
+        self.flipped.remove(self.n);
 
         // Update B and N
         for elem in self.N.iter_mut() {
@@ -250,17 +639,49 @@ impl SlackFormLP {
         }
 
         // Recompute the objective function
-        self.recompute_objective_for_initialization(saved_c);
+        self.recompute_objective(saved_objective, saved_v, saved_flipped);
+
+        if let Some((interruption, point)) = interrupted {
+            return Ok(InitializationResult::Interrupted(interruption, point));
+        }
 
         Ok(InitializationResult::Done)
     }
 
-    fn recompute_objective_for_initialization(&mut self, init_c: Vec<f64>) {
+    /// Objective of the dictionary, expressed over every variable (0.0 for
+    /// the basic ones)
+    fn objective_over_variables(&self) -> Vec<f64> {
+        let mut objective = vec![0.; self.n + self.B.len()];
+        for (col, non_basic) in self.N.iter().enumerate() {
+            objective[*non_basic] = self.c[col];
+        }
+
+        objective
+    }
+
+    /// Express the objective saved by objective_over_variables with the
+    /// current basis
+    fn recompute_objective(
+        &mut self,
+        mut init_objective: Vec<f64>,
+        init_v: f64,
+        init_flipped: Vec<bool>,
+    ) {
         let mut objective = vec![0.; self.n];
+        self.v = init_v;
+
+        // Variables flipped during the initialization:
+        // c_j . x_j = c_j . width_j - c_j . x_j'
+        for (index, coef) in init_objective.iter_mut().enumerate() {
+            if init_flipped[index] != self.flipped[index] {
+                self.v += *coef * self.flip_width(index);
+                *coef = -*coef;
+            }
+        }
 
         for (row, basic) in self.B.iter().enumerate() {
-            if *basic < self.n {
-                let coef = init_c[*basic];
+            let coef = init_objective[*basic];
+            if coef != 0. {
                 self.v += self.b[row] * coef;
                 for (j, elem) in self.A[row].iter().enumerate() {
                     objective[j] += *elem * coef;
@@ -269,61 +690,121 @@ impl SlackFormLP {
         }
 
         for (col, non_basic) in self.N.iter().enumerate() {
-            if *non_basic < self.n {
-                objective[col] += init_c[*non_basic];
-            }
+            objective[col] += init_objective[*non_basic];
         }
 
         self.c = objective;
     }
 
-    fn find_leaving(&self, col: usize) -> Result<Leaving, String> {
+    fn find_leaving(&self, col: usize) -> Result<Leaving, SolverError> {
         if col >= self.A[0].len() {
-            return Err(
-                "Out of bound error while finding leaving variable.".into()
-            );
+            return Err(SolverError::InvalidColumn(col));
         }
+        // Elements smaller than the pivot tolerance are not taken as pivot,
+        // values out of their bounds by less than the primal tolerance are
+        // taken at their bound
+        let primal_tolerance = self.options.get_primal_feasibility_tolerance();
+        let pivot_tolerance = self.options.get_pivot_tolerance();
         let deltas =
             self.b.iter().enumerate().fold(Vec::new(), |mut acc, (row, b)| {
-                if *b >= 0. && self.A[row][col] < 0. {
-                    let delta = b / -self.A[row][col];
-                    acc.push((delta, row));
+                let a_rc = self.A[row][col];
+                if self.is_free(self.B[row]) {
+                    // A free variable never leaves the basis
+                } else if *b >= -primal_tolerance && a_rc < -pivot_tolerance {
+                    // The basic variable decreases down to its lower bound
+                    acc.push((
+                        b.max(0.) / -a_rc,
+                        (b + primal_tolerance) / -a_rc,
+                        row,
+                    ));
+                } else if a_rc > pivot_tolerance {
+                    // The basic variable increases up to its upper bound
+                    let width = self.width(self.B[row]);
+                    if width.is_finite() && *b <= width + primal_tolerance {
+                        acc.push((
+                            (width - b).max(0.) / a_rc,
+                            (width - b + primal_tolerance) / a_rc,
+                            row,
+                        ));
+                    }
                 }
 
                 acc
             });
 
-        if deltas.is_empty() {
-            return Ok(Leaving::Unbounded);
-        }
-
-        // If there are more than one minimal value, the first one is picked,
-        // this can be changed according to the desired rule.
-        let min_delta = deltas
-            .into_iter()
-            .min_by(|(d1, _), (d2, _)| d1.partial_cmp(&d2).unwrap());
+        // The entering variable may reach its own upper bound first
+        let entering_width = self.width(self.N[col]);
 
-        if let Some((delta, row)) = min_delta {
-            Ok(Leaving::Info(delta, row, self.B[row]))
+        let min_delta = if self.options.is_harris_ratio_test() {
+            // Harris ratio test, first pass: the smallest step with the bounds
+            // relaxed by the primal tolerance. Second pass: among the rows
+            // whose step is below it, the largest pivot element.
+            let max_delta = deltas
+                .iter()
+                .fold(f64::INFINITY, |acc: f64, (_, relaxed, _)| {
+                    acc.min(*relaxed)
+                });
+            deltas
+                .into_iter()
+                .filter(|(delta, _, _)| *delta <= max_delta)
+                .map(|(delta, _, row)| (delta, row))
+                .max_by(|(_, row1), (_, row2)| {
+                    let a1 = self.A[*row1][col].abs();
+                    let a2 = self.A[*row2][col].abs();
+                    a1.partial_cmp(&a2).unwrap().then(row2.cmp(row1))
+                })
         } else {
-            Err("No delta result left, it should not happen.".into())
+            // If there are more than one minimal value, the first one is
+            // picked
+            deltas
+                .into_iter()
+                .map(|(delta, _, row)| (delta, row))
+                .min_by(|(d1, _), (d2, _)| d1.partial_cmp(d2).unwrap())
+        };
+
+        match min_delta {
+            Some((delta, _)) if entering_width < delta => {
+                Ok(Leaving::Flip(entering_width))
+            },
+            Some((delta, row)) => Ok(Leaving::Info(delta, row, self.B[row])),
+            None if entering_width.is_finite() => {
+                Ok(Leaving::Flip(entering_width))
+            },
+            None => Ok(Leaving::Unbounded),
         }
     }
 
-    pub fn find_entering_and_leaving(&self) -> Result<SimplexRound, String> {
+    /// Simplex round with Bland's rule
+    pub fn find_entering_and_leaving(
+        &self,
+    ) -> Result<SimplexRound, SolverError> {
+        self.find_entering_and_leaving_with(&mut Bland)
+    }
+
+    /// Simplex round, the entering variable is chosen by 'rule' among the
+    /// ones which improve the objective
+    pub fn find_entering_and_leaving_with(
+        &self,
+        rule: &mut dyn PricingRule,
+    ) -> Result<SimplexRound, SolverError> {
         let mut cols = Vec::new();
 
         for (col, c) in self.c.iter().enumerate() {
-            if *c > 0. {
-                let leaving = self.find_leaving(col)?;
-
-                if let Leaving::Info(delta, row, basic) = leaving {
-                    // We could compute the objective gain in order to choose a
-                    // pivoting rule. So far, we use the Band's rule, so we
-                    // don't need it.
-                    cols.push((delta, col, self.N[col], row, basic));
-                } else {
-                    return Ok(SimplexRound::Unbounded);
+            // A fixed variable (equality slack variable for example) never
+            // enters the basis
+            if *c > self.options.get_dual_feasibility_tolerance()
+                && self.width(self.N[col]) > 0.
+            {
+                match self.find_leaving(col)? {
+                    Leaving::Info(delta, row, _) => {
+                        cols.push(((col, delta), Some(row)));
+                    },
+                    Leaving::Flip(delta) => {
+                        cols.push(((col, delta), None));
+                    },
+                    Leaving::Unbounded => {
+                        return Ok(SimplexRound::Unbounded(col));
+                    },
                 }
             }
         }
@@ -332,27 +813,24 @@ impl SlackFormLP {
             return Ok(SimplexRound::Finished);
         }
 
-        // We choose the smallest non basic index according to Band's rule
-        let min_non_basic_index = cols
-            .into_iter()
-            .min_by(|col1, col2| col1.2.partial_cmp(&col2.2).unwrap());
+        let candidates: Vec<(usize, f64)> =
+            cols.iter().map(|(candidate, _)| *candidate).collect();
+        let chosen = rule.select(self, &candidates);
 
-        if let Some((_, col, _, row, _)) = min_non_basic_index {
-            Ok(SimplexRound::Switch(col, row))
-        } else {
-            Err("No column result left, it should not happen.".into())
+        match cols.get(chosen) {
+            Some(((col, _), Some(row))) => Ok(SimplexRound::Switch(*col, *row)),
+            Some(((col, _), None)) => Ok(SimplexRound::Flip(*col)),
+            None => Err(SolverError::Internal("no column result left.".into())),
         }
     }
 
     pub fn pivot(&mut self, col: usize, row: usize) {
         // Switch basic and non_basic
-        let tmp = self.N[col];
-        self.N[col] = self.B[row];
-        self.B[row] = tmp;
+        std::mem::swap(&mut self.N[col], &mut self.B[row]);
 
         // Create new row to replace row at index 'row'
         let minus_a_rc = -self.A[row][col]; // which is != 0.0
-        self.b[row] = self.b[row] / minus_a_rc;
+        self.b[row] /= minus_a_rc;
         let inv_row: Vec<f64> =
             self.A[row]
                 .iter()
@@ -370,10 +848,10 @@ impl SlackFormLP {
         for (j, one_row) in self.A.iter_mut().enumerate() {
             if j != row {
                 let a_rc = one_row[col];
-                self.b[j] = self.b[j] + self.b[row] * a_rc;
+                self.b[j] += self.b[row] * a_rc;
                 for (i, elem) in one_row.iter_mut().enumerate() {
                     if i != col {
-                        *elem = *elem + inv_row[i] * a_rc;
+                        *elem += inv_row[i] * a_rc;
                     } else {
                         *elem = inv_row[i] * a_rc;
                     }
@@ -383,10 +861,10 @@ impl SlackFormLP {
 
         // Update objective function
         let c_c = self.c[col];
-        self.v = self.v + self.b[row] * c_c;
+        self.v += self.b[row] * c_c;
         for (i, elem) in self.c.iter_mut().enumerate() {
             if i != col {
-                *elem = *elem + inv_row[i] * c_c;
+                *elem += inv_row[i] * c_c;
             } else {
                 *elem = inv_row[i] * c_c;
             }
@@ -396,62 +874,773 @@ impl SlackFormLP {
         self.A[row] = inv_row;
     }
 
-    pub fn find_optimal(&mut self) -> Result<SimplexResult, String> {
-        let mut round_count = 0;
+    /// Primal simplex, with the pricing rule of the options
+    pub fn find_optimal(&mut self) -> Result<SimplexResult, SolverError> {
+        let mut rule = self.options.get_pricing().into_rule();
+        self.find_optimal_with(rule.as_mut())
+    }
+
+    /// Primal simplex, the entering variables are chosen by 'rule'.
+    ///
+    /// A rule other than Bland's one may cycle on a degenerate linear
+    /// program: after too many rounds without improvement of the objective,
+    /// Bland's rule is used until the objective improves again.
+    ///
+    /// With the perturbation option, the bounds of the basic variables are
+    /// relaxed during the rounds. Once restored, the basic variables may be
+    /// slightly out of their bounds: the dual simplex cleans up an optimal
+    /// basis, the initialization starts again from an unbounded one.
+    pub fn find_optimal_with(
+        &mut self,
+        rule: &mut dyn PricingRule,
+    ) -> Result<SimplexResult, SolverError> {
+        self.find_optimal_within(rule, &mut Budget::new())
+    }
+
+    pub(crate) fn find_optimal_within(
+        &mut self,
+        rule: &mut dyn PricingRule,
+        budget: &mut Budget,
+    ) -> Result<SimplexResult, SolverError> {
+        if !self.options.is_perturbation() {
+            let rounds = self.primal_rounds(rule, budget)?;
+            return self.rounds_result(rounds);
+        }
+
+        let bounds = self.perturb_bounds()?;
+        let rounds = self.primal_rounds(rule, budget)?;
+        for (index, lower, upper) in bounds.into_iter() {
+            self.set_variable_bounds(index, lower, upper)?;
+        }
+
+        if self.is_primal_feasible() {
+            return self.rounds_result(rounds);
+        }
+        match rounds {
+            Rounds::Finished => {
+                let rounds = self.dual_rounds(budget)?;
+                return self.rounds_result(rounds);
+            },
+            Rounds::Interrupted(_) => return self.rounds_result(rounds),
+            _ => {},
+        }
+
+        let options = self.options.clone();
+        self.options = options.clone().set_perturbation(false);
+        let result = match self.initialize_simplex_within(budget) {
+            Ok(InitializationResult::Done) => {
+                self.find_optimal_within(rule, budget)
+            },
+            Ok(InitializationResult::Unfeasible(certificate)) => {
+                Ok(SimplexResult::Unfeasible(certificate))
+            },
+            Ok(InitializationResult::Interrupted(interruption, point)) => {
+                Ok(SimplexResult::Interrupted(interruption, point))
+            },
+            Err(msg) => Err(msg),
+        };
+        self.options = options;
+
+        result
+    }
 
-        loop {
-            match self.find_entering_and_leaving() {
-                Ok(SimplexRound::Unbounded) => {
-                    return Ok(SimplexResult::Unbounded);
+    /// Rounds of the primal simplex, until the linear program is optimal,
+    /// unbounded or a limit of the options is reached.
+    fn primal_rounds(
+        &mut self,
+        rule: &mut dyn PricingRule,
+        budget: &mut Budget,
+    ) -> Result<Rounds, SolverError> {
+        let mut degenerate_rounds = 0;
+
+        let rounds = loop {
+            self.flip_free_non_basic();
+            let v = self.v;
+            let round = if degenerate_rounds < MAX_DEGENERATE_ROUNDS {
+                self.find_entering_and_leaving_with(rule)
+            } else {
+                self.find_entering_and_leaving()
+            };
+            match round {
+                Ok(SimplexRound::Unbounded(col)) => {
+                    break Rounds::Unbounded(col);
                 },
                 Ok(SimplexRound::Finished) => {
-                    break;
+                    break Rounds::Finished;
                 },
                 Ok(SimplexRound::Switch(col, row)) => {
+                    if let Some(interruption) = budget.spend(&self.options) {
+                        break Rounds::Interrupted(interruption);
+                    }
+                    let (entering, leaving) = (self.N[col], self.B[row]);
+                    // The basic variable leaves at its upper bound
+                    if self.A[row][col] > 0. {
+                        self.flip_basic(row);
+                    }
+                    rule.update(self, col, row);
                     self.pivot(col, row);
-                    round_count += 1;
+                    self.report_iteration(budget, entering, Some(leaving));
+                },
+                Ok(SimplexRound::Flip(col)) => {
+                    if let Some(interruption) = budget.spend(&self.options) {
+                        break Rounds::Interrupted(interruption);
+                    }
+                    self.flip_non_basic(col);
+                    self.report_iteration(budget, self.N[col], None);
                 },
                 Err(msg) => {
                     return Err(msg);
                 },
             }
+
+            if self.v > v {
+                degenerate_rounds = 0;
+            } else {
+                degenerate_rounds += 1;
+            }
+        };
+
+        #[cfg(feature = "log")]
+        log::debug!(
+            "Primal simplex: {:?} after {} iterations",
+            rounds,
+            budget.iterations
+        );
+
+        Ok(rounds)
+    }
+
+    fn rounds_result(
+        &self,
+        rounds: Rounds,
+    ) -> Result<SimplexResult, SolverError> {
+        match rounds {
+            Rounds::Finished => self.compute_solution(),
+            Rounds::Unbounded(col) => self.compute_ray(col),
+            Rounds::Unfeasible(row) => {
+                Ok(SimplexResult::Unfeasible(self.farkas_certificate(row)))
+            },
+            Rounds::Interrupted(interruption) => {
+                let mut point = self.compute_values();
+                point.truncate(self.n);
+                Ok(SimplexResult::Interrupted(interruption, point))
+            },
+        }
+    }
+
+    /// Relax the finite bounds of the basic variables (free and fixed ones
+    /// excepted), return the original bounds.
+    fn perturb_bounds(
+        &mut self,
+    ) -> Result<Vec<(usize, f64, f64)>, SolverError> {
+        let mut bounds = Vec::new();
+        for basic in self.B.clone().into_iter() {
+            if self.is_free(basic) || self.width(basic) == 0. {
+                continue;
+            }
+            let (lower, upper) = (self.lower[basic], self.upper[basic]);
+            self.set_variable_bounds(
+                basic,
+                lower - perturbation(basic, lower),
+                upper + perturbation(basic, upper),
+            )?;
+            bounds.push((basic, lower, upper));
+        }
+
+        Ok(bounds)
+    }
+
+    /// The current basis is primal feasible if every basic variable is
+    /// between its bounds, i.e. 0 <= b_i <= upper_i - lower_i
+    pub fn is_primal_feasible(&self) -> bool {
+        let tolerance = self.options.get_primal_feasibility_tolerance();
+        self.b.iter().zip(self.B.iter()).all(|(b, basic)| {
+            self.is_free(*basic)
+                || (*b >= -tolerance && *b <= self.width(*basic) + tolerance)
+        })
+    }
+
+    /// The current basis is dual feasible if no non basic variable can
+    /// improve the objective, i.e. c_j <= 0 for j = 1 to n (fixed variables
+    /// can not move, they are not taken into account)
+    pub fn is_dual_feasible(&self) -> bool {
+        let tolerance = self.options.get_dual_feasibility_tolerance();
+        self.c.iter().zip(self.N.iter()).all(|(c, non_basic)| {
+            if self.is_free(*non_basic) {
+                c.abs() <= tolerance
+            } else {
+                *c <= tolerance || self.width(*non_basic) == 0.
+            }
+        })
+    }
+
+    /// Set to zero the values of b and c smaller than 'tolerance' (in absolute
+    /// value), which are round off errors of the previous pivots.
+    pub fn snap_to_zero(&mut self, tolerance: f64) {
+        for value in self.b.iter_mut().chain(self.c.iter_mut()) {
+            if value.abs() <= tolerance {
+                *value = 0.;
+            }
+        }
+    }
+
+    /// Dual simplex round, the current basis must be dual feasible.
+    ///
+    /// The leaving variable is a basic variable with a negative value, the
+    /// entering variable is chosen by the dual ratio test, so that the basis
+    /// stays dual feasible. Ties are broken with the Band's rule.
+    pub fn find_dual_leaving_and_entering(
+        &self,
+    ) -> Result<DualSimplexRound, SolverError> {
+        // Leaving variable: smallest basic index with b_i < 0 (a free
+        // variable never leaves the basis)
+        let primal_tolerance = self.options.get_primal_feasibility_tolerance();
+        let pivot_tolerance = self.options.get_pivot_tolerance();
+        let leaving = self
+            .b
+            .iter()
+            .enumerate()
+            .filter(|(row, b)| {
+                **b < -primal_tolerance && !self.is_free(self.B[*row])
+            })
+            .min_by(|(row1, _), (row2, _)| self.B[*row1].cmp(&self.B[*row2]));
+
+        let row = if let Some((row, _)) = leaving {
+            row
+        } else {
+            return Ok(DualSimplexRound::Finished);
+        };
+
+        // Entering variable: among a_row_j > 0, the smallest -c_j / a_row_j
+        // (fixed variables can not enter the basis, free variables can enter
+        // in both directions). A gain within the dual tolerance counts as 0.
+        let ratios = self.A[row].iter().enumerate().fold(
+            Vec::new(),
+            |mut acc, (col, a)| {
+                let gain = (-self.c[col]).max(0.);
+                if self.is_free(self.N[col]) && a.abs() > pivot_tolerance {
+                    acc.push((gain / a.abs(), col));
+                } else if *a > pivot_tolerance && self.width(self.N[col]) > 0. {
+                    acc.push((gain / a, col));
+                }
+
+                acc
+            },
+        );
+
+        if ratios.is_empty() {
+            // The basic variable can not be increased: no feasible solution
+            return Ok(DualSimplexRound::Unfeasible(row));
+        }
+
+        let min_ratio = ratios.into_iter().min_by(|(r1, col1), (r2, col2)| {
+            r1.partial_cmp(r2).unwrap().then(self.N[*col1].cmp(&self.N[*col2]))
+        });
+
+        if let Some((_, col)) = min_ratio {
+            Ok(DualSimplexRound::Switch(col, row))
+        } else {
+            Err(SolverError::Internal("no ratio result left.".into()))
+        }
+    }
+
+    /// Farkas certificate given by the row 'row' of the dictionary, whose
+    /// basic variable is negative and can not be increased.
+    ///
+    /// The row is a combination of the constraints, y_i is the coefficient of
+    /// the slack variable i in this row (in the variable space, not in the
+    /// dictionary one).
+    fn farkas_certificate(&self, row: usize) -> Vec<f64> {
+        let mut y = vec![0.; self.B.len()];
+        let (_, sign) = self.reference(self.B[row]);
+        if self.B[row] >= self.n {
+            y[self.B[row] - self.n] = sign;
+        }
+        for (col, non_basic) in self.N.iter().enumerate() {
+            if *non_basic >= self.n {
+                let (_, sign) = self.reference(*non_basic);
+                y[*non_basic - self.n] = -self.A[row][col] * sign;
+            }
+        }
+
+        y
+    }
+
+    /// Dual simplex, the current basis must be dual feasible.
+    ///
+    /// With the perturbation option, the costs of the non basic variables are
+    /// decreased during the rounds. Once restored, the basis may not be dual
+    /// feasible anymore: the primal simplex cleans it up.
+    pub fn find_dual_optimal(&mut self) -> Result<SimplexResult, SolverError> {
+        self.find_dual_optimal_within(&mut Budget::new())
+    }
+
+    pub(crate) fn find_dual_optimal_within(
+        &mut self,
+        budget: &mut Budget,
+    ) -> Result<SimplexResult, SolverError> {
+        if !self.options.is_perturbation() {
+            let rounds = self.dual_rounds(budget)?;
+            return self.rounds_result(rounds);
+        }
+
+        let saved_objective = self.objective_over_variables();
+        let saved_v = self.v;
+        let saved_flipped = self.flipped.clone();
+        for col in 0..self.N.len() {
+            let non_basic = self.N[col];
+            if !self.is_free(non_basic) && self.width(non_basic) > 0. {
+                self.c[col] -= perturbation(non_basic, self.c[col]);
+            }
+        }
+
+        let rounds = self.dual_rounds(budget)?;
+        self.recompute_objective(saved_objective, saved_v, saved_flipped);
+
+        if rounds != Rounds::Finished || self.is_dual_feasible() {
+            return self.rounds_result(rounds);
+        }
+
+        let mut rule = self.options.get_pricing().into_rule();
+        let rounds = self.primal_rounds(rule.as_mut(), budget)?;
+        self.rounds_result(rounds)
+    }
+
+    /// Rounds of the dual simplex, until the linear program is optimal,
+    /// unfeasible or a limit of the options is reached.
+    fn dual_rounds(
+        &mut self,
+        budget: &mut Budget,
+    ) -> Result<Rounds, SolverError> {
+        let rounds = loop {
+            // Flipping a basic variable does not change c, the basis stays
+            // dual feasible
+            self.flip_basic_above_upper();
+
+            match self.find_dual_leaving_and_entering()? {
+                DualSimplexRound::Unfeasible(row) => {
+                    break Rounds::Unfeasible(row);
+                },
+                DualSimplexRound::Finished => {
+                    break Rounds::Finished;
+                },
+                DualSimplexRound::Switch(col, row) => {
+                    if let Some(interruption) = budget.spend(&self.options) {
+                        break Rounds::Interrupted(interruption);
+                    }
+                    let (entering, leaving) = (self.N[col], self.B[row]);
+                    // A free variable entering by decreasing
+                    if self.A[row][col] < 0. {
+                        self.flip_non_basic(col);
+                    }
+                    self.pivot(col, row);
+                    self.report_iteration(budget, entering, Some(leaving));
+                },
+            }
+        };
+
+        #[cfg(feature = "log")]
+        log::debug!(
+            "Dual simplex: {:?} after {} iterations",
+            rounds,
+            budget.iterations
+        );
+
+        Ok(rounds)
+    }
+
+    /// Sum of the bound violations of the basic variables (free ones
+    /// excepted)
+    fn primal_infeasibility(&self) -> f64 {
+        self.b
+            .iter()
+            .zip(self.B.iter())
+            .filter(|(_, basic)| !self.is_free(**basic))
+            .map(|(b, basic)| (-b).max(0.) + (b - self.width(*basic)).max(0.))
+            .sum()
+    }
+
+    /// Give the state of the dictionary to the iteration callback of the
+    /// options, and to the log (trace level) with the 'log' feature.
+    fn report_iteration(
+        &self,
+        budget: &Budget,
+        entering: usize,
+        leaving: Option<usize>,
+    ) {
+        #[cfg(feature = "log")]
+        let logged = log::log_enabled!(log::Level::Trace);
+        #[cfg(not(feature = "log"))]
+        let logged = false;
+
+        let callback = self.options.get_iteration_callback();
+        if callback.is_none() && !logged {
+            return;
+        }
+
+        let info = IterationInfo::new(
+            budget.iterations,
+            self.v,
+            entering,
+            leaving,
+            self.primal_infeasibility(),
+        );
+        if let Some(callback) = callback {
+            callback.call(&info);
+        }
+
+        #[cfg(feature = "log")]
+        log::trace!("{:?}", info);
+    }
+
+    /// Add the constraint:
+    ///
+    /// a_1 . x_1 + ... + a_n . x_n <= b
+    ///
+    /// The constraint is given over the original variables, it is rewritten
+    /// with the current non basic variables. Its slack variable becomes basic,
+    /// so the basis stays dual feasible (but maybe not primal feasible).
+    pub fn add_constraint(
+        &mut self,
+        mut a: Vec<f64>,
+        b: f64,
+    ) -> Result<(), SolverError> {
+        if a.len() != self.n {
+            return Err(SolverError::DimensionMismatch(
+                "constraint".into(),
+                self.n,
+                a.len(),
+            ));
+        }
+
+        // Bounded variables: x_j = lower_j + x_j' or x_j = upper_j - x_j'
+        let mut rhs = b;
+        for (j, a_j) in a.iter_mut().enumerate() {
+            let (reference, sign) = self.reference(j);
+            rhs -= *a_j * reference;
+            *a_j *= sign;
+        }
+
+        // x_s = b - Sum(a_j . x_j) for j = 1 to n
+        let mut row = vec![0.; self.N.len()];
+        for (col, non_basic) in self.N.iter().enumerate() {
+            if *non_basic < self.n {
+                row[col] -= a[*non_basic];
+            }
+        }
+        for (j, basic) in self.B.iter().enumerate() {
+            if *basic < self.n {
+                let coef = a[*basic];
+                rhs -= coef * self.b[j];
+                for (elem, a_jk) in row.iter_mut().zip(self.A[j].iter()) {
+                    *elem -= coef * a_jk;
+                }
+            }
         }
 
-        println!("Round count: {}", round_count);
+        self.B.push(self.n + self.B.len());
+        self.A.push(row);
+        self.b.push(rhs);
+        self.lower.push(0.);
+        self.upper.push(f64::INFINITY);
+        self.flipped.push(false);
+        self.rhs.push(b);
+
+        Ok(())
+    }
+
+    /// Replace b_i by b_i + delta, where i is the index of the constraint
+    /// (its slack variable is x_n+i).
+    pub fn shift_rhs(
+        &mut self,
+        i: usize,
+        delta: f64,
+    ) -> Result<(), SolverError> {
+        if i >= self.B.len() {
+            return Err(SolverError::InvalidRow(i));
+        }
+        let slack = self.n + i;
+        self.rhs[i] += delta;
+
+        // The new slack variable is x_s' = x_s + delta, its complement moves
+        // the other way
+        let delta = if self.flipped[slack] { -delta } else { delta };
+        if let Some(row) = self.B.iter().position(|basic| *basic == slack) {
+            self.b[row] += delta;
+        } else if let Some(col) = self.N.iter().position(|nb| *nb == slack) {
+            for (b, line) in self.b.iter_mut().zip(self.A.iter()) {
+                *b -= delta * line[col];
+            }
+            self.v -= delta * self.c[col];
+        } else {
+            return Err(SolverError::Internal(
+                "slack variable has not been found.".into(),
+            ));
+        }
 
-        Ok(self.compute_solution_vector())
+        Ok(())
     }
 
-    pub fn compute_solution_vector(&self) -> SimplexResult {
+    /// Values of all the variables (slacks included) in the current basis.
+    fn compute_values(&self) -> Vec<f64> {
         // Each non basic variables are set to 0
         // Each basic variables 'j' are set with the value of the respective b_j
-        let mut x = vec![0.; self.n];
+        let mut values = vec![0.; self.n + self.B.len()];
         for (j, basic) in self.B.iter().enumerate() {
+            values[*basic] = self.b[j];
+        }
+
+        // Back from the dictionary to the bounded variables
+        for (j, elem) in values.iter_mut().enumerate() {
+            let (reference, sign) = self.reference(j);
+            *elem = reference + sign * *elem;
+        }
+
+        values
+    }
+
+    /// Solution of the current basis, see Solution.
+    ///
+    /// The duals and the reduced costs are read from the objective function
+    /// of the dictionary: each non basic variable x_j' has a gain c_j, and
+    /// x_n+i = rhs_i - Sum(a_ij . x_j) so increasing rhs_i is the same as
+    /// decreasing x_n+i.
+    pub fn compute_solution(&self) -> Result<SimplexResult, SolverError> {
+        let mut values = self.compute_values();
+
+        // Gain of each variable, 0 for the basic ones
+        let mut gains = vec![0.; self.n + self.B.len()];
+        for (col, non_basic) in self.N.iter().enumerate() {
+            let (_, sign) = self.reference(*non_basic);
+            gains[*non_basic] = sign * self.c[col];
+        }
+
+        let slacks = values.split_off(self.n);
+        let row_activities = self
+            .rhs
+            .iter()
+            .zip(slacks.iter())
+            .map(|(rhs, slack)| rhs - slack)
+            .collect();
+        let duals = gains.split_off(self.n).into_iter().map(|g| -g).collect();
+
+        let solution = Solution::new(
+            self.v,
+            values,
+            row_activities,
+            slacks,
+            duals,
+            gains,
+        )?;
+
+        Ok(SimplexResult::Optimal(solution))
+    }
+
+    /// Unbounded ray: the current basic solution and the direction followed
+    /// when the non basic variable at 'col' increases (c_col > 0), no basic
+    /// variable being blocked.
+    pub fn compute_ray(
+        &self,
+        col: usize,
+    ) -> Result<SimplexResult, SolverError> {
+        if col >= self.N.len() {
+            return Err(SolverError::InvalidColumn(col));
+        }
+
+        let mut point = self.compute_values();
+        point.truncate(self.n);
+
+        // x_B = b + A . x_N, so x_B moves by A_col when x_N[col] moves by 1
+        let mut direction = vec![0.; self.n];
+        if self.N[col] < self.n {
+            let (_, sign) = self.reference(self.N[col]);
+            direction[self.N[col]] = sign;
+        }
+        for (row, basic) in self.B.iter().enumerate() {
             if *basic < self.n {
-                x[*basic] = self.b[j];
+                let (_, sign) = self.reference(*basic);
+                direction[*basic] = sign * self.A[row][col];
             }
         }
 
-        SimplexResult::Optimal(x)
+        Ok(SimplexResult::Unbounded(point, direction))
+    }
+
+    fn check_optimal(&self) -> Result<(), SolverError> {
+        if !self.is_primal_feasible() || !self.is_dual_feasible() {
+            return Err(SolverError::InvalidState(
+                "Sensitivity analysis requires an optimal basis.".into(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Objective ranging: for each variable x_j (slack variables excluded),
+    /// the interval [delta_min, delta_max] such that the current basis stays
+    /// optimal when c_j is replaced by c_j + delta.
+    ///
+    /// Changing c_j changes the gain of x_j in the dictionary if x_j is non
+    /// basic, the gain of every non basic variable of its row otherwise.
+    pub fn objective_ranging(&self) -> Result<Vec<(f64, f64)>, SolverError> {
+        self.check_optimal()?;
+        let tolerance = self.options.get_pivot_tolerance();
+
+        let mut ranges = Vec::with_capacity(self.n);
+        for j in 0..self.n {
+            let (_, sign) = self.reference(j);
+            let range = if let Some(col) = self.N.iter().position(|nb| *nb == j)
+            {
+                // c_col + sign . delta <= 0
+                gain_range(
+                    self.c[col],
+                    sign,
+                    self.width(j),
+                    self.is_free(j),
+                    tolerance,
+                )
+            } else if let Some(row) = self.B.iter().position(|b| *b == j) {
+                // c_k + sign . a_row_k . delta <= 0 for each non basic x_k
+                self.N.iter().enumerate().fold(
+                    (f64::NEG_INFINITY, f64::INFINITY),
+                    |acc, (col, nb)| {
+                        let (lo, hi) = gain_range(
+                            self.c[col],
+                            sign * self.A[row][col],
+                            self.width(*nb),
+                            self.is_free(*nb),
+                            tolerance,
+                        );
+                        (acc.0.max(lo), acc.1.min(hi))
+                    },
+                )
+            } else {
+                return Err(SolverError::Internal(
+                    "variable is neither basic nor non basic.".into(),
+                ));
+            };
+            ranges.push(range);
+        }
+
+        Ok(ranges)
+    }
+
+    /// Right hand side ranging: for each constraint i, the interval
+    /// [delta_min, delta_max] such that the current basis stays feasible
+    /// (thus optimal) when b_i is replaced by b_i + delta.
+    ///
+    /// Changing b_i shifts the slack variable x_n+i by the same value (see
+    /// shift_rhs), so either its own row or every row of the dictionary.
+    pub fn rhs_ranging(&self) -> Result<Vec<(f64, f64)>, SolverError> {
+        self.check_optimal()?;
+        let tolerance = self.options.get_pivot_tolerance();
+
+        let mut ranges = Vec::with_capacity(self.B.len());
+        for i in 0..self.B.len() {
+            let slack = self.n + i;
+            let (_, sign) = self.reference(slack);
+            let range =
+                if let Some(row) = self.B.iter().position(|b| *b == slack) {
+                    // 0 <= b_row + sign . delta <= width
+                    value_range(self.b[row], sign, self.width(slack), tolerance)
+                } else if let Some(col) =
+                    self.N.iter().position(|nb| *nb == slack)
+                {
+                    // 0 <= b_r - sign . a_r_col . delta <= width for each row r
+                    (0..self.B.len())
+                        .filter(|r| !self.is_free(self.B[*r]))
+                        .fold((f64::NEG_INFINITY, f64::INFINITY), |acc, r| {
+                            let (lo, hi) = value_range(
+                                self.b[r],
+                                -sign * self.A[r][col],
+                                self.width(self.B[r]),
+                                tolerance,
+                            );
+                            (acc.0.max(lo), acc.1.min(hi))
+                        })
+                } else {
+                    return Err(SolverError::Internal(
+                        "slack variable has not been found.".into(),
+                    ));
+                };
+            ranges.push(range);
+        }
+
+        Ok(ranges)
+    }
+}
+
+/// Perturbation of the bound (or of the cost) 'value' of the variable
+/// 'index', which differs from one variable to another
+fn perturbation(index: usize, value: f64) -> f64 {
+    let spread = 1. + ((index * 7919) % 101) as f64 / 101.;
+    PERTURBATION * (1. + value.abs()) * spread
+}
+
+/// Values of delta such that a non basic variable keeps a non positive gain:
+/// gain + slope . delta <= 0. A fixed variable may have any gain, a free one
+/// must keep a zero gain.
+fn gain_range(
+    gain: f64,
+    slope: f64,
+    width: f64,
+    is_free: bool,
+    tolerance: f64,
+) -> (f64, f64) {
+    if width == 0. || slope.abs() <= tolerance {
+        (f64::NEG_INFINITY, f64::INFINITY)
+    } else if is_free {
+        (0., 0.)
+    } else if slope > 0. {
+        (f64::NEG_INFINITY, -gain / slope)
+    } else {
+        (-gain / slope, f64::INFINITY)
+    }
+}
+
+/// Values of delta such that a basic variable stays between its bounds:
+/// 0 <= value + slope . delta <= width
+fn value_range(
+    value: f64,
+    slope: f64,
+    width: f64,
+    tolerance: f64,
+) -> (f64, f64) {
+    if slope.abs() <= tolerance {
+        (f64::NEG_INFINITY, f64::INFINITY)
+    } else if slope > 0. {
+        (-value / slope, (width - value) / slope)
+    } else {
+        ((width - value) / slope, -value / slope)
     }
 }
 
 impl fmt::Display for SlackFormLP {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        println!("LP - Slack form:");
-        println!("N: {:?}", self.N);
-        println!("B: {:?}", self.B);
-        println!("c: {:?}", self.c);
-        println!("A:");
+        writeln!(f, "LP - Slack form:")?;
+        writeln!(f, "N: {:?}", self.N)?;
+        writeln!(f, "B: {:?}", self.B)?;
+        writeln!(f, "c: {:?}", self.c)?;
+        writeln!(f, "A:")?;
         for row in self.A.iter() {
-            println!("\t{:?}", row);
+            writeln!(f, "\t{:?}", row)?;
         }
-        println!("b: {:?}", self.b);
-        println!("v: {:?}", self.v);
-        println!("n: {}", self.n);
-        println!("Solution vector: {:?}", self.compute_solution_vector());
+        writeln!(f, "b: {:?}", self.b)?;
+        writeln!(f, "v: {:?}", self.v)?;
+        writeln!(f, "n: {}", self.n)?;
+        writeln!(f, "lower: {:?}", self.lower)?;
+        writeln!(f, "upper: {:?}", self.upper)?;
+        writeln!(f, "flipped: {:?}", self.flipped)?;
+        writeln!(f, "rhs: {:?}", self.rhs)?;
+        writeln!(f, "Solution: {:?}", self.compute_solution())
+    }
+}
 
-        write!(f, "\n")
+#[cfg(test)]
+fn solution_x(lp_slack: &SlackFormLP) -> Vec<f64> {
+    match lp_slack.compute_solution() {
+        Ok(SimplexResult::Optimal(solution)) => solution.get_x().clone(),
+        result => panic!("A solution was expected, got {:?}", result),
     }
 }
 
@@ -469,6 +1658,11 @@ fn non_public_forms_slack_slackformlp_find_leaving() {
         c: vec![3., -2., 5., -6.],
         v: 0.,
         n: 5,
+        lower: vec![0.; 8],
+        upper: vec![f64::INFINITY; 8],
+        flipped: vec![false; 8],
+        rhs: vec![1.5, 2.5, -3.5],
+        options: SolverOptions::default(),
     };
 
     assert_eq!(lp_slack.find_leaving(0), Ok(Leaving::Info(2.5, 1, 7)));
@@ -476,10 +1670,7 @@ fn non_public_forms_slack_slackformlp_find_leaving() {
     assert_eq!(lp_slack.find_leaving(2), Ok(Leaving::Info(5., 1, 7)));
     assert_eq!(lp_slack.find_leaving(3), Ok(Leaving::Unbounded));
     assert_eq!(lp_slack.find_leaving(4), Ok(Leaving::Info(1.0, 0, 6)));
-    assert_eq!(
-        lp_slack.find_leaving(5),
-        Err("Out of bound error while finding leaving variable.".to_string())
-    );
+    assert_eq!(lp_slack.find_leaving(5), Err(SolverError::InvalidColumn(5)));
 }
 
 #[test]
@@ -496,6 +1687,11 @@ fn non_public_forms_slack_slackformlp_find_entering_and_leaving() {
         c: vec![3., -2., 5., -6.],
         v: 0.,
         n: 5,
+        lower: vec![0.; 8],
+        upper: vec![f64::INFINITY; 8],
+        flipped: vec![false; 8],
+        rhs: vec![1.5, 2.5, -3.5],
+        options: SolverOptions::default(),
     };
 
     let simplex_round = lp_slack.find_entering_and_leaving().unwrap();
@@ -513,6 +1709,11 @@ fn non_public_forms_slack_slackformlp_pivot_case_1() {
         c: vec![3., 1., 2.],
         v: 0.,
         n: 3,
+        lower: vec![0.; 6],
+        upper: vec![f64::INFINITY; 6],
+        flipped: vec![false; 6],
+        rhs: vec![30., 24., 36.],
+        options: SolverOptions::default(),
     };
 
     lp_slack.pivot(0, 2);
@@ -529,6 +1730,11 @@ fn non_public_forms_slack_slackformlp_pivot_case_1() {
         c: vec![-0.75, 0.25, 0.5],
         v: 27.,
         n: 3,
+        lower: vec![0.; 6],
+        upper: vec![f64::INFINITY; 6],
+        flipped: vec![false; 6],
+        rhs: vec![30., 24., 36.],
+        options: SolverOptions::default(),
     };
 
     assert_eq!(lp_slack, expected);
@@ -544,6 +1750,11 @@ fn non_public_forms_slack_slackformlp_pivot_case_1() {
         c: vec![3., 1., 2.],
         v: 0.,
         n: 3,
+        lower: vec![0.; 6],
+        upper: vec![f64::INFINITY; 6],
+        flipped: vec![false; 6],
+        rhs: vec![30., 24., 36.],
+        options: SolverOptions::default(),
     };
 
     assert_eq!(lp_slack, expected2);
@@ -563,6 +1774,11 @@ fn non_public_forms_slack_slackformlp_pivot_case_2() {
         c: vec![-0.75, 0.25, 0.5],
         v: 27.,
         n: 3,
+        lower: vec![0.; 6],
+        upper: vec![f64::INFINITY; 6],
+        flipped: vec![false; 6],
+        rhs: vec![30., 24., 36.],
+        options: SolverOptions::default(),
     };
 
     lp_slack.pivot(2, 1);
@@ -579,6 +1795,11 @@ fn non_public_forms_slack_slackformlp_pivot_case_2() {
         c: vec![-0.6875, 0.0625, -0.125],
         v: 27.75,
         n: 3,
+        lower: vec![0.; 6],
+        upper: vec![f64::INFINITY; 6],
+        flipped: vec![false; 6],
+        rhs: vec![30., 24., 36.],
+        options: SolverOptions::default(),
     };
 
     assert_eq!(lp_slack, expected);
@@ -598,6 +1819,11 @@ fn non_public_forms_slack_slackformlp_pivot_case_3() {
         c: vec![-0.6875, 0.0625, -0.125],
         v: 27.75,
         n: 3,
+        lower: vec![0.; 6],
+        upper: vec![f64::INFINITY; 6],
+        flipped: vec![false; 6],
+        rhs: vec![30., 24., 36.],
+        options: SolverOptions::default(),
     };
 
     lp_slack.pivot(1, 1);
@@ -618,6 +1844,11 @@ fn non_public_forms_slack_slackformlp_pivot_case_3() {
         ],
         v: 28.0,
         n: 3,
+        lower: vec![0.; 6],
+        upper: vec![f64::INFINITY; 6],
+        flipped: vec![false; 6],
+        rhs: vec![30., 24., 36.],
+        options: SolverOptions::default(),
     };
 
     assert_eq!(lp_slack, expected);
@@ -633,6 +1864,11 @@ fn non_public_forms_slack_slackformlp_initialize_simplex_case_1() {
         c: vec![2., -1.],
         v: 0.,
         n: 2,
+        lower: vec![0.; 4],
+        upper: vec![f64::INFINITY; 4],
+        flipped: vec![false; 4],
+        rhs: vec![2., -4.],
+        options: SolverOptions::default(),
     };
 
     let result = lp_slack.initialize_simplex().unwrap();
@@ -646,7 +1882,401 @@ fn non_public_forms_slack_slackformlp_initialize_simplex_case_1() {
         c: vec![1.8, -0.2],
         v: -0.8,
         n: 2,
+        lower: vec![0.; 4],
+        upper: vec![f64::INFINITY; 4],
+        flipped: vec![false; 4],
+        rhs: vec![2., -4.],
+        options: SolverOptions::default(),
+    };
+
+    assert_eq!(lp_slack, expected);
+}
+
+#[test]
+fn non_public_forms_slack_slackformlp_initialize_simplex_case_2() {
+    // Same linear program, but the dictionary has already been pivoted
+    let mut lp_slack = SlackFormLP {
+        N: vec![0, 1],
+        B: vec![2, 3],
+        A: vec![vec![-2., 1.], vec![-1., 5.]],
+        b: vec![2., -4.],
+        c: vec![2., -1.],
+        v: 0.,
+        n: 2,
+        lower: vec![0.; 4],
+        upper: vec![f64::INFINITY; 4],
+        flipped: vec![false; 4],
+        rhs: vec![2., -4.],
+        options: SolverOptions::default(),
+    };
+    lp_slack.pivot(0, 0);
+
+    let result = lp_slack.initialize_simplex().unwrap();
+    assert_eq!(result, InitializationResult::Done);
+
+    let mut sorted = [lp_slack.N.clone(), lp_slack.B.clone()].concat();
+    sorted.sort_unstable();
+    assert_eq!(sorted, vec![0, 1, 2, 3]);
+    assert!(lp_slack.b.iter().all(|b| *b >= 0.));
+
+    // The objective is still 2 x_0 - x_1
+    if let SimplexResult::Optimal(solution) =
+        lp_slack.compute_solution().unwrap()
+    {
+        let x = solution.get_x();
+        assert!((2. * x[0] - x[1] - lp_slack.v).abs() < 1e-9);
+    } else {
+        panic!("A basic feasible solution was expected");
+    }
+}
+
+#[test]
+fn non_public_forms_slack_slackformlp_initialize_simplex_equality() {
+    // x_2 = -4 + x_0 + x_1 is the slack variable of an equality, x_3 is not
+    let mut lp_slack = SlackFormLP {
+        N: vec![0, 1],
+        B: vec![2, 3],
+        A: vec![vec![1., 1.], vec![-1., 2.]],
+        b: vec![-4., -2.],
+        c: vec![1., 1.],
+        v: 0.,
+        n: 2,
+        lower: vec![0.; 4],
+        upper: vec![f64::INFINITY, f64::INFINITY, 0., f64::INFINITY],
+        flipped: vec![false; 4],
+        rhs: vec![-4., -2.],
+        options: SolverOptions::default(),
+    };
+
+    let result = lp_slack.initialize_simplex().unwrap();
+    assert_eq!(result, InitializationResult::Done);
+
+    assert!(lp_slack.is_primal_feasible());
+    if let SimplexResult::Optimal(solution) =
+        lp_slack.compute_solution().unwrap()
+    {
+        let x = solution.get_x();
+        assert!((x[0] + x[1] - 4.).abs() < 1e-9);
+        assert!(2. * x[1] - x[0] >= 2. - 1e-9);
+    } else {
+        panic!("A basic feasible solution was expected");
+    }
+}
+
+#[test]
+fn non_public_forms_slack_slackformlp_find_dual_leaving_and_entering() {
+    let lp_slack = SlackFormLP {
+        N: vec![0, 1],
+        B: vec![2, 3],
+        A: vec![vec![1., 2.], vec![3., 1.]],
+        b: vec![-4., -6.],
+        c: vec![-1., -1.],
+        v: 0.,
+        n: 2,
+        lower: vec![0.; 4],
+        upper: vec![f64::INFINITY; 4],
+        flipped: vec![false; 4],
+        rhs: vec![-4., -6.],
+        options: SolverOptions::default(),
+    };
+
+    let dual_round = lp_slack.find_dual_leaving_and_entering().unwrap();
+
+    // Leaving x_2 (smallest basic index), ratios are 1 / 1 and 1 / 2
+    assert_eq!(dual_round, DualSimplexRound::Switch(1, 0));
+}
+
+#[test]
+fn non_public_forms_slack_slackformlp_find_dual_leaving_and_entering_unfeasible(
+) {
+    let lp_slack = SlackFormLP {
+        N: vec![0, 1],
+        B: vec![2, 3],
+        A: vec![vec![-1., -2.], vec![3., 1.]],
+        b: vec![-4., -6.],
+        c: vec![-1., -1.],
+        v: 0.,
+        n: 2,
+        lower: vec![0.; 4],
+        upper: vec![f64::INFINITY; 4],
+        flipped: vec![false; 4],
+        rhs: vec![-4., -6.],
+        options: SolverOptions::default(),
+    };
+
+    let dual_round = lp_slack.find_dual_leaving_and_entering().unwrap();
+
+    assert_eq!(dual_round, DualSimplexRound::Unfeasible(0));
+    // x_2 = -4 - x_0 - 2 . x_1 is the first row itself
+    assert_eq!(lp_slack.farkas_certificate(0), vec![1., 0.]);
+}
+
+#[test]
+fn non_public_forms_slack_slackformlp_add_constraint() {
+    let mut lp_slack = SlackFormLP {
+        N: vec![5, 1, 4],
+        B: vec![3, 2, 0],
+        A: vec![
+            vec![-0.0625, 0.1875, 0.625],
+            vec![0.125, -0.375, -0.25],
+            vec![-0.3125, -0.0625, 0.125],
+        ],
+        b: vec![17.25, 1.5, 8.25],
+        c: vec![-0.6875, 0.0625, -0.125],
+        v: 27.75,
+        n: 3,
+        lower: vec![0.; 6],
+        upper: vec![f64::INFINITY; 6],
+        flipped: vec![false; 6],
+        rhs: vec![30., 24., 36.],
+        options: SolverOptions::default(),
+    };
+
+    // x_6 = 10 - x_0 - x_1
+    lp_slack.add_constraint(vec![1., 1., 0.], 10.).unwrap();
+
+    let expected = SlackFormLP {
+        N: vec![5, 1, 4],
+        B: vec![3, 2, 0, 6],
+        A: vec![
+            vec![-0.0625, 0.1875, 0.625],
+            vec![0.125, -0.375, -0.25],
+            vec![-0.3125, -0.0625, 0.125],
+            vec![0.3125, -0.9375, -0.125],
+        ],
+        b: vec![17.25, 1.5, 8.25, 1.75],
+        c: vec![-0.6875, 0.0625, -0.125],
+        v: 27.75,
+        n: 3,
+        lower: vec![0.; 7],
+        upper: vec![f64::INFINITY; 7],
+        flipped: vec![false; 7],
+        rhs: vec![30., 24., 36., 10.],
+        options: SolverOptions::default(),
+    };
+
+    assert_eq!(lp_slack, expected);
+
+    assert_eq!(
+        lp_slack.add_constraint(vec![1., 1.], 10.),
+        Err(SolverError::DimensionMismatch("constraint".into(), 3, 2))
+    );
+}
+
+#[test]
+fn non_public_forms_slack_slackformlp_shift_rhs() {
+    let mut lp_slack = SlackFormLP {
+        N: vec![5, 1, 2],
+        B: vec![3, 4, 0],
+        A: vec![
+            vec![0.25, -0.75, -2.5],
+            vec![0.5, -1.5, -4.],
+            vec![-0.25, -0.25, -0.5],
+        ],
+        b: vec![21., 6., 9.],
+        c: vec![-0.75, 0.25, 0.5],
+        v: 27.,
+        n: 3,
+        lower: vec![0.; 6],
+        upper: vec![f64::INFINITY; 6],
+        flipped: vec![false; 6],
+        rhs: vec![30., 24., 36.],
+        options: SolverOptions::default(),
+    };
+
+    // Basic slack variable x_3
+    lp_slack.shift_rhs(0, 2.).unwrap();
+    // Non basic slack variable x_5
+    lp_slack.shift_rhs(2, -4.).unwrap();
+
+    let expected = SlackFormLP {
+        N: vec![5, 1, 2],
+        B: vec![3, 4, 0],
+        A: vec![
+            vec![0.25, -0.75, -2.5],
+            vec![0.5, -1.5, -4.],
+            vec![-0.25, -0.25, -0.5],
+        ],
+        b: vec![24., 8., 8.],
+        c: vec![-0.75, 0.25, 0.5],
+        v: 24.,
+        n: 3,
+        lower: vec![0.; 6],
+        upper: vec![f64::INFINITY; 6],
+        flipped: vec![false; 6],
+        rhs: vec![32., 24., 32.],
+        options: SolverOptions::default(),
+    };
+
+    assert_eq!(lp_slack, expected);
+
+    assert_eq!(lp_slack.shift_rhs(3, 1.), Err(SolverError::InvalidRow(3)));
+}
+
+#[test]
+fn non_public_forms_slack_slackformlp_find_leaving_bounded() {
+    let lp_slack = SlackFormLP {
+        N: vec![0, 1],
+        B: vec![2, 3],
+        A: vec![vec![-1., 2.], vec![1., -1.]],
+        b: vec![6., 2.],
+        c: vec![1., 1.],
+        v: 0.,
+        n: 2,
+        lower: vec![0.; 4],
+        upper: vec![4., 1.5, f64::INFINITY, 3.],
+        flipped: vec![false; 4],
+        rhs: vec![6., 2.],
+        options: SolverOptions::default(),
+    };
+
+    // x_3 reaches its upper bound before x_2 reaches 0
+    assert_eq!(lp_slack.find_leaving(0), Ok(Leaving::Info(1., 1, 3)));
+    // x_1 reaches its own upper bound first
+    assert_eq!(lp_slack.find_leaving(1), Ok(Leaving::Flip(1.5)));
+}
+
+#[test]
+fn non_public_forms_slack_slackformlp_flip() {
+    let mut lp_slack = SlackFormLP {
+        N: vec![0, 1],
+        B: vec![2, 3],
+        A: vec![vec![-1., 2.], vec![1., -1.]],
+        b: vec![6., 2.],
+        c: vec![1., 1.],
+        v: 0.,
+        n: 2,
+        lower: vec![0.; 4],
+        upper: vec![4., 1.5, f64::INFINITY, 3.],
+        flipped: vec![false; 4],
+        rhs: vec![6., 2.],
+        options: SolverOptions::default(),
+    };
+
+    // x_1 = 1.5 - x_1'
+    lp_slack.flip_non_basic(1);
+    // x_3 = 3 - x_3'
+    lp_slack.flip_basic(1);
+
+    let expected = SlackFormLP {
+        N: vec![0, 1],
+        B: vec![2, 3],
+        A: vec![vec![-1., -2.], vec![-1., -1.]],
+        b: vec![9., 2.5],
+        c: vec![1., -1.],
+        v: 1.5,
+        n: 2,
+        lower: vec![0.; 4],
+        upper: vec![4., 1.5, f64::INFINITY, 3.],
+        flipped: vec![false, true, false, true],
+        rhs: vec![6., 2.],
+        options: SolverOptions::default(),
     };
 
     assert_eq!(lp_slack, expected);
+    assert_eq!(solution_x(&lp_slack), vec![0., 1.5]);
+}
+
+#[test]
+fn non_public_forms_slack_slackformlp_set_variable_bounds() {
+    let mut lp_slack = SlackFormLP {
+        N: vec![0, 3],
+        B: vec![2, 1],
+        A: vec![vec![-1., 2.], vec![1., -1.]],
+        b: vec![6., 1.],
+        c: vec![1., -1.],
+        v: 1.,
+        n: 2,
+        lower: vec![0.; 4],
+        upper: vec![f64::INFINITY; 4],
+        flipped: vec![false; 4],
+        rhs: vec![6., 1.],
+        options: SolverOptions::default(),
+    };
+
+    // Non basic x_0 in [2, 5]
+    lp_slack.set_variable_bounds(0, 2., 5.).unwrap();
+    // Basic x_1 in [1, 10]
+    lp_slack.set_variable_bounds(1, 1., 10.).unwrap();
+
+    let expected = SlackFormLP {
+        N: vec![0, 3],
+        B: vec![2, 1],
+        A: vec![vec![-1., 2.], vec![1., -1.]],
+        b: vec![4., 2.],
+        c: vec![1., -1.],
+        v: 3.,
+        n: 2,
+        lower: vec![2., 1., 0., 0.],
+        upper: vec![5., 10., f64::INFINITY, f64::INFINITY],
+        flipped: vec![false; 4],
+        rhs: vec![6., 1.],
+        options: SolverOptions::default(),
+    };
+
+    assert_eq!(lp_slack, expected);
+    assert_eq!(solution_x(&lp_slack), vec![2., 3.]);
+
+    assert_eq!(
+        lp_slack.set_variable_bounds(4, 0., 1.),
+        Err(SolverError::InvalidColumn(4))
+    );
+}
+
+#[test]
+fn non_public_forms_slack_slackformlp_set_variable_bounds_without_lower() {
+    let mut lp_slack = SlackFormLP {
+        N: vec![0, 3],
+        B: vec![2, 1],
+        A: vec![vec![-1., 2.], vec![1., -1.]],
+        b: vec![6., 1.],
+        c: vec![1., -1.],
+        v: 1.,
+        n: 2,
+        lower: vec![0.; 4],
+        upper: vec![f64::INFINITY; 4],
+        flipped: vec![false; 4],
+        rhs: vec![6., 1.],
+        options: SolverOptions::default(),
+    };
+
+    // Non basic x_0 <= 5, it becomes 5 - x_0 in the dictionary
+    lp_slack.set_variable_bounds(0, f64::NEG_INFINITY, 5.).unwrap();
+    // Basic x_1 <= 10, it becomes 10 - x_1 in the dictionary
+    lp_slack.set_variable_bounds(1, f64::NEG_INFINITY, 10.).unwrap();
+
+    let expected = SlackFormLP {
+        N: vec![0, 3],
+        B: vec![2, 1],
+        A: vec![vec![1., 2.], vec![1., 1.]],
+        b: vec![1., 4.],
+        c: vec![-1., -1.],
+        v: 6.,
+        n: 2,
+        lower: vec![f64::NEG_INFINITY, f64::NEG_INFINITY, 0., 0.],
+        upper: vec![5., 10., f64::INFINITY, f64::INFINITY],
+        flipped: vec![true, true, false, false],
+        rhs: vec![6., 1.],
+        options: SolverOptions::default(),
+    };
+
+    assert_eq!(lp_slack, expected);
+    assert_eq!(solution_x(&lp_slack), vec![5., 6.]);
+
+    // Free x_1 keeps its sign in the dictionary, with -x_1 = 4 - 10
+    lp_slack.set_variable_bounds(1, f64::NEG_INFINITY, f64::INFINITY).unwrap();
+
+    assert!(lp_slack.is_free(1));
+    assert_eq!(lp_slack.get_b(), &vec![1., -6.]);
+    assert_eq!(solution_x(&lp_slack), vec![5., 6.]);
+}
+
+#[test]
+fn non_public_forms_slack_ranges_roundoff_slope() {
+    let unbounded = (f64::NEG_INFINITY, f64::INFINITY);
+
+    assert_eq!(gain_range(-1., 1e-16, f64::INFINITY, false, 1e-9), unbounded);
+    assert_eq!(gain_range(-1., 0.5, f64::INFINITY, false, 1e-9).1, 2.);
+    assert_eq!(value_range(2., -1e-16, f64::INFINITY, 1e-9), unbounded);
+    assert_eq!(value_range(2., -0.5, 6., 1e-9), (-8., 4.));
 }
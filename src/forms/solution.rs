@@ -0,0 +1,92 @@
+use super::super::types::error::SolverError;
+
+/// Optimal solution of a linear program.
+///
+/// For the standard form (max c . x with a . x <= b):
+/// - objective_value: c . x
+/// - x: value of each variable
+/// - row_activities: a_i . x for each row
+/// - slacks: b_i - a_i . x for each row
+/// - duals: shadow price y_i of each row, the objective increases by y_i when
+///   b_i increases by 1.0 (0.0 for a row which is not tight)
+/// - reduced_costs: c_j - Sum(a_ij . y_i) for each variable, the objective
+///   increases by this value when x_j increases by 1.0 (0.0 for a basic
+///   variable)
+///
+/// With a min objective (see the builder), the objective value, the duals and
+/// the reduced costs are the ones of the min objective.
+#[derive(Debug, PartialEq, PartialOrd, Clone)]
+pub struct Solution {
+    objective_value: f64,
+    x: Vec<f64>,
+    row_activities: Vec<f64>,
+    slacks: Vec<f64>,
+    duals: Vec<f64>,
+    reduced_costs: Vec<f64>,
+}
+
+impl Solution {
+    pub fn new(
+        objective_value: f64,
+        x: Vec<f64>,
+        row_activities: Vec<f64>,
+        slacks: Vec<f64>,
+        duals: Vec<f64>,
+        reduced_costs: Vec<f64>,
+    ) -> Result<Solution, SolverError> {
+        // Row activities, slacks and duals should have the same size
+        if row_activities.len() != slacks.len() {
+            return Err(SolverError::DimensionMismatch(
+                "row activities".into(),
+                slacks.len(),
+                row_activities.len(),
+            ));
+        }
+        if duals.len() != slacks.len() {
+            return Err(SolverError::DimensionMismatch(
+                "duals".into(),
+                slacks.len(),
+                duals.len(),
+            ));
+        }
+        if reduced_costs.len() != x.len() {
+            return Err(SolverError::DimensionMismatch(
+                "reduced costs".into(),
+                x.len(),
+                reduced_costs.len(),
+            ));
+        }
+
+        Ok(Solution {
+            objective_value,
+            x,
+            row_activities,
+            slacks,
+            duals,
+            reduced_costs,
+        })
+    }
+
+    pub fn get_objective_value(&self) -> f64 { self.objective_value }
+
+    pub fn get_x(&self) -> &Vec<f64> { &self.x }
+
+    pub fn get_row_activities(&self) -> &Vec<f64> { &self.row_activities }
+
+    pub fn get_slacks(&self) -> &Vec<f64> { &self.slacks }
+
+    pub fn get_duals(&self) -> &Vec<f64> { &self.duals }
+
+    pub fn get_reduced_costs(&self) -> &Vec<f64> { &self.reduced_costs }
+
+    /// Same solution for the min objective: min -c . x
+    pub fn into_min_objective(mut self) -> Self {
+        self.objective_value = -self.objective_value;
+        for value in self.duals.iter_mut().chain(self.reduced_costs.iter_mut())
+        {
+            *value = -*value;
+        }
+
+        self
+    }
+}
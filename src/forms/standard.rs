@@ -1,7 +1,10 @@
 use std::fmt;
 
+use super::super::algo::simplex::simplex_lp_chvatal;
 use super::super::shared::utils::is_uniq_sorted;
-use super::slack::SlackFormLP;
+use super::super::types::error::SolverError;
+use super::options::SolverOptions;
+use super::slack::{SimplexResult, SlackFormLP};
 
 /// Linear Programming, Standard form.
 ///
@@ -27,8 +30,35 @@ use super::slack::SlackFormLP;
 /// [i such as x_i in Real]
 /// Indices start at 0
 /// For example: vec![0, 2, 5];
-/// Caution: x_i = x_i' - x_i'' transformation is already done!
-/// non_negative_indices are used to retrieve the solution
+/// These variables are free (lower_i = -infinity, upper_i = infinity), the
+/// simplex handles them directly, without the x_i = x_i' - x_i''
+/// transformation.
+///
+/// Variable bounds replace the x_j >= 0.0 constraints:
+/// ```ignore
+///     lower_j <= x_j <= upper_j
+/// ```
+/// By default lower_j = 0.0 and upper_j = infinity. The bounds are not rows
+/// of the matrix 'a', the simplex handles them directly. The lower bound can
+/// be -infinity.
+///
+/// Row ranges turn the rows into:
+/// ```ignore
+///     b_i - range_i <= Sum(a_ij . x_j) <= b_i
+/// ```
+/// By default range_i = infinity, and range_i = 0.0 for an equality. The
+/// slack variable of the row is bounded by the range.
+///
+/// Integer indices are the variables which must take integer values:
+/// [j such as x_j in Integer]
+/// For example: vec![0, 1];
+/// The simplex (see solve) ignores them, it solves the linear relaxation. The
+/// branch and bound (see algo::branch_and_bound) enforces them.
+///
+/// A min objective is stored as max -c . x, min_objective is only used to
+/// give back the objective value of the solution with the right sign.
+///
+/// The options (tolerances) are given to the slack form.
 #[derive(Debug, PartialEq, PartialOrd, Clone)]
 pub struct StandardFormLP {
     c: Vec<f64>,
@@ -36,6 +66,13 @@ pub struct StandardFormLP {
     a: Vec<Vec<f64>>,
     b: Vec<f64>,
     non_negative_indices: Option<Vec<usize>>,
+    lower: Vec<f64>,
+    upper: Vec<f64>,
+    ranges: Vec<f64>,
+    integer_indices: Vec<usize>,
+    min_objective: bool,
+    objective_value: Option<f64>,
+    options: SolverOptions,
 }
 
 impl StandardFormLP {
@@ -44,35 +81,29 @@ impl StandardFormLP {
         a: Vec<Vec<f64>>,
         b: Vec<f64>,
         non_negative_indices: Option<Vec<usize>>,
-    ) -> Result<StandardFormLP, String> {
+    ) -> Result<StandardFormLP, SolverError> {
         if a.is_empty() {
-            return Err("Matrix 'a' should not be empty".into());
+            return Err(SolverError::EmptyInput("matrix 'a'".into()));
         }
         if b.is_empty() {
-            return Err("Vector 'b' should not be empty".into());
+            return Err(SolverError::EmptyInput("vector 'b'".into()));
         }
         if c.is_empty() {
-            return Err("Vector 'c' should not be empty".into());
-        }
-        if c.len() != a[0].len() {
-            return Err(format!(
-                "Matrix 'a' rows count does not match with vector 'c' size \
-                 ({} != {})",
-                a[0].len(),
-                c.len(),
-            ));
+            return Err(SolverError::EmptyInput("vector 'c'".into()));
         }
-        for line in a.iter() {
+        // Each and every row in the matrix 'a' should have the size of 'c'
+        for (row, line) in a.iter().enumerate() {
             if line.len() != c.len() {
-                return Err("Each and every row in the matrix 'a' should \
-                            have the same size"
-                    .into());
+                return Err(SolverError::DimensionMismatch(
+                    format!("row {} of the matrix 'a'", row),
+                    c.len(),
+                    line.len(),
+                ));
             }
         }
         if a.len() != b.len() {
-            return Err(format!(
-                "Matrix 'a' columns count does not match with vector 'b' size \
-                 ({} != {})",
+            return Err(SolverError::DimensionMismatch(
+                "vector 'b'".into(),
                 a.len(),
                 b.len(),
             ));
@@ -80,14 +111,16 @@ impl StandardFormLP {
 
         let non_negative_indices = if let Some(nni) = non_negative_indices {
             let nni_len = nni.len();
-            if 2 * nni_len > c.len() {
-                return Err("Cannot be more non negative indices than the \
-                            dimension size."
-                    .into());
+            if nni_len > c.len() {
+                return Err(SolverError::InvalidInput(
+                    "Cannot be more non negative indices than the dimension \
+                     size."
+                        .into(),
+                ));
             }
 
             let nni_opt = Some(nni);
-            check_non_negative_indices(&nni_opt, Some(c.len() - nni_len))?;
+            check_non_negative_indices(&nni_opt, Some(c.len()))?;
 
             nni_opt
         } else {
@@ -95,62 +128,336 @@ impl StandardFormLP {
         };
 
         let x = Vec::new();
+        let mut lower = vec![0.; c.len()];
+        if let Some(nni) = &non_negative_indices {
+            for i in nni.iter() {
+                lower[*i] = f64::NEG_INFINITY;
+            }
+        }
+        let upper = vec![f64::INFINITY; c.len()];
+        let ranges = vec![f64::INFINITY; b.len()];
 
-        Ok(StandardFormLP { c, x, a, b, non_negative_indices })
+        Ok(StandardFormLP {
+            c,
+            x,
+            a,
+            b,
+            non_negative_indices,
+            lower,
+            upper,
+            ranges,
+            integer_indices: Vec::new(),
+            min_objective: false,
+            objective_value: None,
+            options: SolverOptions::default(),
+        })
     }
 
+    /// Add variable bounds
+    ///
+    /// lower <= x_index <= upper
+    /// Both bounds can be infinite (-infinity for the lower one). A free
+    /// variable (see non_negative_indices) gets the bounds and is no longer
+    /// free.
+    pub fn add_variable_bounds(
+        mut self,
+        index: usize,
+        lower: f64,
+        upper: f64,
+    ) -> Result<Self, SolverError> {
+        check_variable_bounds(lower, upper)?;
+
+        if index >= self.c.len() {
+            return Err(SolverError::InvalidColumn(index));
+        }
+        if let Some(nni) = &mut self.non_negative_indices {
+            nni.retain(|i| *i != index);
+            if nni.is_empty() {
+                self.non_negative_indices = None;
+            }
+        }
+
+        self.lower[index] = lower;
+        self.upper[index] = upper;
+
+        Ok(self)
+    }
+
+    /// Mark the objective as a min objective, 'c' is expected to be negated
+    /// already.
+    pub fn set_min_objective(mut self) -> Self {
+        self.min_objective = true;
+        self
+    }
+
+    pub fn is_min_objective(&self) -> bool { self.min_objective }
+
+    /// Tolerances of the simplex
+    pub fn set_options(mut self, options: SolverOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    pub fn get_options(&self) -> &SolverOptions { &self.options }
+
+    /// Solve the linear program with the simplex.
+    ///
+    /// The optimal solution is indexed like the variables of the linear
+    /// program (the slack variables are not part of it), its objective value,
+    /// duals and reduced costs match the objective given to the builder (min
+    /// or max). 'x' and the objective value are kept as well.
+    pub fn solve(&mut self) -> Result<SimplexResult, SolverError> {
+        let mut slack_lp = self.clone().into_slack_form()?;
+        let result = match simplex_lp_chvatal(&mut slack_lp)? {
+            SimplexResult::Optimal(solution) if self.min_objective => {
+                SimplexResult::Optimal(solution.into_min_objective())
+            },
+            result => result,
+        };
+
+        if let SimplexResult::Optimal(solution) = &result {
+            self.x = solution.get_x().clone();
+            self.objective_value = Some(solution.get_objective_value());
+        } else {
+            self.x = Vec::new();
+            self.objective_value = None;
+        }
+
+        Ok(result)
+    }
+
+    /// Objective value of the optimal solution, once solved
+    pub fn get_objective_value(&self) -> Option<f64> { self.objective_value }
+
     pub fn get_x(self) -> Vec<f64> { self.x }
 
     pub fn get_x_clone(&self) -> Vec<f64> { self.x.clone() }
 
-    pub fn into_slack_form(self) -> Result<SlackFormLP, String> {
+    pub fn get_c(&self) -> &Vec<f64> { &self.c }
+
+    pub fn get_a(&self) -> &Vec<Vec<f64>> { &self.a }
+
+    pub fn get_b(&self) -> &Vec<f64> { &self.b }
+
+    pub fn get_non_negative_indices(&self) -> &Option<Vec<usize>> {
+        &self.non_negative_indices
+    }
+
+    /// Add row range
+    ///
+    /// b_row - range <= a_row_1 . x_1 + ... + a_row_n . x_n <= b_row
+    /// A range of 0.0 makes the row an equality.
+    pub fn add_row_range(
+        mut self,
+        row: usize,
+        range: f64,
+    ) -> Result<Self, SolverError> {
+        if row >= self.b.len() {
+            return Err(SolverError::InvalidRow(row));
+        }
+        if range.is_nan() || range < 0. {
+            return Err(SolverError::InvalidInput(
+                "Row range should be a non negative number.".into(),
+            ));
+        }
+
+        self.ranges[row] = range;
+
+        Ok(self)
+    }
+
+    /// Add integer indices (sorted in ascending order)
+    ///
+    /// [j such as x_j in Integer]
+    /// Indices start at 0
+    pub fn add_integer_indices(
+        mut self,
+        indices: Vec<usize>,
+    ) -> Result<Self, SolverError> {
+        check_integer_indices(&indices, Some(self.c.len()))?;
+        if !self.integer_indices.is_empty() {
+            return Err(SolverError::InvalidState(
+                "Integer indices have already been set.".into(),
+            ));
+        }
+
+        self.integer_indices = indices;
+
+        Ok(self)
+    }
+
+    pub fn get_integer_indices(&self) -> &Vec<usize> { &self.integer_indices }
+
+    /// Whether some variables must take integer values
+    pub fn has_integer_indices(&self) -> bool {
+        !self.integer_indices.is_empty()
+    }
+
+    /// Same linear program with only the rows 'rows' (in this order), the
+    /// variable bounds and the ranges of these rows are kept.
+    pub fn select_rows(&self, rows: &[usize]) -> Result<Self, SolverError> {
+        if rows.is_empty() {
+            return Err(SolverError::EmptyInput("selection of rows".into()));
+        }
+        if let Some(row) = rows.iter().find(|row| **row >= self.b.len()) {
+            return Err(SolverError::InvalidRow(*row));
+        }
+
+        Ok(StandardFormLP {
+            c: self.c.clone(),
+            x: Vec::new(),
+            a: rows.iter().map(|row| self.a[*row].clone()).collect(),
+            b: rows.iter().map(|row| self.b[*row]).collect(),
+            non_negative_indices: self.non_negative_indices.clone(),
+            lower: self.lower.clone(),
+            upper: self.upper.clone(),
+            ranges: rows.iter().map(|row| self.ranges[*row]).collect(),
+            integer_indices: self.integer_indices.clone(),
+            min_objective: self.min_objective,
+            objective_value: None,
+            options: self.options.clone(),
+        })
+    }
+
+    pub fn get_lower(&self) -> &Vec<f64> { &self.lower }
+
+    pub fn get_upper(&self) -> &Vec<f64> { &self.upper }
+
+    pub fn get_ranges(&self) -> &Vec<f64> { &self.ranges }
+
+    /// Whether a row has a finite range (an equality for example)
+    pub fn has_row_ranges(&self) -> bool {
+        self.ranges.iter().any(|r| r.is_finite())
+    }
+
+    /// Whether a variable has other bounds than x_j >= 0.0
+    pub fn has_variable_bounds(&self) -> bool {
+        self.lower.iter().any(|l| *l != 0.)
+            || self.upper.iter().any(|u| u.is_finite())
+    }
+
+    pub fn into_slack_form(self) -> Result<SlackFormLP, SolverError> {
         let a: Vec<Vec<f64>> = self
             .a
             .into_iter()
             .map(|row| row.into_iter().map(|v| -v).collect())
             .collect();
 
-        SlackFormLP::new(a, self.b, self.c)
+        let n = self.c.len();
+        let mut slack_lp = SlackFormLP::new(a, self.b, self.c)?;
+        slack_lp.set_options(self.options);
+        for (i, range) in self.ranges.iter().enumerate() {
+            if range.is_finite() {
+                slack_lp.set_variable_bounds(n + i, 0., *range)?;
+            }
+        }
+        for (j, (lower, upper)) in
+            self.lower.iter().zip(self.upper.iter()).enumerate()
+        {
+            if *lower != 0. || upper.is_finite() {
+                slack_lp.set_variable_bounds(j, *lower, *upper)?;
+            }
+        }
+
+        Ok(slack_lp)
     }
 }
 
 impl fmt::Display for StandardFormLP {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        println!("LP - Standard form:");
-        println!("c: {:?}", self.c);
-        println!("a:");
+        writeln!(f, "LP - Standard form:")?;
+        writeln!(f, "c: {:?}", self.c)?;
+        writeln!(f, "a:")?;
         for row in self.a.iter() {
-            println!("\t{:?}", row);
+            writeln!(f, "\t{:?}", row)?;
         }
-        println!("b: {:?}", self.b);
-
-        write!(f, "\n")
+        writeln!(f, "b: {:?}", self.b)?;
+        writeln!(f, "lower: {:?}", self.lower)?;
+        writeln!(f, "upper: {:?}", self.upper)?;
+        writeln!(f, "ranges: {:?}", self.ranges)
     }
 }
 
 pub fn check_non_negative_indices(
     non_negative_indices: &Option<Vec<usize>>,
     max_dim: Option<usize>,
-) -> Result<(), String> {
+) -> Result<(), SolverError> {
     if let Some(nni) = &non_negative_indices {
         if nni.is_empty() {
-            return Err("It is not allowed to add an empty array of non \
-                        negative indices."
-                .into());
+            return Err(SolverError::EmptyInput(
+                "array of non negative indices".into(),
+            ));
         }
         if !is_uniq_sorted(nni) {
-            return Err("Non negative indices vector must be unique and \
-                        sorted in ascending order."
-                .into());
+            return Err(SolverError::InvalidInput(
+                "Non negative indices vector must be unique and sorted in \
+                 ascending order."
+                    .into(),
+            ));
         }
         if let Some(dim) = max_dim {
             if nni[nni.len() - 1] >= dim {
-                return Err("Non negative indices are out of bound of the \
-                            dimension size."
-                    .into());
+                return Err(SolverError::InvalidColumn(nni[nni.len() - 1]));
             }
         }
     }
 
     Ok(())
 }
+
+pub fn check_integer_indices(
+    indices: &[usize],
+    max_dim: Option<usize>,
+) -> Result<(), SolverError> {
+    if indices.is_empty() {
+        return Err(SolverError::EmptyInput("array of integer indices".into()));
+    }
+    if !is_uniq_sorted(indices) {
+        return Err(SolverError::InvalidInput(
+            "Integer indices vector must be unique and sorted in ascending \
+             order."
+                .into(),
+        ));
+    }
+    if let Some(dim) = max_dim {
+        if indices[indices.len() - 1] >= dim {
+            return Err(SolverError::InvalidColumn(indices[indices.len() - 1]));
+        }
+    }
+
+    Ok(())
+}
+
+pub fn check_variable_bounds(
+    lower: f64,
+    upper: f64,
+) -> Result<(), SolverError> {
+    if lower.is_nan() {
+        return Err(SolverError::InvalidInput(
+            "Lower bound should be a number.".into(),
+        ));
+    }
+    if lower == f64::INFINITY {
+        return Err(SolverError::InvalidInput(
+            "Lower bound should not be infinity.".into(),
+        ));
+    }
+    if upper.is_nan() {
+        return Err(SolverError::InvalidInput(
+            "Upper bound should be a number.".into(),
+        ));
+    }
+    if upper == f64::NEG_INFINITY {
+        return Err(SolverError::InvalidInput(
+            "Upper bound should not be minus infinity.".into(),
+        ));
+    }
+    if lower > upper {
+        return Err(SolverError::InvalidInput(format!(
+            "Lower bound is greater than upper bound ({} > {})",
+            lower, upper,
+        )));
+    }
+
+    Ok(())
+}
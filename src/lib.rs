@@ -3,6 +3,7 @@
 /// 1. has an optimal solution with a finite objective value.
 /// 2. is infeasible or
 /// 3. is unbounded
+///
 /// (Introduction to algorithm, 2009, Cormen, Leiserson, et al)
 pub mod algo;
 #[allow(non_snake_case)]
@@ -14,5 +15,10 @@ mod tests;
 mod types;
 
 pub use forms::builder::StandardFormBuilder;
-pub use forms::slack::SlackFormLP;
+pub use forms::options::{
+    CancelFlag, IterationCallback, IterationInfo, SolverOptions,
+};
+pub use forms::slack::{Interruption, SimplexResult, SlackFormLP};
+pub use forms::solution::Solution;
 pub use forms::standard::StandardFormLP;
+pub use types::error::SolverError;
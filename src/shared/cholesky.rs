@@ -0,0 +1,95 @@
+use super::super::types::error::SolverError;
+
+/// Pivots smaller than this value (relatively to the diagonal of the
+/// matrix) are considered as zero: the matrix is not full rank.
+const PIVOT_TOLERANCE: f64 = 1e-14;
+/// Value used instead of a zero pivot, which neutralizes the dependent rows.
+const SKIPPED_PIVOT: f64 = 1e64;
+
+/// Cholesky factorization of a symmetric positive (semi) definite matrix:
+/// ```ignore
+///     M = L . L^T
+/// ```
+/// When the matrix is only semi definite (dependent rows), the zero pivots
+/// (or slightly negative ones, due to round off errors) are replaced by a huge
+/// value, so that the matching components of the solution are (almost) zero.
+/// This is the usual trick of the interior point methods, whose normal
+/// equations become ill conditioned near the optimum.
+#[derive(Debug, PartialEq, Clone)]
+pub struct CholeskyFactorization {
+    l: Vec<Vec<f64>>, // lower triangular part only
+}
+
+impl CholeskyFactorization {
+    pub fn new(
+        matrix: &[Vec<f64>],
+    ) -> Result<CholeskyFactorization, SolverError> {
+        let size = matrix.len();
+        if size == 0 {
+            return Err(SolverError::EmptyInput("matrix to factorize".into()));
+        }
+        for (i, row) in matrix.iter().enumerate() {
+            if row.len() != size {
+                return Err(SolverError::DimensionMismatch(
+                    format!("row {} of the matrix to factorize", i),
+                    size,
+                    row.len(),
+                ));
+            }
+        }
+
+        let max_diagonal =
+            (0..size).fold(0., |acc: f64, i| acc.max(matrix[i][i].abs()));
+
+        let mut l: Vec<Vec<f64>> = Vec::with_capacity(size);
+        for i in 0..size {
+            let mut row = vec![0.; i + 1];
+            for j in 0..i {
+                let mut sum = matrix[i][j];
+                for k in 0..j {
+                    sum -= row[k] * l[j][k];
+                }
+                row[j] = sum / l[j][j];
+            }
+
+            let sum =
+                matrix[i][i] - row.iter().take(i).map(|v| v * v).sum::<f64>();
+            row[i] = if sum <= PIVOT_TOLERANCE * (1. + max_diagonal) {
+                SKIPPED_PIVOT
+            } else {
+                sum.sqrt()
+            };
+            l.push(row);
+        }
+
+        Ok(CholeskyFactorization { l })
+    }
+
+    pub fn get_size(&self) -> usize { self.l.len() }
+
+    /// Solve M . x = rhs
+    pub fn solve(&self, rhs: &[f64]) -> Vec<f64> {
+        let size = self.get_size();
+        let mut x = rhs.to_vec();
+
+        // L . y = rhs
+        for i in 0..size {
+            let mut sum = x[i];
+            for (j, x_j) in x.iter().enumerate().take(i) {
+                sum -= self.l[i][j] * x_j;
+            }
+            x[i] = sum / self.l[i][i];
+        }
+
+        // L^T . x = y
+        for i in (0..size).rev() {
+            let mut sum = x[i];
+            for (j, x_j) in x.iter().enumerate().skip(i + 1) {
+                sum -= self.l[j][i] * x_j;
+            }
+            x[i] = sum / self.l[i][i];
+        }
+
+        x
+    }
+}
@@ -0,0 +1,382 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use super::super::types::error::SolverError;
+
+/// Relative threshold of the partial pivoting: an entry is an acceptable
+/// pivot when its magnitude is at least this fraction of the largest one of
+/// its column.
+const PIVOT_THRESHOLD: f64 = 0.1;
+/// Number of columns (the sparsest ones) searched for each pivot.
+const SEARCHED_COLUMNS: usize = 4;
+
+/// Sparse vector, as (index, value) pairs.
+type SparseVector = Vec<(usize, f64)>;
+
+/// Sparse LU factorization of a square matrix, with Markowitz pivoting:
+/// ```ignore
+///     P . M . Q = L . U
+/// ```
+/// The active submatrix is kept sparse, by rows and by columns. Each pivot is
+/// chosen among the acceptable pivots (threshold partial pivoting) of the
+/// sparsest columns, so that the rows and the columns it updates are short
+/// and the factors stay almost as sparse as the matrix. The unit diagonal of
+/// L is implicit.
+///
+/// Column replacements (basis changes) are applied with the product form of
+/// the inverse, each replacement adds a sparse eta matrix:
+/// ```ignore
+///     M_k^-1 = E_k . ... . E_1 . M_0^-1
+/// ```
+/// The eta file grows at each update, so the factorization should be
+/// recomputed from scratch once in a while (see get_eta_count).
+#[derive(Debug, PartialEq, Clone)]
+pub struct LUFactorization {
+    size: usize,
+    order: Vec<usize>, // column of M eliminated at each step
+    pivot_rows: Vec<usize>, // row of M pivoted at each step
+    lower: Vec<SparseVector>, // multipliers of each step, by row of M
+    upper: Vec<(f64, SparseVector)>, // pivot, entries of the previous steps
+    etas: Vec<(usize, f64, SparseVector)>, // pivot row, pivot, others
+}
+
+impl LUFactorization {
+    /// Factorize a dense matrix, given by rows.
+    pub fn new(matrix: Vec<Vec<f64>>) -> Result<LUFactorization, SolverError> {
+        let size = matrix.len();
+        if size == 0 {
+            return Err(SolverError::EmptyInput("matrix to factorize".into()));
+        }
+        for (i, row) in matrix.iter().enumerate() {
+            if row.len() != size {
+                return Err(SolverError::DimensionMismatch(
+                    format!("row {} of the matrix to factorize", i),
+                    size,
+                    row.len(),
+                ));
+            }
+        }
+
+        let columns: Vec<SparseVector> = (0..size)
+            .map(|j| {
+                matrix
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, row)| row[j] != 0.)
+                    .map(|(i, row)| (i, row[j]))
+                    .collect()
+            })
+            .collect();
+        let columns: Vec<&[(usize, f64)]> =
+            columns.iter().map(|column| column.as_slice()).collect();
+
+        LUFactorization::from_columns(size, &columns)
+    }
+
+    /// Factorize a sparse matrix, given by columns of (row, value) entries.
+    pub fn from_columns(
+        size: usize,
+        columns: &[&[(usize, f64)]],
+    ) -> Result<LUFactorization, SolverError> {
+        if size == 0 {
+            return Err(SolverError::EmptyInput("matrix to factorize".into()));
+        }
+        if columns.len() != size {
+            return Err(SolverError::DimensionMismatch(
+                "columns of the matrix to factorize".into(),
+                size,
+                columns.len(),
+            ));
+        }
+
+        // Active submatrix, by rows (with the values) and by columns
+        let mut rows: Vec<SparseVector> = vec![Vec::new(); size];
+        let mut cols: Vec<Vec<usize>> = vec![Vec::new(); size];
+        for (j, column) in columns.iter().enumerate() {
+            for (i, value) in column.iter() {
+                if *i >= size {
+                    return Err(SolverError::InvalidRow(*i));
+                }
+                if *value != 0. {
+                    rows[*i].push((j, *value));
+                    cols[j].push(*i);
+                }
+            }
+        }
+
+        // Columns by number of non zeros, outdated entries are skipped
+        let mut sparsest: BinaryHeap<Reverse<(usize, usize)>> =
+            (0..size).map(|j| Reverse((cols[j].len(), j))).collect();
+        let mut eliminated = vec![false; size];
+        let mut position = vec![usize::MAX; size];
+
+        let mut order = Vec::with_capacity(size);
+        let mut pivot_rows = Vec::with_capacity(size);
+        let mut lower = Vec::with_capacity(size);
+        let mut pivots = Vec::with_capacity(size);
+        let mut upper_rows = Vec::with_capacity(size);
+
+        for _ in 0..size {
+            // Markowitz pivot: among the acceptable pivots of the sparsest
+            // columns, the one minimizing (row count - 1) . (col count - 1)
+            let mut searched: Vec<usize> = Vec::new();
+            while searched.len() < SEARCHED_COLUMNS {
+                match sparsest.pop() {
+                    Some(Reverse((count, j))) => {
+                        if !eliminated[j]
+                            && count == cols[j].len()
+                            && !searched.contains(&j)
+                        {
+                            searched.push(j);
+                        }
+                    },
+                    None => break,
+                }
+            }
+
+            let mut best: Option<(usize, f64, f64, usize, usize)> = None;
+            for j in searched.iter() {
+                let values: SparseVector = cols[*j]
+                    .iter()
+                    .map(|i| {
+                        let (_, value) =
+                            rows[*i].iter().find(|(k, _)| k == j).unwrap();
+                        (*i, *value)
+                    })
+                    .collect();
+                let largest =
+                    values.iter().fold(0., |acc: f64, (_, v)| acc.max(v.abs()));
+                if largest == 0. {
+                    return Err(SolverError::NumericalBreakdown(format!(
+                        "Matrix is singular, no pivot found at column {}.",
+                        j
+                    )));
+                }
+                for (i, value) in values.into_iter() {
+                    let ratio = value.abs() / largest;
+                    if ratio < PIVOT_THRESHOLD {
+                        continue;
+                    }
+                    let cost = (rows[i].len() - 1) * (cols[*j].len() - 1);
+                    let is_better = match best {
+                        None => true,
+                        Some((best_cost, best_ratio, ..)) => {
+                            cost < best_cost
+                                || (cost == best_cost && ratio > best_ratio)
+                        },
+                    };
+                    if is_better {
+                        best = Some((cost, ratio, value, i, *j));
+                    }
+                }
+            }
+            let (_, _, pivot, p, q) = if let Some(best) = best {
+                best
+            } else {
+                return Err(SolverError::NumericalBreakdown(
+                    "Matrix is singular, no column left to pivot.".into(),
+                ));
+            };
+            for j in searched.into_iter().filter(|j| *j != q) {
+                sparsest.push(Reverse((cols[j].len(), j)));
+            }
+
+            // The pivot row leaves the active submatrix, it is a row of U
+            eliminated[q] = true;
+            let mut pivot_row = std::mem::take(&mut rows[p]);
+            for (j, _) in pivot_row.iter() {
+                if let Some(k) = cols[*j].iter().position(|i| *i == p) {
+                    cols[*j].swap_remove(k);
+                }
+            }
+            pivot_row.retain(|(j, _)| *j != q);
+
+            // row_i = row_i - l_i . row_p for each row i of the pivot column
+            let mut multipliers = Vec::new();
+            for i in std::mem::take(&mut cols[q]).into_iter() {
+                let row = &mut rows[i];
+                let k = row.iter().position(|(j, _)| *j == q).unwrap();
+                let (_, value) = row.swap_remove(k);
+                let l = value / pivot;
+                multipliers.push((i, l));
+
+                for (k, (j, _)) in row.iter().enumerate() {
+                    position[*j] = k;
+                }
+                for (j, u) in pivot_row.iter() {
+                    if position[*j] == usize::MAX {
+                        row.push((*j, -l * u));
+                        cols[*j].push(i);
+                    } else {
+                        row[position[*j]].1 -= l * u;
+                    }
+                }
+                for (j, _) in row.iter() {
+                    position[*j] = usize::MAX;
+                }
+
+                // Entries cancelled by the update leave the submatrix
+                let mut k = 0;
+                while k < row.len() {
+                    if row[k].1 == 0. {
+                        let (j, _) = row.swap_remove(k);
+                        if let Some(l) = cols[j].iter().position(|r| *r == i) {
+                            cols[j].swap_remove(l);
+                        }
+                    } else {
+                        k += 1;
+                    }
+                }
+            }
+            for (j, _) in pivot_row.iter() {
+                sparsest.push(Reverse((cols[*j].len(), *j)));
+            }
+
+            order.push(q);
+            pivot_rows.push(p);
+            lower.push(multipliers);
+            pivots.push(pivot);
+            upper_rows.push(pivot_row);
+        }
+
+        // U by columns: the entry of the row of a step, at the column of a
+        // later step
+        let mut step_of_column = vec![0; size];
+        for (step, j) in order.iter().enumerate() {
+            step_of_column[*j] = step;
+        }
+        let mut upper: Vec<(f64, SparseVector)> =
+            pivots.into_iter().map(|pivot| (pivot, Vec::new())).collect();
+        for (step, row) in upper_rows.into_iter().enumerate() {
+            for (j, value) in row.into_iter() {
+                upper[step_of_column[j]].1.push((step, value));
+            }
+        }
+
+        Ok(LUFactorization {
+            size,
+            order,
+            pivot_rows,
+            lower,
+            upper,
+            etas: Vec::new(),
+        })
+    }
+
+    pub fn get_size(&self) -> usize { self.size }
+
+    pub fn get_eta_count(&self) -> usize { self.etas.len() }
+
+    /// Solve M . x = rhs (FTRAN)
+    pub fn solve(&self, rhs: &[f64]) -> Vec<f64> {
+        // L . z = P . rhs
+        let mut work = rhs.to_vec();
+        for (row, multipliers) in self.pivot_rows.iter().zip(self.lower.iter())
+        {
+            let value = work[*row];
+            if value != 0. {
+                for (i, l) in multipliers.iter() {
+                    work[*i] -= l * value;
+                }
+            }
+        }
+        let mut z: Vec<f64> =
+            self.pivot_rows.iter().map(|row| work[*row]).collect();
+
+        // U . Q^T . x = z, column by column
+        let mut x = vec![0.; self.size];
+        for step in (0..self.size).rev() {
+            let (pivot, entries) = &self.upper[step];
+            let value = z[step] / pivot;
+            if value != 0. {
+                for (k, u) in entries.iter() {
+                    z[*k] -= u * value;
+                }
+            }
+            x[self.order[step]] = value;
+        }
+
+        // Apply eta matrices in order
+        for (row, pivot, others) in self.etas.iter() {
+            let x_r = x[*row] / pivot;
+            if x_r != 0. {
+                for (i, value) in others.iter() {
+                    x[*i] -= value * x_r;
+                }
+            }
+            x[*row] = x_r;
+        }
+
+        x
+    }
+
+    /// Solve M^T . y = rhs (BTRAN)
+    pub fn solve_transpose(&self, rhs: &[f64]) -> Vec<f64> {
+        let mut z = rhs.to_vec();
+
+        // Apply transposed eta matrices in reverse order
+        for (row, pivot, others) in self.etas.iter().rev() {
+            let sum: f64 =
+                others.iter().map(|(i, value)| value * z[*i]).sum::<f64>();
+            z[*row] = (z[*row] - sum) / pivot;
+        }
+
+        // U^T . w = Q^T . z, step by step
+        let mut w = vec![0.; self.size];
+        for step in 0..self.size {
+            let (pivot, entries) = &self.upper[step];
+            let sum: f64 = entries.iter().map(|(k, u)| u * w[*k]).sum();
+            w[step] = (z[self.order[step]] - sum) / pivot;
+        }
+
+        // L^T . P . y = w, steps in reverse order
+        let mut y = vec![0.; self.size];
+        for (row, value) in self.pivot_rows.iter().zip(w) {
+            y[*row] = value;
+        }
+        for (row, multipliers) in
+            self.pivot_rows.iter().zip(self.lower.iter()).rev()
+        {
+            let sum: f64 = multipliers.iter().map(|(i, l)| l * y[*i]).sum();
+            y[*row] -= sum;
+        }
+
+        y
+    }
+
+    /// Replace the column at index 'row' of the factorized matrix.
+    /// 'column' must be the solution of M . column = new column, as given by
+    /// solve, so that column[row] is the pivot element.
+    pub fn update(
+        &mut self,
+        row: usize,
+        column: Vec<f64>,
+    ) -> Result<(), SolverError> {
+        if row >= self.get_size() {
+            return Err(SolverError::InvalidRow(row));
+        }
+        if column.len() != self.get_size() {
+            return Err(SolverError::DimensionMismatch(
+                "column".into(),
+                self.get_size(),
+                column.len(),
+            ));
+        }
+        if column[row] == 0. {
+            return Err(SolverError::NumericalBreakdown(
+                "Pivot element is zero, the updated matrix would be singular."
+                    .into(),
+            ));
+        }
+
+        let others = column
+            .iter()
+            .enumerate()
+            .filter(|(i, value)| *i != row && **value != 0.)
+            .map(|(i, value)| (i, *value))
+            .collect();
+        self.etas.push((row, column[row], others));
+
+        Ok(())
+    }
+}
@@ -1 +1,3 @@
+pub mod cholesky;
+pub mod lu;
 pub mod utils;
@@ -1,4 +1,4 @@
-pub fn is_uniq_sorted<T: PartialOrd>(v: &Vec<T>) -> bool {
+pub fn is_uniq_sorted<T: PartialOrd>(v: &[T]) -> bool {
     if v.is_empty() || v.len() == 1 {
         return true;
     }
@@ -12,6 +12,4 @@ pub fn is_uniq_sorted<T: PartialOrd>(v: &Vec<T>) -> bool {
     true
 }
 
-pub fn all_zeroes(v: &Vec<f64>) -> bool {
-    v.iter().fold(true, |acc, v| acc && *v == 0.0)
-}
+pub fn all_zeroes(v: &[f64]) -> bool { v.iter().all(|v| *v == 0.0) }
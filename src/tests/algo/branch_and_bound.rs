@@ -0,0 +1,251 @@
+use super::super::super::algo::branch_and_bound::{
+    branch_and_bound, MilpResult,
+};
+use super::super::super::forms::builder::StandardFormBuilder;
+use super::super::super::forms::options::{
+    MilpOptions, NodeSelection, SolverOptions,
+};
+use super::super::super::forms::slack::{Interruption, SimplexResult};
+use super::super::super::forms::standard::StandardFormLP;
+use super::super::helpers::assert_close;
+
+// max 5 x_1 + 8 x_2
+// x_1 + x_2 <= 6
+// 5 x_1 + 9 x_2 <= 45
+// The relaxation gives 41.25 at (2.25, 3.75), the integer optimum is 40
+fn integer_lp() -> StandardFormLP {
+    StandardFormBuilder::new()
+        .add_max_objective(vec![5., 8.])
+        .unwrap()
+        .add_integer_indices(vec![0, 1])
+        .unwrap()
+        .add_less_than_or_equal_constraint(vec![1., 1.], 6.)
+        .unwrap()
+        .add_less_than_or_equal_constraint(vec![5., 9.], 45.)
+        .unwrap()
+        .build()
+        .unwrap()
+}
+
+// Binary knapsack with two rows, several solutions reach the optimum 45
+fn knapsack() -> StandardFormLP {
+    let mut builder = StandardFormBuilder::new()
+        .add_max_objective(vec![10., 13., 7., 8., 15., 9., 6., 11.])
+        .unwrap()
+        .add_integer_indices((0..8).collect())
+        .unwrap();
+    for j in 0..8 {
+        builder = builder.add_variable_bounds(j, 0., 1.).unwrap();
+    }
+
+    builder
+        .add_less_than_or_equal_constraint(
+            vec![4., 6., 3., 5., 7., 4., 3., 5.],
+            20.,
+        )
+        .unwrap()
+        .add_less_than_or_equal_constraint(
+            vec![1., 2., 1., 3., 2., 1., 2., 1.],
+            7.,
+        )
+        .unwrap()
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn algo_branch_and_bound_branch_and_bound_max_objective() {
+    let mut standard_form = integer_lp();
+    let relaxation = standard_form.solve().unwrap();
+    assert!(matches!(relaxation, SimplexResult::Optimal(_)));
+    assert!(
+        (standard_form.get_objective_value().unwrap() - 41.25).abs() < 1e-9
+    );
+
+    let solution =
+        match branch_and_bound(&standard_form, &MilpOptions::new()).unwrap() {
+            MilpResult::Optimal(solution) => solution,
+            result => panic!("{:?}", result),
+        };
+
+    assert_close(solution.get_x(), &[0., 5.]);
+    assert_eq!(solution.get_objective_value(), 40.);
+    assert_eq!(solution.get_bound(), 40.);
+    assert_eq!(solution.get_gap(), 0.);
+    assert!(solution.get_nodes() > 1);
+}
+
+#[test]
+fn algo_branch_and_bound_branch_and_bound_min_objective() {
+    // min x_1 + x_2 with 2 x_1 + 2 x_2 >= 3, the relaxation gives 1.5
+    let standard_form = StandardFormBuilder::new()
+        .add_min_objective(vec![1., 1.])
+        .unwrap()
+        .add_integer_indices(vec![0, 1])
+        .unwrap()
+        .add_greater_than_or_equal_constraint(vec![2., 2.], 3.)
+        .unwrap()
+        .build()
+        .unwrap();
+
+    let solution =
+        match branch_and_bound(&standard_form, &MilpOptions::new()).unwrap() {
+            MilpResult::Optimal(solution) => solution,
+            result => panic!("{:?}", result),
+        };
+
+    assert_eq!(solution.get_objective_value(), 2.);
+    assert_eq!(solution.get_bound(), 2.);
+    assert_eq!(solution.get_gap(), 0.);
+    let x = solution.get_x();
+    assert!((x[0] + x[1] - 2.).abs() < 1e-9);
+}
+
+#[test]
+fn algo_branch_and_bound_branch_and_bound_mixed_integer() {
+    // max x_1 + 2 x_2 with 2 x_1 + 4 x_2 <= 9 and x_1 <= 2.5, x_2 integer
+    let standard_form = StandardFormBuilder::new()
+        .add_max_objective(vec![1., 2.])
+        .unwrap()
+        .add_integer_indices(vec![1])
+        .unwrap()
+        .add_variable_bounds(0, 0., 2.5)
+        .unwrap()
+        .add_less_than_or_equal_constraint(vec![2., 4.], 9.)
+        .unwrap()
+        .build()
+        .unwrap();
+
+    let solution =
+        match branch_and_bound(&standard_form, &MilpOptions::new()).unwrap() {
+            MilpResult::Optimal(solution) => solution,
+            result => panic!("{:?}", result),
+        };
+
+    // x_2 = 1 leaves x_1 = 2.5, x_2 = 2 leaves x_1 = 0.5: both give 4.5
+    assert!((solution.get_objective_value() - 4.5).abs() < 1e-9);
+    assert_eq!(solution.get_x()[1].fract(), 0.);
+}
+
+#[test]
+fn algo_branch_and_bound_branch_and_bound_unfeasible() {
+    // 2 x_1 + 2 x_2 = 1 has no integer solution
+    let standard_form = StandardFormBuilder::new()
+        .add_max_objective(vec![1., 1.])
+        .unwrap()
+        .add_integer_indices(vec![0, 1])
+        .unwrap()
+        .add_equality_constraint(vec![2., 2.], 1.)
+        .unwrap()
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        branch_and_bound(&standard_form, &MilpOptions::new()).unwrap(),
+        MilpResult::Unfeasible
+    );
+
+    // No integer value between the bounds
+    let standard_form = StandardFormBuilder::new()
+        .add_max_objective(vec![1., 1.])
+        .unwrap()
+        .add_integer_indices(vec![0])
+        .unwrap()
+        .add_variable_bounds(0, 0.2, 0.8)
+        .unwrap()
+        .add_less_than_or_equal_constraint(vec![1., 1.], 4.)
+        .unwrap()
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        branch_and_bound(&standard_form, &MilpOptions::new()).unwrap(),
+        MilpResult::Unfeasible
+    );
+}
+
+#[test]
+fn algo_branch_and_bound_branch_and_bound_unbounded() {
+    let standard_form = StandardFormBuilder::new()
+        .add_max_objective(vec![1., 1.])
+        .unwrap()
+        .add_integer_indices(vec![0, 1])
+        .unwrap()
+        .add_less_than_or_equal_constraint(vec![1., -1.], 1.5)
+        .unwrap()
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        branch_and_bound(&standard_form, &MilpOptions::new()).unwrap(),
+        MilpResult::Unbounded
+    );
+}
+
+#[test]
+fn algo_branch_and_bound_branch_and_bound_gap_tolerance() {
+    let options = MilpOptions::new().set_mip_gap_tolerance(0.1).unwrap();
+
+    let solution = match branch_and_bound(&integer_lp(), &options).unwrap() {
+        MilpResult::Optimal(solution) => solution,
+        result => panic!("{:?}", result),
+    };
+
+    // The search stops as soon as the incumbent is within the gap tolerance
+    assert!(solution.get_gap() <= 0.1);
+    assert!(solution.get_bound() >= solution.get_objective_value());
+    assert!(solution.get_bound() <= 41.25 + 1e-9);
+}
+
+#[test]
+fn algo_branch_and_bound_branch_and_bound_iteration_limit() {
+    let options = SolverOptions::new().set_iteration_limit(1);
+    let standard_form = integer_lp().set_options(options);
+
+    assert_eq!(
+        branch_and_bound(&standard_form, &MilpOptions::new()).unwrap(),
+        MilpResult::Interrupted(Interruption::IterationLimit, None)
+    );
+
+    // The limit applies to the whole search, not to each relaxation
+    let options = SolverOptions::new().set_iteration_limit(4);
+    let standard_form = integer_lp().set_options(options);
+
+    match branch_and_bound(&standard_form, &MilpOptions::new()).unwrap() {
+        MilpResult::Interrupted(Interruption::IterationLimit, solution) => {
+            if let Some(solution) = solution {
+                assert!(solution.get_bound() >= solution.get_objective_value());
+            }
+        },
+        result => panic!("{:?}", result),
+    }
+}
+
+#[test]
+fn algo_branch_and_bound_branch_and_bound_node_selections() {
+    let mut nodes = Vec::new();
+    for selection in [
+        NodeSelection::DepthFirst,
+        NodeSelection::BestBound,
+        NodeSelection::BestEstimate,
+        NodeSelection::Hybrid,
+    ]
+    .iter()
+    {
+        let options = MilpOptions::new().set_node_selection(*selection);
+        let solution = match branch_and_bound(&knapsack(), &options).unwrap() {
+            MilpResult::Optimal(solution) => solution,
+            result => panic!("{:?}", result),
+        };
+
+        assert_eq!(solution.get_objective_value(), 45.);
+        assert_eq!(solution.get_gap(), 0.);
+        assert!(solution.get_x().iter().all(|x| *x == 0. || *x == 1.));
+        nodes.push(solution.get_nodes());
+    }
+
+    // Best bound proves the optimality with fewer nodes than depth first,
+    // the hybrid selection switches to best bound after the first incumbent
+    assert!(nodes[1] < nodes[0]);
+    assert!(nodes[3] < nodes[0]);
+}
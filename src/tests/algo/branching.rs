@@ -0,0 +1,202 @@
+use super::super::super::algo::branch_and_bound::{
+    branch_and_bound, branch_and_bound_with, MilpResult,
+};
+use super::super::super::algo::branching::{
+    BranchingRule, MostFractional, Pseudocosts, StrongBranching,
+};
+use super::super::super::algo::simplex::simplex_lp_chvatal;
+use super::super::super::forms::builder::StandardFormBuilder;
+use super::super::super::forms::options::{Branching, MilpOptions};
+use super::super::super::forms::slack::{SimplexResult, SlackFormLP};
+use super::super::super::forms::standard::StandardFormLP;
+
+const BRANCHINGS: [Branching; 4] = [
+    Branching::MostFractional,
+    Branching::Pseudocost,
+    Branching::Strong { iterations: 10 },
+    Branching::Reliability { iterations: 10, reliability: 1 },
+];
+
+// Binary knapsack with two rows, several solutions reach the optimum 45
+fn knapsack() -> StandardFormLP {
+    let mut builder = StandardFormBuilder::new()
+        .add_max_objective(vec![10., 13., 7., 8., 15., 9., 6., 11.])
+        .unwrap()
+        .add_integer_indices((0..8).collect())
+        .unwrap();
+    for j in 0..8 {
+        builder = builder.add_variable_bounds(j, 0., 1.).unwrap();
+    }
+
+    builder
+        .add_less_than_or_equal_constraint(
+            vec![4., 6., 3., 5., 7., 4., 3., 5.],
+            20.,
+        )
+        .unwrap()
+        .add_less_than_or_equal_constraint(
+            vec![1., 2., 1., 3., 2., 1., 2., 1.],
+            7.,
+        )
+        .unwrap()
+        .build()
+        .unwrap()
+}
+
+// max 5 x_1 + 8 x_2 with x_1 + x_2 <= 6 and 5 x_1 + 9 x_2 <= 45, the
+// relaxation is optimal at (2.25, 3.75)
+fn optimal_relaxation() -> SlackFormLP {
+    let mut slack_form = StandardFormLP::new(
+        vec![5., 8.],
+        vec![vec![1., 1.], vec![5., 9.]],
+        vec![6., 45.],
+        None,
+    )
+    .unwrap()
+    .into_slack_form()
+    .unwrap();
+    assert!(matches!(
+        simplex_lp_chvatal(&mut slack_form).unwrap(),
+        SimplexResult::Optimal(_)
+    ));
+
+    slack_form
+}
+
+#[test]
+fn algo_branching_branching_default() {
+    assert_eq!(Branching::default(), Branching::MostFractional);
+    assert_eq!(MilpOptions::new().get_branching(), Branching::MostFractional);
+}
+
+#[test]
+fn algo_branching_pseudocosts() {
+    let mut pseudocosts = Pseudocosts::new(3);
+
+    // No observation at all
+    assert_eq!(pseudocosts.get(0, false), 1.);
+    assert_eq!(pseudocosts.get_count(0, false), 0);
+
+    pseudocosts.update(0, false, 0.5, 2.);
+    pseudocosts.update(0, false, 0.25, 2.);
+    pseudocosts.update(1, false, 1., 2.);
+    pseudocosts.update(1, true, 0.5, 1.);
+
+    assert_eq!(pseudocosts.get(0, false), 6.);
+    assert_eq!(pseudocosts.get_count(0, false), 2);
+    assert_eq!(pseudocosts.get(1, true), 2.);
+    // Average of the observed variables
+    assert_eq!(pseudocosts.get(2, false), 4.);
+    assert_eq!(pseudocosts.get(0, true), 2.);
+    assert_eq!(pseudocosts.degradations(0, 1.75), (4.5, 0.5));
+}
+
+#[test]
+fn algo_branching_most_fractional() {
+    let slack_form = optimal_relaxation();
+    let mut pseudocosts = Pseudocosts::new(2);
+
+    let choice = MostFractional.select(
+        &slack_form,
+        &[(0, 0.2), (1, 2.5), (2, 3.7)],
+        &mut pseudocosts,
+    );
+
+    assert_eq!(choice, 1);
+}
+
+#[test]
+fn algo_branching_strong_branching() {
+    let slack_form = optimal_relaxation();
+    let mut pseudocosts = Pseudocosts::new(2);
+    let mut rule = StrongBranching { iterations: 10 };
+
+    rule.select(&slack_form, &[(0, 2.25), (1, 3.75)], &mut pseudocosts);
+
+    // Both children of both candidates are feasible, the trial solves give
+    // the pseudocosts
+    for j in 0..2 {
+        assert_eq!(pseudocosts.get_count(j, false), 1);
+        assert_eq!(pseudocosts.get_count(j, true), 1);
+    }
+    // x_1 <= 2 gives 41.11 at (2, 35 / 9): 41.25 - 41.11 over 0.25
+    assert!((pseudocosts.get(0, false) - 5. / 9.).abs() < 1e-9);
+}
+
+#[test]
+fn algo_branching_strong_branching_failed_trials() {
+    // The initial basis is not dual feasible, no trial can be solved
+    let slack_form = StandardFormLP::new(
+        vec![5., 8.],
+        vec![vec![1., 1.], vec![5., 9.]],
+        vec![6., 45.],
+        None,
+    )
+    .unwrap()
+    .into_slack_form()
+    .unwrap();
+    let mut pseudocosts = Pseudocosts::new(2);
+    pseudocosts.update(1, false, 1., 4.);
+    pseudocosts.update(1, true, 1., 4.);
+    let mut rule = StrongBranching { iterations: 10 };
+
+    let choice =
+        rule.select(&slack_form, &[(0, 2.9), (1, 3.5)], &mut pseudocosts);
+
+    // Both directions fall back on the pseudocosts, x_2 is the most balanced
+    assert_eq!(choice, 1);
+    assert_eq!(pseudocosts.get_count(0, false), 0);
+    assert_eq!(pseudocosts.get_count(1, true), 1);
+}
+
+#[test]
+fn algo_branching_branch_and_bound() {
+    for branching in BRANCHINGS.iter() {
+        let options = MilpOptions::new().set_branching(*branching);
+
+        let solution = match branch_and_bound(&knapsack(), &options).unwrap() {
+            MilpResult::Optimal(solution) => solution,
+            result => panic!("{:?}", result),
+        };
+
+        assert_eq!(solution.get_objective_value(), 45.);
+        assert_eq!(solution.get_gap(), 0.);
+    }
+}
+
+/// Last candidate, counting the calls
+struct LastCandidate {
+    calls: usize,
+}
+
+impl BranchingRule for LastCandidate {
+    fn select(
+        &mut self,
+        _slack_lp: &SlackFormLP,
+        candidates: &[(usize, f64)],
+        _pseudocosts: &mut Pseudocosts,
+    ) -> usize {
+        self.calls += 1;
+        candidates.len() - 1
+    }
+}
+
+#[test]
+fn algo_branching_branch_and_bound_with_rule() {
+    let mut rule = LastCandidate { calls: 0 };
+
+    let solution = match branch_and_bound_with(
+        &knapsack(),
+        &MilpOptions::new(),
+        &mut rule,
+    )
+    .unwrap()
+    {
+        MilpResult::Optimal(solution) => solution,
+        result => panic!("{:?}", result),
+    };
+
+    assert_eq!(solution.get_objective_value(), 45.);
+    assert!(rule.calls > 0);
+    assert!(rule.calls < solution.get_nodes());
+}
@@ -0,0 +1,223 @@
+use super::super::super::algo::crossover::crossover;
+use super::super::super::algo::interior_point::interior_point_mehrotra;
+use super::super::super::algo::simplex::simplex_lp_chvatal;
+use super::super::super::forms::slack::SimplexResult;
+use super::super::super::forms::standard::StandardFormLP;
+use super::super::super::types::error::SolverError;
+use super::super::helpers::{assert_optimal_within, INTERIOR_TOLERANCE};
+
+fn interior_solution(standard_form: &StandardFormLP) -> Vec<f64> {
+    let result = interior_point_mehrotra(standard_form).unwrap();
+    if let SimplexResult::Optimal(solution) = result.get_result() {
+        let x = solution.get_x();
+        x.clone()
+    } else {
+        panic!("Optimal solution expected, got {:?}", result.get_result());
+    }
+}
+
+#[test]
+fn algo_crossover_crossover_optimal_face() {
+    // The interior point method returns (2, 2), the middle of the optimal
+    // face, the crossover returns one of its vertices
+    let c = vec![1., 1.];
+    let a = vec![vec![1., 1.]];
+    let b = vec![4.];
+
+    let standard_form = StandardFormLP::new(c, a, b, None).unwrap();
+    let x = interior_solution(&standard_form);
+
+    let slack_form = crossover(&standard_form, &x).unwrap();
+
+    assert_optimal_within(
+        &slack_form.compute_solution().unwrap(),
+        &[4., 0.],
+        INTERIOR_TOLERANCE,
+    );
+    assert!((slack_form.get_v() - 4.).abs() < 1e-6);
+    assert!(slack_form.is_dual_feasible());
+}
+
+#[test]
+fn algo_crossover_crossover_feasible_case_1() {
+    let c = vec![3., 1., 2.];
+    let a = vec![vec![1., 1., 3.], vec![2., 2., 5.], vec![4., 1., 2.]];
+    let b = vec![30., 24., 36.];
+
+    let standard_form = StandardFormLP::new(c, a, b, None).unwrap();
+    let x = interior_solution(&standard_form);
+
+    let slack_form = crossover(&standard_form, &x).unwrap();
+
+    assert_optimal_within(
+        &slack_form.compute_solution().unwrap(),
+        &[8., 4., 0.],
+        INTERIOR_TOLERANCE,
+    );
+    assert!((slack_form.get_v() - 28.).abs() < 1e-6);
+}
+
+#[test]
+fn algo_crossover_crossover_non_basic_feasible_case_1() {
+    let c = vec![2., -1.];
+    let a = vec![vec![2., -1.], vec![1., -5.]];
+    let b = vec![2., -4.];
+
+    let standard_form = StandardFormLP::new(c, a, b, None).unwrap();
+    let x = interior_solution(&standard_form);
+
+    let slack_form = crossover(&standard_form, &x).unwrap();
+
+    let mut expected = standard_form.clone().into_slack_form().unwrap();
+    simplex_lp_chvatal(&mut expected).unwrap();
+
+    assert!((slack_form.get_v() - expected.get_v()).abs() < 1e-6);
+    assert!(slack_form.get_b().iter().all(|b| *b >= 0.));
+    assert!(slack_form.is_dual_feasible());
+}
+
+#[test]
+fn algo_crossover_crossover_degenerate() {
+    let c = vec![1., 1.];
+    let a = vec![
+        vec![1., 0.],
+        vec![0., 1.],
+        vec![1., 1.],
+        vec![2., 1.],
+        vec![1., 2.],
+    ];
+    let b = vec![1., 1., 2., 3., 3.];
+
+    let standard_form = StandardFormLP::new(c, a, b, None).unwrap();
+    let x = interior_solution(&standard_form);
+
+    let slack_form = crossover(&standard_form, &x).unwrap();
+
+    assert_optimal_within(
+        &slack_form.compute_solution().unwrap(),
+        &[1., 1.],
+        INTERIOR_TOLERANCE,
+    );
+}
+
+#[test]
+fn algo_crossover_crossover_from_non_optimal_point() {
+    // The cleanup phase finishes the work when the given point is far from
+    // the optimum
+    let c = vec![1., 1.];
+    let a = vec![vec![4., -1.], vec![2., 1.], vec![-5., 2.]];
+    let b = vec![8., 10., 2.];
+
+    let standard_form = StandardFormLP::new(c, a, b, None).unwrap();
+
+    let slack_form = crossover(&standard_form, &[0., 0.]).unwrap();
+
+    assert_optimal_within(
+        &slack_form.compute_solution().unwrap(),
+        &[2., 6.],
+        INTERIOR_TOLERANCE,
+    );
+}
+
+#[test]
+fn algo_crossover_crossover_unbounded() {
+    let c = vec![1., 1.];
+    let a = vec![vec![1., -1.]];
+    let b = vec![1.];
+
+    let standard_form = StandardFormLP::new(c, a, b, None).unwrap();
+
+    let result = crossover(&standard_form, &[1., 0.]);
+
+    assert_eq!(
+        result,
+        Err(SolverError::Unsolvable(
+            "Crossover failed, the linear program is unbounded.".into()
+        ))
+    );
+}
+
+#[test]
+fn algo_crossover_crossover_size_mismatch() {
+    let c = vec![1., 1.];
+    let a = vec![vec![1., 1.]];
+    let b = vec![4.];
+
+    let standard_form = StandardFormLP::new(c, a, b, None).unwrap();
+
+    let result = crossover(&standard_form, &[1., 1., 1.]);
+
+    assert_eq!(
+        result,
+        Err(SolverError::DimensionMismatch("solution".into(), 2, 3))
+    );
+}
+
+#[test]
+fn algo_crossover_crossover_row_ranges() {
+    // x_1 + x_2 + x_3 = 4 and x_1 - x_2 + 2 . x_3 = 1
+    let c = vec![1., 2., 1.];
+    let a = vec![vec![1., 1., 1.], vec![1., -1., 2.], vec![0., 1., 3.]];
+    let b = vec![4., 1., 6.];
+
+    let standard_form = StandardFormLP::new(c, a, b, None)
+        .unwrap()
+        .add_row_range(0, 0.)
+        .unwrap()
+        .add_row_range(1, 0.)
+        .unwrap();
+    let x = interior_solution(&standard_form);
+
+    let slack_form = crossover(&standard_form, &x).unwrap();
+
+    assert_optimal_within(
+        &slack_form.compute_solution().unwrap(),
+        &[4. / 7., 15. / 7., 9. / 7.],
+        INTERIOR_TOLERANCE,
+    );
+    assert!(slack_form.is_primal_feasible());
+}
+
+#[test]
+fn algo_crossover_crossover_variable_bounds() {
+    // x_1 is at its upper bound in the optimal solution
+    let c = vec![1., 1.];
+    let a = vec![vec![1., 2.]];
+    let b = vec![10.];
+
+    let standard_form = StandardFormLP::new(c, a, b, None)
+        .unwrap()
+        .add_variable_bounds(0, 0., 3.)
+        .unwrap();
+    let x = interior_solution(&standard_form);
+
+    let slack_form = crossover(&standard_form, &x).unwrap();
+
+    assert_optimal_within(
+        &slack_form.compute_solution().unwrap(),
+        &[3., 3.5],
+        INTERIOR_TOLERANCE,
+    );
+    assert!((slack_form.get_v() - 6.5).abs() < 1e-6);
+    assert!(slack_form.is_primal_feasible());
+}
+
+#[test]
+fn algo_crossover_crossover_free_variable() {
+    let c = vec![1., 2.];
+    let a = vec![vec![-1., 1.], vec![1., 1.]];
+    let b = vec![4., 2.];
+
+    let standard_form = StandardFormLP::new(c, a, b, Some(vec![0])).unwrap();
+    let x = interior_solution(&standard_form);
+
+    let slack_form = crossover(&standard_form, &x).unwrap();
+
+    assert_optimal_within(
+        &slack_form.compute_solution().unwrap(),
+        &[-1., 3.],
+        INTERIOR_TOLERANCE,
+    );
+    assert!((slack_form.get_v() - 5.).abs() < 1e-6);
+    assert!(slack_form.is_dual_feasible());
+}
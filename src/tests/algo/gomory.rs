@@ -0,0 +1,134 @@
+use super::super::super::algo::branch_and_bound::{
+    branch_and_bound, MilpResult,
+};
+use super::super::super::algo::gomory::{gomory_cuts, gomory_rounds};
+use super::super::super::algo::simplex::simplex_lp_chvatal;
+use super::super::super::forms::builder::StandardFormBuilder;
+use super::super::super::forms::options::MilpOptions;
+use super::super::super::forms::slack::{SimplexResult, SlackFormLP};
+use super::super::super::forms::standard::StandardFormLP;
+use super::super::super::types::error::SolverError;
+
+// max 5 x_1 + 8 x_2
+// x_1 + x_2 <= 6
+// 5 x_1 + 9 x_2 <= 45
+// The relaxation gives 41.25 at (2.25, 3.75), the integer optimum is 40
+fn integer_lp() -> StandardFormLP {
+    StandardFormBuilder::new()
+        .add_max_objective(vec![5., 8.])
+        .unwrap()
+        .add_integer_indices(vec![0, 1])
+        .unwrap()
+        .add_less_than_or_equal_constraint(vec![1., 1.], 6.)
+        .unwrap()
+        .add_less_than_or_equal_constraint(vec![5., 9.], 45.)
+        .unwrap()
+        .build()
+        .unwrap()
+}
+
+fn optimal_relaxation() -> SlackFormLP {
+    let mut slack_form = integer_lp().into_slack_form().unwrap();
+    assert!(matches!(
+        simplex_lp_chvatal(&mut slack_form).unwrap(),
+        SimplexResult::Optimal(_)
+    ));
+
+    slack_form
+}
+
+// Every integer point of the linear program
+fn integer_points() -> Vec<Vec<f64>> {
+    let mut points = Vec::new();
+    for x_1 in 0..=6 {
+        for x_2 in 0..=5 {
+            let (x_1, x_2) = (x_1 as f64, x_2 as f64);
+            if x_1 + x_2 <= 6. && 5. * x_1 + 9. * x_2 <= 45. {
+                points.push(vec![x_1, x_2]);
+            }
+        }
+    }
+
+    points
+}
+
+fn value(a: &[f64], x: &[f64]) -> f64 {
+    a.iter().zip(x.iter()).map(|(a, x)| a * x).sum()
+}
+
+#[test]
+fn algo_gomory_gomory_cuts() {
+    let slack_form = optimal_relaxation();
+
+    let cuts = gomory_cuts(&slack_form, &[0, 1], &MilpOptions::new()).unwrap();
+
+    // Both basic variables are fractional
+    assert_eq!(cuts.len(), 2);
+    for (a, b) in cuts.iter() {
+        // The relaxation optimum is cut off, no integer point is
+        assert!(value(a, &[2.25, 3.75]) > b + 1e-9);
+        for point in integer_points().iter() {
+            assert!(value(a, point) <= b + 1e-9, "{:?} {:?}", a, point);
+        }
+    }
+
+    // No cut without integer indices
+    assert!(gomory_cuts(&slack_form, &[], &MilpOptions::new())
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn algo_gomory_gomory_rounds() {
+    let mut slack_form = optimal_relaxation();
+
+    let solution =
+        match gomory_rounds(&mut slack_form, &[0, 1], 5, &MilpOptions::new())
+            .unwrap()
+        {
+            SimplexResult::Optimal(solution) => solution,
+            result => panic!("{:?}", result),
+        };
+
+    // The bound gets closer to the integer optimum
+    let objective = solution.get_objective_value();
+    assert!(objective < 41.25 - 1e-6);
+    assert!(objective >= 40. - 1e-9);
+    // The cuts are rows of the slack form
+    assert!(slack_form.get_B().len() > 2);
+}
+
+#[test]
+fn algo_gomory_gomory_rounds_not_optimal() {
+    let mut slack_form = integer_lp().into_slack_form().unwrap();
+
+    assert_eq!(
+        gomory_rounds(&mut slack_form, &[0, 1], 1, &MilpOptions::new())
+            .unwrap_err(),
+        SolverError::InvalidState(
+            "The current basis is not optimal, the Gomory cuts cannot be \
+             derived."
+                .into()
+        )
+    );
+}
+
+#[test]
+fn algo_gomory_branch_and_bound_with_cuts() {
+    let options = MilpOptions::new().set_cut_rounds(2);
+
+    let with_cuts = match branch_and_bound(&integer_lp(), &options).unwrap() {
+        MilpResult::Optimal(solution) => solution,
+        result => panic!("{:?}", result),
+    };
+    let without_cuts =
+        match branch_and_bound(&integer_lp(), &MilpOptions::new()).unwrap() {
+            MilpResult::Optimal(solution) => solution,
+            result => panic!("{:?}", result),
+        };
+
+    assert!((with_cuts.get_objective_value() - 40.).abs() < 1e-9);
+    assert_eq!(with_cuts.get_gap(), 0.);
+    // The cuts tighten the bounds of the nodes
+    assert!(with_cuts.get_nodes() < without_cuts.get_nodes());
+}
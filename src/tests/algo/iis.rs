@@ -0,0 +1,101 @@
+use super::super::super::algo::iis::find_iis;
+use super::super::super::forms::builder::StandardFormBuilder;
+use super::super::super::forms::standard::StandardFormLP;
+use super::super::super::types::error::SolverError;
+
+#[test]
+fn algo_iis_find_iis_two_rows() {
+    // x_1 + x_2 <= 4 and x_1 + x_2 >= 5, the other rows are not involved
+    let standard_form = StandardFormBuilder::new()
+        .add_max_objective(vec![1., 1.])
+        .unwrap()
+        .add_less_than_or_equal_constraint(vec![1., 0.], 10.)
+        .unwrap()
+        .add_less_than_or_equal_constraint(vec![1., 1.], 4.)
+        .unwrap()
+        .add_less_than_or_equal_constraint(vec![0., 1.], 3.)
+        .unwrap()
+        .add_greater_than_or_equal_constraint(vec![1., 1.], 5.)
+        .unwrap()
+        .build()
+        .unwrap();
+
+    assert_eq!(find_iis(&standard_form).unwrap(), vec![1, 3]);
+}
+
+#[test]
+fn algo_iis_find_iis_three_rows() {
+    // x_1 <= 1, x_2 <= 1 and x_1 + x_2 >= 3: no pair is unfeasible
+    let standard_form = StandardFormBuilder::new()
+        .add_max_objective(vec![1., 1.])
+        .unwrap()
+        .add_less_than_or_equal_constraint(vec![1., 0.], 1.)
+        .unwrap()
+        .add_less_than_or_equal_constraint(vec![1., -1.], 5.)
+        .unwrap()
+        .add_less_than_or_equal_constraint(vec![0., 1.], 1.)
+        .unwrap()
+        .add_greater_than_or_equal_constraint(vec![1., 1.], 3.)
+        .unwrap()
+        .build()
+        .unwrap();
+
+    assert_eq!(find_iis(&standard_form).unwrap(), vec![0, 2, 3]);
+}
+
+#[test]
+fn algo_iis_find_iis_equality_constraints() {
+    // x_1 + x_2 = 4 and x_1 + x_2 = 5
+    let standard_form = StandardFormBuilder::new()
+        .add_max_objective(vec![1., 1.])
+        .unwrap()
+        .add_less_than_or_equal_constraint(vec![1., 0.], 3.)
+        .unwrap()
+        .add_equality_constraint(vec![1., 1.], 4.)
+        .unwrap()
+        .add_equality_constraint(vec![1., 1.], 5.)
+        .unwrap()
+        .build()
+        .unwrap();
+
+    assert_eq!(find_iis(&standard_form).unwrap(), vec![1, 2]);
+}
+
+#[test]
+fn algo_iis_find_iis_with_variable_bounds() {
+    // x_1 + x_2 >= 10 with x_1 <= 4 and x_2 <= 3: the row is unfeasible on
+    // its own
+    let standard_form = StandardFormBuilder::new()
+        .add_max_objective(vec![1., 1.])
+        .unwrap()
+        .add_variable_bounds(0, 0., 4.)
+        .unwrap()
+        .add_variable_bounds(1, 0., 3.)
+        .unwrap()
+        .add_less_than_or_equal_constraint(vec![1., -1.], 2.)
+        .unwrap()
+        .add_greater_than_or_equal_constraint(vec![1., 1.], 10.)
+        .unwrap()
+        .build()
+        .unwrap();
+
+    assert_eq!(find_iis(&standard_form).unwrap(), vec![1]);
+}
+
+#[test]
+fn algo_iis_find_iis_feasible() {
+    let c = vec![1., 1.];
+    let a = vec![vec![4., -1.], vec![2., 1.], vec![-5., 2.]];
+    let b = vec![8., 10., 2.];
+
+    let standard_form = StandardFormLP::new(c, a, b, None).unwrap();
+
+    assert_eq!(
+        find_iis(&standard_form).unwrap_err(),
+        SolverError::Unsolvable(
+            "The linear program is feasible, there is no irreducible \
+             infeasible subsystem."
+                .into()
+        )
+    );
+}
@@ -0,0 +1,373 @@
+use std::time::Duration;
+
+use super::super::super::algo::interior_point::interior_point_mehrotra;
+use super::super::super::forms::builder::StandardFormBuilder;
+use super::super::super::forms::options::{CancelFlag, SolverOptions};
+use super::super::super::forms::slack::{Interruption, SimplexResult};
+use super::super::super::forms::standard::StandardFormLP;
+use super::super::helpers::{
+    assert_farkas, assert_optimal_within, assert_ray, INTERIOR_TOLERANCE,
+};
+
+#[test]
+fn algo_interior_point_interior_point_mehrotra_feasible_case_1() {
+    let c = vec![1., 1.];
+    let a = vec![vec![4., -1.], vec![2., 1.], vec![-5., 2.]];
+    let b = vec![8., 10., 2.];
+
+    let standard_form = StandardFormLP::new(c, a, b, None).unwrap();
+
+    let result = interior_point_mehrotra(&standard_form).unwrap();
+
+    assert_optimal_within(result.get_result(), &[2., 6.], INTERIOR_TOLERANCE);
+    assert!(result.get_duality_gap() < 1e-6);
+    if let SimplexResult::Optimal(solution) = result.get_result() {
+        assert!((solution.get_objective_value() - 8.).abs() < 1e-6);
+        let expected_duals = [0., 7. / 9., 1. / 9.];
+        for (y, e) in solution.get_duals().iter().zip(expected_duals.iter()) {
+            assert!((y - e).abs() < 1e-6);
+        }
+        assert!(solution.get_reduced_costs().iter().all(|d| d.abs() < 1e-6));
+    }
+}
+
+#[test]
+fn algo_interior_point_interior_point_mehrotra_feasible_case_2() {
+    let c = vec![3., 1., 2.];
+    let a = vec![vec![1., 1., 3.], vec![2., 2., 5.], vec![4., 1., 2.]];
+    let b = vec![30., 24., 36.];
+
+    let standard_form = StandardFormLP::new(c, a, b, None).unwrap();
+
+    let result = interior_point_mehrotra(&standard_form).unwrap();
+
+    assert_optimal_within(
+        result.get_result(),
+        &[8., 4., 0.],
+        INTERIOR_TOLERANCE,
+    );
+    assert!(result.get_duality_gap() < 1e-6);
+}
+
+#[test]
+fn algo_interior_point_interior_point_mehrotra_non_basic_feasible_case_2() {
+    let c = vec![1., 3.];
+    let a = vec![vec![1., -1.], vec![-1., -1.], vec![-1., 4.]];
+    let b = vec![8., -3., 2.];
+
+    let standard_form = StandardFormLP::new(c, a, b, None).unwrap();
+
+    let result = interior_point_mehrotra(&standard_form).unwrap();
+
+    assert_optimal_within(
+        result.get_result(),
+        &[34. / 3., 10. / 3.],
+        INTERIOR_TOLERANCE,
+    );
+}
+
+#[test]
+fn algo_interior_point_interior_point_mehrotra_optimal_face() {
+    // Every point of the segment between (0, 4) and (4, 0) is optimal, the
+    // interior point method converges to the middle of the optimal face
+    let c = vec![1., 1.];
+    let a = vec![vec![1., 1.]];
+    let b = vec![4.];
+
+    let standard_form = StandardFormLP::new(c, a, b, None).unwrap();
+
+    let result = interior_point_mehrotra(&standard_form).unwrap();
+
+    assert_optimal_within(result.get_result(), &[2., 2.], INTERIOR_TOLERANCE);
+}
+
+#[test]
+fn algo_interior_point_interior_point_mehrotra_degenerate() {
+    // Many constraints are active at the optimal vertex (1, 1)
+    let c = vec![1., 1.];
+    let a = vec![
+        vec![1., 0.],
+        vec![0., 1.],
+        vec![1., 1.],
+        vec![2., 1.],
+        vec![1., 2.],
+        vec![3., 1.],
+    ];
+    let b = vec![1., 1., 2., 3., 3., 4.];
+
+    let standard_form = StandardFormLP::new(c, a, b, None).unwrap();
+
+    let result = interior_point_mehrotra(&standard_form).unwrap();
+
+    assert_optimal_within(result.get_result(), &[1., 1.], INTERIOR_TOLERANCE);
+}
+
+#[test]
+fn algo_interior_point_interior_point_mehrotra_unbounded_case_1() {
+    let c = vec![1., -1.];
+    let a = vec![vec![-2., 1.], vec![-1., -2.]];
+    let b = vec![-1., -2.];
+
+    let standard_form =
+        StandardFormLP::new(c.clone(), a.clone(), b.clone(), None).unwrap();
+
+    let result = interior_point_mehrotra(&standard_form).unwrap();
+
+    assert_ray(result.get_result(), &c, &a, &b);
+}
+
+#[test]
+fn algo_interior_point_interior_point_mehrotra_unbounded_case_2() {
+    let c = vec![1., 3.];
+    let a = vec![vec![-1., 1.], vec![-1., -1.], vec![-1., 4.]];
+    let b = vec![-1., -3., 2.];
+
+    let standard_form =
+        StandardFormLP::new(c.clone(), a.clone(), b.clone(), None).unwrap();
+
+    let result = interior_point_mehrotra(&standard_form).unwrap();
+
+    assert_ray(result.get_result(), &c, &a, &b);
+}
+
+#[test]
+fn algo_interior_point_interior_point_mehrotra_unfeasible_case_1() {
+    let c = vec![3., -2.];
+    let a = vec![vec![1., 1.], vec![-2., -2.]];
+    let b = vec![-2., -10.];
+
+    let standard_form =
+        StandardFormLP::new(c, a.clone(), b.clone(), None).unwrap();
+
+    let result = interior_point_mehrotra(&standard_form).unwrap();
+
+    assert_farkas(result.get_result(), &a, &b);
+}
+
+#[test]
+fn algo_interior_point_interior_point_mehrotra_unfeasible_case_2() {
+    let c = vec![1., -2.];
+    let a = vec![vec![1., 2.], vec![-2., -6.], vec![0., 1.]];
+    let b = vec![4., -12., 1.];
+
+    let standard_form =
+        StandardFormLP::new(c, a.clone(), b.clone(), None).unwrap();
+
+    let result = interior_point_mehrotra(&standard_form).unwrap();
+
+    assert_farkas(result.get_result(), &a, &b);
+}
+
+#[test]
+fn algo_interior_point_interior_point_mehrotra_variable_bounds() {
+    let c = vec![1., 1.];
+    let a = vec![vec![1., 2.]];
+    let b = vec![10.];
+
+    let standard_form = StandardFormLP::new(c, a, b, None)
+        .unwrap()
+        .add_variable_bounds(0, 0., 3.)
+        .unwrap();
+
+    let result = interior_point_mehrotra(&standard_form).unwrap();
+
+    assert_optimal_within(result.get_result(), &[3., 3.5], INTERIOR_TOLERANCE);
+}
+
+#[test]
+fn algo_interior_point_interior_point_mehrotra_free_variable() {
+    let c = vec![1., 2.];
+    let a = vec![vec![-1., 1.], vec![1., 1.]];
+    let b = vec![4., 2.];
+
+    let standard_form = StandardFormLP::new(c, a, b, Some(vec![0])).unwrap();
+
+    let result = interior_point_mehrotra(&standard_form).unwrap();
+
+    assert_optimal_within(result.get_result(), &[-1., 3.], INTERIOR_TOLERANCE);
+}
+
+#[test]
+fn algo_interior_point_interior_point_mehrotra_row_ranges() {
+    // x_1 + x_2 + x_3 = 4 and x_1 - x_2 + 2 . x_3 = 1
+    let c = vec![1., 2., 1.];
+    let a = vec![vec![1., 1., 1.], vec![1., -1., 2.], vec![0., 1., 3.]];
+    let b = vec![4., 1., 6.];
+
+    let standard_form = StandardFormLP::new(c, a, b, None)
+        .unwrap()
+        .add_row_range(0, 0.)
+        .unwrap()
+        .add_row_range(1, 0.)
+        .unwrap();
+
+    let result = interior_point_mehrotra(&standard_form).unwrap();
+
+    assert_optimal_within(
+        result.get_result(),
+        &[4. / 7., 15. / 7., 9. / 7.],
+        INTERIOR_TOLERANCE,
+    );
+}
+
+#[test]
+fn algo_interior_point_interior_point_mehrotra_min_objective() {
+    // min x_1 + 2 . x_2, with x_1 + x_2 >= 4
+    let standard_form = StandardFormBuilder::new()
+        .add_min_objective(vec![1., 2.])
+        .unwrap()
+        .add_greater_than_or_equal_constraint(vec![1., 1.], 4.)
+        .unwrap()
+        .build()
+        .unwrap();
+
+    let result = interior_point_mehrotra(&standard_form).unwrap();
+
+    // Same objective value, duals and reduced costs as the simplex
+    assert_optimal_within(result.get_result(), &[4., 0.], INTERIOR_TOLERANCE);
+    if let SimplexResult::Optimal(solution) = result.get_result() {
+        assert!((solution.get_objective_value() - 4.).abs() < 1e-6);
+        assert!((solution.get_duals()[0] + 1.).abs() < 1e-6);
+        let reduced_costs = solution.get_reduced_costs();
+        assert!(reduced_costs[0].abs() < 1e-6);
+        assert!((reduced_costs[1] - 1.).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn algo_interior_point_interior_point_mehrotra_unbounded_from_feasible_point() {
+    // Feasible at x = 0: the iterate also gives a Farkas like vector of the
+    // wrong sign, which must not be taken for a certificate
+    let c = vec![2., -1.];
+    let a = vec![vec![0., -3.], vec![-2., 2.], vec![0., -4.], vec![-3., 1.]];
+    let b = vec![6., 4., 6., 3.];
+
+    let standard_form =
+        StandardFormLP::new(c.clone(), a.clone(), b.clone(), None).unwrap();
+
+    let result = interior_point_mehrotra(&standard_form).unwrap();
+
+    assert_ray(result.get_result(), &c, &a, &b);
+    if let SimplexResult::Unbounded(point, _) = result.get_result() {
+        assert!(!point.is_empty());
+        assert!(point.iter().all(|x| *x >= -1e-9));
+    }
+}
+
+#[test]
+fn algo_interior_point_interior_point_mehrotra_unfeasible_with_ray() {
+    // 4 . x_1 <= -2 is unfeasible, while x_2 is a ray of the objective
+    let c = vec![-3., 1.];
+    let a = vec![vec![4., 0.], vec![4., 0.]];
+    let b = vec![8., -2.];
+
+    let standard_form =
+        StandardFormLP::new(c, a.clone(), b.clone(), None).unwrap();
+
+    let result = interior_point_mehrotra(&standard_form).unwrap();
+
+    assert_farkas(result.get_result(), &a, &b);
+}
+
+#[test]
+fn algo_interior_point_interior_point_mehrotra_iteration_limit() {
+    let c = vec![3., 1., 2.];
+    let a = vec![vec![1., 1., 3.], vec![2., 2., 5.], vec![4., 1., 2.]];
+    let b = vec![30., 24., 36.];
+    let standard_form = StandardFormLP::new(c, a, b, None).unwrap();
+
+    let result = interior_point_mehrotra(
+        &standard_form
+            .clone()
+            .set_options(SolverOptions::new().set_iteration_limit(3)),
+    )
+    .unwrap();
+
+    assert_eq!(result.get_iterations(), 3);
+    match result.get_result() {
+        SimplexResult::Interrupted(Interruption::IterationLimit, point) => {
+            assert_eq!(point.len(), 3);
+        },
+        result => panic!("The solve should be interrupted, got {:?}", result),
+    }
+
+    // Without limit, the same problem is solved
+    let result = interior_point_mehrotra(&standard_form).unwrap();
+
+    assert_optimal_within(
+        result.get_result(),
+        &[8., 4., 0.],
+        INTERIOR_TOLERANCE,
+    );
+}
+
+#[test]
+fn algo_interior_point_interior_point_mehrotra_iteration_limit_unbounded() {
+    let c = vec![1., -1.];
+    let a = vec![vec![-2., 1.], vec![-1., -2.]];
+    let b = vec![-1., -2.];
+    let standard_form =
+        StandardFormLP::new(c.clone(), a.clone(), b.clone(), None).unwrap();
+
+    // The iterations of the feasibility solve count as well
+    let result = interior_point_mehrotra(&standard_form).unwrap();
+    let iterations = result.get_iterations();
+
+    assert_ray(result.get_result(), &c, &a, &b);
+
+    let result = interior_point_mehrotra(
+        &standard_form
+            .clone()
+            .set_options(SolverOptions::new().set_iteration_limit(iterations)),
+    )
+    .unwrap();
+
+    assert_eq!(result.get_iterations(), iterations);
+    assert_ray(result.get_result(), &c, &a, &b);
+
+    let result =
+        interior_point_mehrotra(&standard_form.set_options(
+            SolverOptions::new().set_iteration_limit(iterations - 1),
+        ))
+        .unwrap();
+
+    assert_eq!(result.get_iterations(), iterations - 1);
+    match result.get_result() {
+        SimplexResult::Interrupted(Interruption::IterationLimit, point) => {
+            assert_eq!(point.len(), 2);
+        },
+        result => panic!("The solve should be interrupted, got {:?}", result),
+    }
+}
+
+#[test]
+fn algo_interior_point_interior_point_mehrotra_time_limit_and_cancel_flag() {
+    let c = vec![1., 1.];
+    let a = vec![vec![4., -1.], vec![2., 1.], vec![-5., 2.]];
+    let b = vec![8., 10., 2.];
+    let standard_form = StandardFormLP::new(c, a, b, None).unwrap();
+
+    let result =
+        interior_point_mehrotra(&standard_form.clone().set_options(
+            SolverOptions::new().set_time_limit(Duration::new(0, 0)),
+        ))
+        .unwrap();
+
+    // The initial iterate: every variable at 1
+    assert_eq!(result.get_iterations(), 0);
+    assert_eq!(
+        result.get_result(),
+        &SimplexResult::Interrupted(Interruption::TimeLimit, vec![1., 1.])
+    );
+
+    let flag = CancelFlag::new();
+    let standard_form = standard_form
+        .set_options(SolverOptions::new().set_cancel_flag(flag.clone()));
+    flag.cancel();
+    let result = interior_point_mehrotra(&standard_form).unwrap();
+
+    assert_eq!(
+        result.get_result(),
+        &SimplexResult::Interrupted(Interruption::Cancelled, vec![1., 1.])
+    );
+}
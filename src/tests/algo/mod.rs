@@ -1 +1,10 @@
+mod branch_and_bound;
+mod branching;
+mod crossover;
+mod gomory;
+mod iis;
+mod interior_point;
+mod pricing;
+mod relaxation;
+mod revised_simplex;
 mod simplex;
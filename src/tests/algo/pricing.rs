@@ -0,0 +1,178 @@
+use super::super::super::algo::pricing::{
+    Bland, Dantzig, Devex, LargestImprovement, PricingRule, SteepestEdge,
+};
+use super::super::super::algo::simplex::simplex_lp_chvatal;
+use super::super::super::forms::builder::StandardFormBuilder;
+use super::super::super::forms::options::{Pricing, SolverOptions};
+use super::super::super::forms::slack::{SimplexResult, SlackFormLP};
+use super::super::super::forms::standard::StandardFormLP;
+use super::super::helpers::assert_optimal_value;
+
+const PRICINGS: [Pricing; 5] = [
+    Pricing::Bland,
+    Pricing::Dantzig,
+    Pricing::LargestImprovement,
+    Pricing::SteepestEdge,
+    Pricing::Devex,
+];
+
+fn solve(standard_form: &StandardFormLP, pricing: Pricing) -> SimplexResult {
+    let options = SolverOptions::new().set_pricing(pricing);
+    let mut slack_form =
+        standard_form.clone().set_options(options).into_slack_form().unwrap();
+
+    simplex_lp_chvatal(&mut slack_form).unwrap()
+}
+
+#[test]
+fn algo_pricing_pricing_default() {
+    assert_eq!(Pricing::default(), Pricing::Bland);
+    assert_eq!(SolverOptions::new().get_pricing(), Pricing::Bland);
+}
+
+#[test]
+fn algo_pricing_pricing_feasible() {
+    let c = vec![3., 1., 2.];
+    let a = vec![vec![1., 1., 3.], vec![2., 2., 5.], vec![4., 1., 2.]];
+    let b = vec![30., 24., 36.];
+    let standard_form = StandardFormLP::new(c, a, b, None).unwrap();
+
+    for pricing in PRICINGS.iter() {
+        assert_optimal_value(
+            &solve(&standard_form, *pricing),
+            &[8., 4., 0.],
+            28.,
+        );
+    }
+}
+
+#[test]
+fn algo_pricing_pricing_non_basic_feasible() {
+    let c = vec![1., 1.];
+    let a = vec![vec![4., -1.], vec![2., 1.], vec![-5., 2.], vec![-1., -1.]];
+    let b = vec![8., 10., 2., -1.];
+    let standard_form = StandardFormLP::new(c, a, b, None).unwrap();
+
+    for pricing in PRICINGS.iter() {
+        assert_optimal_value(&solve(&standard_form, *pricing), &[2., 6.], 8.);
+    }
+}
+
+#[test]
+fn algo_pricing_pricing_bounds_and_free_variables() {
+    // max x_1 + 2 . x_2 with x_1 + x_2 <= 4, x_1 - x_2 >= -6, 0 <= x_2 <= 3
+    // and x_1 free
+    let standard_form = StandardFormBuilder::new()
+        .add_max_objective(vec![1., 2.])
+        .unwrap()
+        .add_non_negative_indices(vec![0])
+        .unwrap()
+        .add_variable_bounds(1, 0., 3.)
+        .unwrap()
+        .add_less_than_or_equal_constraint(vec![1., 1.], 4.)
+        .unwrap()
+        .add_greater_than_or_equal_constraint(vec![1., -1.], -6.)
+        .unwrap()
+        .build()
+        .unwrap();
+
+    for pricing in PRICINGS.iter() {
+        assert_optimal_value(&solve(&standard_form, *pricing), &[1., 3.], 7.);
+    }
+}
+
+#[test]
+fn algo_pricing_pricing_degenerate_cycling_example() {
+    // Cycles with Dantzig's rule when the ties of the ratio test are broken
+    // with the first row (Chvatal, Linear Programming, 1983)
+    let c = vec![10., -57., -9., -24.];
+    let a = vec![
+        vec![0.5, -5.5, -2.5, 9.],
+        vec![0.5, -1.5, -0.5, 1.],
+        vec![1., 0., 0., 0.],
+    ];
+    let b = vec![0., 0., 1.];
+    let standard_form = StandardFormLP::new(c, a, b, None).unwrap();
+
+    for pricing in PRICINGS.iter() {
+        assert_optimal_value(
+            &solve(&standard_form, *pricing),
+            &[1., 0., 1., 0.],
+            1.,
+        );
+    }
+}
+
+#[test]
+fn algo_pricing_pricing_unbounded_and_unfeasible() {
+    let c = vec![1., 3.];
+    let a = vec![vec![-1., 1.], vec![-1., -1.], vec![-1., 4.]];
+    let b = vec![-1., -3., 2.];
+    let unbounded = StandardFormLP::new(c, a, b, None).unwrap();
+
+    let c = vec![1., -2.];
+    let a = vec![vec![1., 2.], vec![-2., -6.], vec![0., 1.]];
+    let b = vec![4., -12., 1.];
+    let unfeasible = StandardFormLP::new(c, a, b, None).unwrap();
+
+    for pricing in PRICINGS.iter() {
+        assert!(matches!(
+            solve(&unbounded, *pricing),
+            SimplexResult::Unbounded(..)
+        ));
+        assert!(matches!(
+            solve(&unfeasible, *pricing),
+            SimplexResult::Unfeasible(_)
+        ));
+    }
+}
+
+#[test]
+fn algo_pricing_rules_select() {
+    // z = x_0 + 3 . x_1 + 2 . x_2
+    // x_3 = 6 - x_0 - 2 . x_1 - x_2
+    // x_4 = 9 - 5 . x_1 - x_2
+    let slack_form = SlackFormLP::new(
+        vec![vec![-1., -2., -1.], vec![0., -5., -1.]],
+        vec![6., 9.],
+        vec![1., 3., 2.],
+    )
+    .unwrap();
+    // Steps: x_0 up to 6, x_1 up to 1.8, x_2 up to 6
+    let candidates = vec![(0, 6.), (1, 1.8), (2, 6.)];
+
+    assert_eq!(Bland.select(&slack_form, &candidates), 0);
+    assert_eq!(Dantzig.select(&slack_form, &candidates), 1);
+    // Improvements: 6, 5.4, 12
+    assert_eq!(LargestImprovement.select(&slack_form, &candidates), 2);
+    // Scores: 1 / 2, 9 / 30, 4 / 3
+    assert_eq!(SteepestEdge.select(&slack_form, &candidates), 2);
+    // With unit weights, same choice as Dantzig's rule
+    assert_eq!(Devex::default().select(&slack_form, &candidates), 1);
+}
+
+#[test]
+fn algo_pricing_rules_custom_rule() {
+    // Always the last candidate
+    struct Last;
+
+    impl PricingRule for Last {
+        fn select(
+            &mut self,
+            _slack_lp: &SlackFormLP,
+            candidates: &[(usize, f64)],
+        ) -> usize {
+            candidates.len() - 1
+        }
+    }
+
+    let c = vec![3., 1., 2.];
+    let a = vec![vec![1., 1., 3.], vec![2., 2., 5.], vec![4., 1., 2.]];
+    let b = vec![30., 24., 36.];
+    let mut slack_form =
+        StandardFormLP::new(c, a, b, None).unwrap().into_slack_form().unwrap();
+
+    let result = slack_form.find_optimal_with(&mut Last).unwrap();
+
+    assert_optimal_value(&result, &[8., 4., 0.], 28.);
+}
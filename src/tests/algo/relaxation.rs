@@ -0,0 +1,127 @@
+use super::super::super::algo::relaxation::feasibility_relaxation;
+use super::super::super::forms::builder::StandardFormBuilder;
+use super::super::super::forms::standard::StandardFormLP;
+use super::super::super::types::error::SolverError;
+use super::super::helpers::assert_close;
+
+#[test]
+fn algo_relaxation_feasibility_relaxation_cheapest_row() {
+    // x_1 + x_2 <= 4 and x_1 + x_2 >= 5, violating the first row is cheaper
+    let standard_form = StandardFormBuilder::new()
+        .add_max_objective(vec![1., 1.])
+        .unwrap()
+        .add_less_than_or_equal_constraint(vec![1., 1.], 4.)
+        .unwrap()
+        .add_greater_than_or_equal_constraint(vec![1., 1.], 5.)
+        .unwrap()
+        .build()
+        .unwrap();
+
+    let relaxation = feasibility_relaxation(&standard_form, &[1., 3.]).unwrap();
+
+    assert_close(relaxation.get_violations(), &[1., 0.]);
+    assert!((relaxation.get_penalty() - 1.).abs() < 1e-9);
+    let x = relaxation.get_x();
+    assert!((x[0] + x[1] - 5.).abs() < 1e-9);
+
+    let relaxation = feasibility_relaxation(&standard_form, &[3., 1.]).unwrap();
+
+    assert_close(relaxation.get_violations(), &[0., 1.]);
+    assert!((relaxation.get_penalty() - 1.).abs() < 1e-9);
+}
+
+#[test]
+fn algo_relaxation_feasibility_relaxation_range_row() {
+    // 5 <= x_1 + x_2 <= 6 with x_1 <= 1 and x_2 <= 2 (variable bounds)
+    let standard_form = StandardFormBuilder::new()
+        .add_max_objective(vec![1., 1.])
+        .unwrap()
+        .add_variable_bounds(0, 0., 1.)
+        .unwrap()
+        .add_variable_bounds(1, 0., 2.)
+        .unwrap()
+        .add_range_constraint(vec![1., 1.], 5., 6.)
+        .unwrap()
+        .build()
+        .unwrap();
+
+    let relaxation = feasibility_relaxation(&standard_form, &[2.]).unwrap();
+
+    // The row is violated below its lower bound
+    assert_close(relaxation.get_violations(), &[-2.]);
+    assert!((relaxation.get_penalty() - 4.).abs() < 1e-9);
+    assert_close(relaxation.get_x(), &[1., 2.]);
+}
+
+#[test]
+fn algo_relaxation_feasibility_relaxation_hard_constraint() {
+    // x_1 + x_2 = 4 can not be violated, x_1 + x_2 >= 5 is relaxed
+    let standard_form = StandardFormBuilder::new()
+        .add_max_objective(vec![1., 1.])
+        .unwrap()
+        .add_equality_constraint(vec![1., 1.], 4.)
+        .unwrap()
+        .add_greater_than_or_equal_constraint(vec![1., 1.], 5.)
+        .unwrap()
+        .build()
+        .unwrap();
+
+    let relaxation =
+        feasibility_relaxation(&standard_form, &[f64::INFINITY, 10.]).unwrap();
+
+    assert_close(relaxation.get_violations(), &[0., 1.]);
+    assert!((relaxation.get_penalty() - 10.).abs() < 1e-9);
+}
+
+#[test]
+fn algo_relaxation_feasibility_relaxation_hard_constraints_unfeasible() {
+    let c = vec![1., 1.];
+    let a = vec![vec![1., 1.], vec![-1., -1.]];
+    let b = vec![1., -2.];
+
+    let standard_form = StandardFormLP::new(c, a, b, None).unwrap();
+
+    assert_eq!(
+        feasibility_relaxation(&standard_form, &[f64::INFINITY; 2])
+            .unwrap_err(),
+        SolverError::Unsolvable(
+            "The hard constraints are unfeasible, the linear program can not \
+             be relaxed."
+                .into()
+        )
+    );
+}
+
+#[test]
+fn algo_relaxation_feasibility_relaxation_feasible() {
+    let c = vec![1., 1.];
+    let a = vec![vec![4., -1.], vec![2., 1.], vec![-5., 2.]];
+    let b = vec![8., 10., 2.];
+
+    let standard_form = StandardFormLP::new(c, a, b, None).unwrap();
+    let relaxation =
+        feasibility_relaxation(&standard_form, &[1., 1., 1.]).unwrap();
+
+    assert_close(relaxation.get_violations(), &[0., 0., 0.]);
+    assert_eq!(relaxation.get_penalty(), 0.);
+}
+
+#[test]
+fn algo_relaxation_feasibility_relaxation_wrong_penalties() {
+    let c = vec![1., 1.];
+    let a = vec![vec![1., 1.], vec![-1., -1.]];
+    let b = vec![1., -2.];
+
+    let standard_form = StandardFormLP::new(c, a, b, None).unwrap();
+
+    assert_eq!(
+        feasibility_relaxation(&standard_form, &[1.]).unwrap_err(),
+        SolverError::DimensionMismatch("penalties".into(), 2, 1)
+    );
+    assert_eq!(
+        feasibility_relaxation(&standard_form, &[1., -1.]).unwrap_err(),
+        SolverError::InvalidInput(
+            "Penalties should be non negative numbers.".into()
+        )
+    );
+}
@@ -0,0 +1,445 @@
+use std::time::Duration;
+
+use super::super::super::algo::revised_simplex::{
+    simplex_lp_revised, simplex_lp_revised_in_place,
+};
+use super::super::super::algo::simplex::simplex_lp_chvatal;
+use super::super::super::forms::options::{CancelFlag, Pricing, SolverOptions};
+use super::super::super::forms::slack::{Interruption, SimplexResult};
+use super::super::super::forms::standard::StandardFormLP;
+use super::super::helpers::{assert_farkas, assert_optimal, assert_ray};
+
+#[test]
+fn algo_revised_simplex_simplex_lp_revised_feasible_case_1() {
+    let c = vec![1., 1.];
+    let a = vec![vec![4., -1.], vec![2., 1.], vec![-5., 2.]];
+    let b = vec![8., 10., 2.];
+
+    let standard_form = StandardFormLP::new(c, a, b, None).unwrap();
+    let mut slack_form = standard_form.into_slack_form().unwrap();
+
+    let simplex_result = simplex_lp_revised_in_place(&mut slack_form).unwrap();
+
+    assert_optimal(&simplex_result, &[2., 6.]);
+    assert!((slack_form.get_v() - 8.).abs() < 1e-9);
+}
+
+#[test]
+fn algo_revised_simplex_simplex_lp_revised_feasible_case_2() {
+    let c = vec![3., 1., 2.];
+    let a = vec![vec![1., 1., 3.], vec![2., 2., 5.], vec![4., 1., 2.]];
+    let b = vec![30., 24., 36.];
+
+    let standard_form = StandardFormLP::new(c, a, b, None).unwrap();
+    let mut slack_form = standard_form.into_slack_form().unwrap();
+
+    let simplex_result = simplex_lp_revised_in_place(&mut slack_form).unwrap();
+
+    assert_optimal(&simplex_result, &[8., 4., 0.]);
+    assert!((slack_form.get_v() - 28.).abs() < 1e-9);
+}
+
+#[test]
+fn algo_revised_simplex_simplex_lp_revised_unbounded_case_1() {
+    let c = vec![1., -1.];
+    let a = vec![vec![-2., 1.], vec![-1., -2.]];
+    let b = vec![-1., -2.];
+
+    let standard_form =
+        StandardFormLP::new(c.clone(), a.clone(), b.clone(), None).unwrap();
+    let slack_form = standard_form.into_slack_form().unwrap();
+
+    let simplex_result = simplex_lp_revised(&slack_form).unwrap();
+
+    assert_ray(&simplex_result, &c, &a, &b);
+}
+
+#[test]
+fn algo_revised_simplex_simplex_lp_revised_unbounded_case_2() {
+    let c = vec![1., 3.];
+    let a = vec![vec![-1., 1.], vec![-1., -1.], vec![-1., 4.]];
+    let b = vec![-1., -3., 2.];
+
+    let standard_form =
+        StandardFormLP::new(c.clone(), a.clone(), b.clone(), None).unwrap();
+    let slack_form = standard_form.into_slack_form().unwrap();
+
+    let simplex_result = simplex_lp_revised(&slack_form).unwrap();
+
+    assert_ray(&simplex_result, &c, &a, &b);
+}
+
+#[test]
+fn algo_revised_simplex_simplex_lp_revised_unfeasible_case_1() {
+    let c = vec![3., -2.];
+    let a = vec![vec![1., 1.], vec![-2., -2.]];
+    let b = vec![-2., -10.];
+
+    let standard_form =
+        StandardFormLP::new(c, a.clone(), b.clone(), None).unwrap();
+    let slack_form = standard_form.into_slack_form().unwrap();
+
+    let simplex_result = simplex_lp_revised(&slack_form).unwrap();
+
+    assert_farkas(&simplex_result, &a, &b);
+}
+
+#[test]
+fn algo_revised_simplex_simplex_lp_revised_unfeasible_case_2() {
+    let c = vec![1., -2.];
+    let a = vec![vec![1., 2.], vec![-2., -6.], vec![0., 1.]];
+    let b = vec![4., -12., 1.];
+
+    let standard_form =
+        StandardFormLP::new(c, a.clone(), b.clone(), None).unwrap();
+    let slack_form = standard_form.into_slack_form().unwrap();
+
+    let simplex_result = simplex_lp_revised(&slack_form).unwrap();
+
+    assert_farkas(&simplex_result, &a, &b);
+}
+
+#[test]
+fn algo_revised_simplex_simplex_lp_revised_non_basic_feasible_case_1() {
+    let c = vec![2., -1.];
+    let a = vec![vec![2., -1.], vec![1., -5.]];
+    let b = vec![2., -4.];
+
+    let standard_form = StandardFormLP::new(c, a, b, None).unwrap();
+    let slack_form = standard_form.into_slack_form().unwrap();
+
+    let simplex_result = simplex_lp_revised(&slack_form).unwrap();
+
+    assert_optimal(&simplex_result, &[1.5555555555555554, 1.1111111111111112]);
+}
+
+#[test]
+fn algo_revised_simplex_simplex_lp_revised_non_basic_feasible_case_2() {
+    let c = vec![1., 3.];
+    let a = vec![vec![1., -1.], vec![-1., -1.], vec![-1., 4.]];
+    let b = vec![8., -3., 2.];
+
+    let standard_form = StandardFormLP::new(c, a, b, None).unwrap();
+    let slack_form = standard_form.into_slack_form().unwrap();
+
+    let simplex_result = simplex_lp_revised(&slack_form).unwrap();
+
+    assert_optimal(&simplex_result, &[11.333333333333334, 3.3333333333333335]);
+}
+
+#[test]
+fn algo_revised_simplex_simplex_lp_revised_same_dictionary_as_chvatal() {
+    // Enough pivots to trigger several refactorizations of the basis: each
+    // variable has its own upper limit, plus a few coupling constraints.
+    let cols = 120;
+    let c: Vec<f64> = (0..cols).map(|j| 1. + ((j * 7) % 5) as f64).collect();
+    let mut a: Vec<Vec<f64>> = (0..cols)
+        .map(|i| (0..cols).map(|j| if i == j { 1. } else { 0. }).collect())
+        .collect();
+    let mut b: Vec<f64> = (0..cols).map(|i| 1. + (i % 3) as f64).collect();
+    for i in 0..5 {
+        a.push(
+            (0..cols).map(|j| 1. + ((i * 13 + j * 17) % 11) as f64).collect(),
+        );
+        b.push(800. + 10. * i as f64);
+    }
+
+    let standard_form = StandardFormLP::new(c, a, b, None).unwrap();
+    let mut slack_chvatal = standard_form.clone().into_slack_form().unwrap();
+    let mut slack_revised = standard_form.into_slack_form().unwrap();
+
+    let result_chvatal = simplex_lp_chvatal(&mut slack_chvatal).unwrap();
+    let result_revised =
+        simplex_lp_revised_in_place(&mut slack_revised).unwrap();
+
+    if let SimplexResult::Optimal(solution) = result_chvatal {
+        let x = solution.get_x();
+        assert_optimal(&result_revised, x);
+    } else {
+        panic!("Optimal solution expected, got {:?}", result_chvatal);
+    }
+    assert!((slack_chvatal.get_v() - slack_revised.get_v()).abs() < 1e-9);
+}
+
+#[test]
+fn algo_revised_simplex_simplex_lp_revised_same_solution_as_chvatal() {
+    let c = vec![3., 1., 2.];
+    let a = vec![vec![1., 1., 3.], vec![2., 2., 5.], vec![4., 1., 2.]];
+    let b = vec![30., 24., 36.];
+
+    let standard_form = StandardFormLP::new(c, a, b, None).unwrap();
+    let mut slack_chvatal = standard_form.clone().into_slack_form().unwrap();
+    let slack_revised = standard_form.into_slack_form().unwrap();
+
+    let result_chvatal = simplex_lp_chvatal(&mut slack_chvatal).unwrap();
+    let result_revised = simplex_lp_revised(&slack_revised).unwrap();
+
+    // Duals and reduced costs are computed from the factorized basis
+    match (result_chvatal, result_revised) {
+        (SimplexResult::Optimal(chvatal), SimplexResult::Optimal(revised)) => {
+            let pairs = [
+                (chvatal.get_x(), revised.get_x()),
+                (chvatal.get_slacks(), revised.get_slacks()),
+                (chvatal.get_duals(), revised.get_duals()),
+                (chvatal.get_reduced_costs(), revised.get_reduced_costs()),
+            ];
+            for (l, r) in pairs.iter() {
+                assert_eq!(l.len(), r.len());
+                for (l_i, r_i) in l.iter().zip(r.iter()) {
+                    assert!((l_i - r_i).abs() < 1e-9, "{:?} != {:?}", l, r);
+                }
+            }
+        },
+        results => panic!("Optimal solutions expected, got {:?}", results),
+    }
+
+    // The slack form is left untouched
+    assert_eq!(slack_revised.get_B(), &vec![3, 4, 5]);
+    assert_eq!(slack_revised.get_v(), 0.);
+}
+
+/// Solve the linear program with both simplex versions, they must reach the
+/// same outcome (and the same objective value when optimal)
+fn assert_same_as_chvatal(standard_form: StandardFormLP) -> SimplexResult {
+    let mut slack_chvatal = standard_form.clone().into_slack_form().unwrap();
+    let mut slack_revised = standard_form.into_slack_form().unwrap();
+
+    let result_chvatal = simplex_lp_chvatal(&mut slack_chvatal).unwrap();
+    let result_revised =
+        simplex_lp_revised_in_place(&mut slack_revised).unwrap();
+
+    match (&result_revised, &result_chvatal) {
+        (SimplexResult::Optimal(revised), SimplexResult::Optimal(chvatal)) => {
+            let objective = chvatal.get_objective_value();
+            assert!((revised.get_objective_value() - objective).abs() < 1e-9);
+            assert!((slack_revised.get_v() - objective).abs() < 1e-9);
+            assert!(slack_revised.is_primal_feasible());
+            assert!(slack_revised.is_dual_feasible());
+        },
+        (SimplexResult::Unfeasible(_), SimplexResult::Unfeasible(_))
+        | (SimplexResult::Unbounded(..), SimplexResult::Unbounded(..)) => {},
+        _ => panic!("{:?} expected, got {:?}", result_chvatal, result_revised),
+    }
+
+    result_revised
+}
+
+#[test]
+fn algo_revised_simplex_simplex_lp_revised_upper_bounds() {
+    let c = vec![1., 1.];
+    let a = vec![vec![1., 2.]];
+    let b = vec![10.];
+
+    let standard_form = StandardFormLP::new(c, a, b, None)
+        .unwrap()
+        .add_variable_bounds(0, 0., 3.)
+        .unwrap();
+
+    let result = assert_same_as_chvatal(standard_form);
+
+    assert_optimal(&result, &[3., 3.5]);
+}
+
+#[test]
+fn algo_revised_simplex_simplex_lp_revised_bounds_and_ranges() {
+    // max 2 . x_1 + 3 . x_2 - x_3, with x_1 + x_2 + x_3 = 4 (equality),
+    // 1 <= x_1 - x_2 <= 3, -1 <= x_2 <= 2 and 1 <= x_3 <= 5
+    let c = vec![2., 3., -1.];
+    let a = vec![vec![1., 1., 1.], vec![1., -1., 0.]];
+    let b = vec![4., 3.];
+
+    let standard_form = StandardFormLP::new(c, a, b, None)
+        .unwrap()
+        .add_row_range(0, 0.)
+        .unwrap()
+        .add_row_range(1, 2.)
+        .unwrap()
+        .add_variable_bounds(1, -1., 2.)
+        .unwrap()
+        .add_variable_bounds(2, 1., 5.)
+        .unwrap();
+
+    let result = assert_same_as_chvatal(standard_form);
+
+    assert_optimal(&result, &[2., 1., 1.]);
+}
+
+#[test]
+fn algo_revised_simplex_simplex_lp_revised_free_variable() {
+    let c = vec![1., 2.];
+    let a = vec![vec![-1., 1.], vec![1., 1.]];
+    let b = vec![4., 2.];
+
+    let standard_form = StandardFormLP::new(c, a, b, Some(vec![0])).unwrap();
+
+    let result = assert_same_as_chvatal(standard_form);
+
+    assert_optimal(&result, &[-1., 3.]);
+}
+
+#[test]
+fn algo_revised_simplex_simplex_lp_revised_free_variable_unbounded() {
+    // x_1 is free, the objective improves when it decreases
+    let c = vec![-1., 1.];
+    let a = vec![vec![1., 1.], vec![0., 1.]];
+    let b = vec![5., 2.];
+
+    let standard_form =
+        StandardFormLP::new(c.clone(), a.clone(), b.clone(), None)
+            .unwrap()
+            .add_variable_bounds(0, f64::NEG_INFINITY, f64::INFINITY)
+            .unwrap();
+    let slack_form = standard_form.into_slack_form().unwrap();
+
+    let simplex_result = simplex_lp_revised(&slack_form).unwrap();
+
+    assert_ray(&simplex_result, &c, &a, &b);
+}
+
+#[test]
+fn algo_revised_simplex_simplex_lp_revised_bounds_unfeasible() {
+    // x_1 + x_2 >= 5 with x_1 <= 2 and x_2 <= 2
+    let c = vec![1., 1.];
+    let a = vec![vec![-1., -1.]];
+    let b = vec![-5.];
+
+    let standard_form = StandardFormLP::new(c, a, b, None)
+        .unwrap()
+        .add_variable_bounds(0, 0., 2.)
+        .unwrap()
+        .add_variable_bounds(1, 0., 2.)
+        .unwrap();
+
+    let result = assert_same_as_chvatal(standard_form);
+
+    // x_1 + x_2 <= 4 < 5
+    assert_eq!(result, SimplexResult::Unfeasible(vec![1.]));
+}
+
+#[test]
+fn algo_revised_simplex_simplex_lp_revised_pricings() {
+    // Degenerate linear program which cycles with Dantzig's rule when the
+    // ties of the ratio test are broken with the first row
+    let c = vec![10., -57., -9., -24.];
+    let a = vec![
+        vec![0.5, -5.5, -2.5, 9.],
+        vec![0.5, -1.5, -0.5, 1.],
+        vec![1., 0., 0., 0.],
+    ];
+    let b = vec![0., 0., 1.];
+
+    for pricing in [
+        Pricing::Bland,
+        Pricing::Dantzig,
+        Pricing::LargestImprovement,
+        Pricing::SteepestEdge,
+        Pricing::Devex,
+    ]
+    .iter()
+    {
+        let options = SolverOptions::new().set_pricing(*pricing);
+        let standard_form =
+            StandardFormLP::new(c.clone(), a.clone(), b.clone(), None)
+                .unwrap()
+                .set_options(options);
+
+        let result = assert_same_as_chvatal(standard_form);
+
+        assert_optimal(&result, &[1., 0., 1., 0.]);
+    }
+}
+
+#[test]
+fn algo_revised_simplex_simplex_lp_revised_iteration_limit() {
+    let c = vec![3., 1., 2.];
+    let a = vec![vec![1., 1., 3.], vec![2., 2., 5.], vec![4., 1., 2.]];
+    let b = vec![30., 24., 36.];
+    let standard_form = StandardFormLP::new(c, a, b, None).unwrap();
+    let mut slack_form = standard_form
+        .set_options(
+            SolverOptions::new()
+                .set_pricing(Pricing::Bland)
+                .set_iteration_limit(1),
+        )
+        .into_slack_form()
+        .unwrap();
+
+    let simplex_result = simplex_lp_revised_in_place(&mut slack_form).unwrap();
+
+    // Bland's rule: x_1 enters first, up to 9.0
+    assert_eq!(
+        simplex_result,
+        SimplexResult::Interrupted(
+            Interruption::IterationLimit,
+            vec![9., 0., 0.]
+        )
+    );
+
+    // The current basis is written back, the solve goes on from it
+    slack_form.set_options(SolverOptions::new());
+    let simplex_result = simplex_lp_revised_in_place(&mut slack_form).unwrap();
+
+    assert_optimal(&simplex_result, &[8., 4., 0.]);
+}
+
+#[test]
+fn algo_revised_simplex_simplex_lp_revised_iteration_limit_initialization() {
+    let c = vec![1., 3.];
+    let a = vec![vec![-1., 1.], vec![-1., -1.], vec![-1., 4.]];
+    let b = vec![-1., -3., 2.];
+    let standard_form =
+        StandardFormLP::new(c.clone(), a.clone(), b.clone(), None).unwrap();
+    let mut slack_form = standard_form
+        .set_options(SolverOptions::new().set_iteration_limit(0))
+        .into_slack_form()
+        .unwrap();
+
+    let simplex_result = simplex_lp_revised_in_place(&mut slack_form).unwrap();
+
+    match simplex_result {
+        SimplexResult::Interrupted(Interruption::IterationLimit, point) => {
+            assert_eq!(point.len(), 2);
+        },
+        _ => panic!("The solve should be interrupted"),
+    }
+    // The slack form is left untouched
+    assert_eq!(slack_form.get_dim(), 2);
+
+    slack_form.set_options(SolverOptions::new());
+    let simplex_result = simplex_lp_revised_in_place(&mut slack_form).unwrap();
+
+    assert_ray(&simplex_result, &c, &a, &b);
+}
+
+#[test]
+fn algo_revised_simplex_simplex_lp_revised_time_limit_and_cancel_flag() {
+    let c = vec![1., 1.];
+    let a = vec![vec![4., -1.], vec![2., 1.], vec![-5., 2.]];
+    let b = vec![8., 10., 2.];
+    let standard_form = StandardFormLP::new(c, a, b, None).unwrap();
+
+    let slack_form = standard_form
+        .clone()
+        .set_options(SolverOptions::new().set_time_limit(Duration::new(0, 0)))
+        .into_slack_form()
+        .unwrap();
+    let simplex_result = simplex_lp_revised(&slack_form).unwrap();
+
+    assert_eq!(
+        simplex_result,
+        SimplexResult::Interrupted(Interruption::TimeLimit, vec![0., 0.])
+    );
+
+    let flag = CancelFlag::new();
+    let slack_form = standard_form
+        .set_options(SolverOptions::new().set_cancel_flag(flag.clone()))
+        .into_slack_form()
+        .unwrap();
+    flag.cancel();
+    let simplex_result = simplex_lp_revised(&slack_form).unwrap();
+
+    assert_eq!(
+        simplex_result,
+        SimplexResult::Interrupted(Interruption::Cancelled, vec![0., 0.])
+    );
+}
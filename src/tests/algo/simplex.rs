@@ -1,6 +1,15 @@
-use super::super::super::algo::simplex::simplex_lp_chvatal;
-use super::super::super::forms::slack::SimplexResult;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use super::super::super::algo::simplex::{simplex_lp_chvatal, simplex_lp_dual};
+use super::super::super::forms::builder::StandardFormBuilder;
+use super::super::super::forms::options::{
+    CancelFlag, IterationCallback, IterationInfo, Pricing, SolverOptions,
+};
+use super::super::super::forms::slack::{Interruption, SimplexResult};
 use super::super::super::forms::standard::StandardFormLP;
+use super::super::super::types::error::SolverError;
+use super::super::helpers::{assert_farkas, assert_optimal, assert_ray};
 
 #[test]
 fn algo_simplex_simplex_lp_chvatal_feasible_case_1() {
@@ -13,7 +22,7 @@ fn algo_simplex_simplex_lp_chvatal_feasible_case_1() {
 
     let simplex_result = simplex_lp_chvatal(&mut slack_form).unwrap();
 
-    assert_eq!(simplex_result, SimplexResult::Optimal(vec![2., 6.]));
+    assert_optimal(&simplex_result, &[2., 6.]);
 }
 
 #[test]
@@ -27,7 +36,7 @@ fn algo_simplex_simplex_lp_chvatal_feasible_case_2() {
 
     let simplex_result = simplex_lp_chvatal(&mut slack_form).unwrap();
 
-    assert_eq!(simplex_result, SimplexResult::Optimal(vec![8., 4., 0.]));
+    assert_optimal(&simplex_result, &[8., 4., 0.]);
 }
 
 #[test]
@@ -36,12 +45,13 @@ fn algo_simplex_simplex_lp_chvatal_unbounded_case_1() {
     let a = vec![vec![-2., 1.], vec![-1., -2.]];
     let b = vec![-1., -2.];
 
-    let standard_form = StandardFormLP::new(c, a, b, None).unwrap();
+    let standard_form =
+        StandardFormLP::new(c.clone(), a.clone(), b.clone(), None).unwrap();
     let mut slack_form = standard_form.into_slack_form().unwrap();
 
     let simplex_result = simplex_lp_chvatal(&mut slack_form).unwrap();
 
-    assert_eq!(simplex_result, SimplexResult::Unbounded);
+    assert_ray(&simplex_result, &c, &a, &b);
 }
 
 #[test]
@@ -50,12 +60,13 @@ fn algo_simplex_simplex_lp_chvatal_unbounded_case_2() {
     let a = vec![vec![-1., 1.], vec![-1., -1.], vec![-1., 4.]];
     let b = vec![-1., -3., 2.];
 
-    let standard_form = StandardFormLP::new(c, a, b, None).unwrap();
+    let standard_form =
+        StandardFormLP::new(c.clone(), a.clone(), b.clone(), None).unwrap();
     let mut slack_form = standard_form.into_slack_form().unwrap();
 
     let simplex_result = simplex_lp_chvatal(&mut slack_form).unwrap();
 
-    assert_eq!(simplex_result, SimplexResult::Unbounded);
+    assert_ray(&simplex_result, &c, &a, &b);
 }
 
 #[test]
@@ -64,12 +75,13 @@ fn algo_simplex_simplex_lp_chvatal_unfeasible_case_1() {
     let a = vec![vec![1., 1.], vec![-2., -2.]];
     let b = vec![-2., -10.];
 
-    let standard_form = StandardFormLP::new(c, a, b, None).unwrap();
+    let standard_form =
+        StandardFormLP::new(c, a.clone(), b.clone(), None).unwrap();
     let mut slack_form = standard_form.into_slack_form().unwrap();
 
     let simplex_result = simplex_lp_chvatal(&mut slack_form).unwrap();
 
-    assert_eq!(simplex_result, SimplexResult::Unfeasible);
+    assert_farkas(&simplex_result, &a, &b);
 }
 
 #[test]
@@ -78,12 +90,13 @@ fn algo_simplex_simplex_lp_chvatal_unfeasible_case_2() {
     let a = vec![vec![1., 2.], vec![-2., -6.], vec![0., 1.]];
     let b = vec![4., -12., 1.];
 
-    let standard_form = StandardFormLP::new(c, a, b, None).unwrap();
+    let standard_form =
+        StandardFormLP::new(c, a.clone(), b.clone(), None).unwrap();
     let mut slack_form = standard_form.into_slack_form().unwrap();
 
     let simplex_result = simplex_lp_chvatal(&mut slack_form).unwrap();
 
-    assert_eq!(simplex_result, SimplexResult::Unfeasible);
+    assert_farkas(&simplex_result, &a, &b);
 }
 
 #[test]
@@ -97,10 +110,7 @@ fn algo_simplex_simplex_lp_chvatal_non_basic_feasible_case_1() {
 
     let simplex_result = simplex_lp_chvatal(&mut slack_form).unwrap();
 
-    assert_eq!(
-        simplex_result,
-        SimplexResult::Optimal(vec![1.5555555555555554, 1.1111111111111112])
-    );
+    assert_optimal(&simplex_result, &[1.5555555555555554, 1.1111111111111112]);
 }
 
 #[test]
@@ -114,8 +124,1044 @@ fn algo_simplex_simplex_lp_chvatal_non_basic_feasible_case_2() {
 
     let simplex_result = simplex_lp_chvatal(&mut slack_form).unwrap();
 
+    assert_optimal(&simplex_result, &[11.333333333333334, 3.3333333333333335]);
+}
+
+#[test]
+fn algo_simplex_simplex_lp_dual_not_dual_feasible() {
+    let c = vec![1., 1.];
+    let a = vec![vec![4., -1.], vec![2., 1.], vec![-5., 2.]];
+    let b = vec![8., 10., 2.];
+
+    let standard_form = StandardFormLP::new(c, a, b, None).unwrap();
+    let mut slack_form = standard_form.into_slack_form().unwrap();
+
+    let err = simplex_lp_dual(&mut slack_form).unwrap_err();
+
+    assert_eq!(
+        err,
+        SolverError::InvalidState(
+            "The current basis is not dual feasible, the dual simplex cannot \
+             start."
+                .into()
+        )
+    );
+}
+
+#[test]
+fn algo_simplex_simplex_lp_dual_from_dual_feasible_basis() {
+    // min x_1 + x_2, with x_1 + 2 . x_2 >= 4 and 3 . x_1 + x_2 >= 6
+    let c = vec![-1., -1.];
+    let a = vec![vec![-1., -2.], vec![-3., -1.]];
+    let b = vec![-4., -6.];
+
+    let standard_form = StandardFormLP::new(c, a, b, None).unwrap();
+    let mut slack_form = standard_form.into_slack_form().unwrap();
+
+    let simplex_result = simplex_lp_dual(&mut slack_form).unwrap();
+
+    assert_optimal(&simplex_result, &[1.6, 1.2]);
+    assert!((slack_form.get_v() + 2.8).abs() < 1e-9);
+}
+
+#[test]
+fn algo_simplex_simplex_lp_dual_after_adding_constraint() {
+    let c = vec![3., 1., 2.];
+    let a = vec![vec![1., 1., 3.], vec![2., 2., 5.], vec![4., 1., 2.]];
+    let b = vec![30., 24., 36.];
+
+    let standard_form =
+        StandardFormLP::new(c.clone(), a.clone(), b.clone(), None).unwrap();
+    let mut slack_form = standard_form.into_slack_form().unwrap();
+    simplex_lp_chvatal(&mut slack_form).unwrap();
+
+    // x_1 <= 6 cuts the current optimal solution (8, 4, 0)
+    slack_form.add_constraint(vec![1., 0., 0.], 6.).unwrap();
+    let simplex_result = simplex_lp_dual(&mut slack_form).unwrap();
+
+    let mut a_scratch = a;
+    a_scratch.push(vec![1., 0., 0.]);
+    let mut b_scratch = b;
+    b_scratch.push(6.);
+    let standard_form =
+        StandardFormLP::new(c, a_scratch, b_scratch, None).unwrap();
+    let mut slack_scratch = standard_form.into_slack_form().unwrap();
+    let expected = simplex_lp_chvatal(&mut slack_scratch).unwrap();
+
+    if let SimplexResult::Optimal(solution) = expected {
+        let x = solution.get_x();
+        assert_optimal(&simplex_result, x);
+    } else {
+        panic!("Optimal solution expected, got {:?}", expected);
+    }
+    assert!((slack_form.get_v() - slack_scratch.get_v()).abs() < 1e-9);
+}
+
+#[test]
+fn algo_simplex_simplex_lp_dual_after_shifting_rhs() {
+    let c = vec![1., 1.];
+    let a = vec![vec![4., -1.], vec![2., 1.], vec![-5., 2.]];
+    let b = vec![8., 10., 2.];
+
+    let standard_form =
+        StandardFormLP::new(c.clone(), a.clone(), b, None).unwrap();
+    let mut slack_form = standard_form.into_slack_form().unwrap();
+    simplex_lp_chvatal(&mut slack_form).unwrap();
+
+    // 2 . x_1 + x_2 <= 10 becomes 2 . x_1 + x_2 <= 7
+    slack_form.shift_rhs(1, -3.).unwrap();
+    let simplex_result = simplex_lp_dual(&mut slack_form).unwrap();
+
+    let standard_form =
+        StandardFormLP::new(c, a, vec![8., 7., 2.], None).unwrap();
+    let mut slack_scratch = standard_form.into_slack_form().unwrap();
+    let expected = simplex_lp_chvatal(&mut slack_scratch).unwrap();
+
+    if let SimplexResult::Optimal(solution) = expected {
+        let x = solution.get_x();
+        assert_optimal(&simplex_result, x);
+    } else {
+        panic!("Optimal solution expected, got {:?}", expected);
+    }
+    assert!((slack_form.get_v() - slack_scratch.get_v()).abs() < 1e-9);
+}
+
+#[test]
+fn algo_simplex_simplex_lp_dual_unfeasible_after_adding_constraint() {
+    let c = vec![1., 1.];
+    let a = vec![vec![4., -1.], vec![2., 1.], vec![-5., 2.]];
+    let b = vec![8., 10., 2.];
+
+    let standard_form =
+        StandardFormLP::new(c, a.clone(), b.clone(), None).unwrap();
+    let mut slack_form = standard_form.into_slack_form().unwrap();
+    simplex_lp_chvatal(&mut slack_form).unwrap();
+
+    // x_1 + x_2 >= 100
+    slack_form.add_constraint(vec![-1., -1.], -100.).unwrap();
+    let simplex_result = simplex_lp_dual(&mut slack_form).unwrap();
+
+    // The certificate covers the added row as well
+    let mut a = a;
+    a.push(vec![-1., -1.]);
+    let mut b = b;
+    b.push(-100.);
+    assert_farkas(&simplex_result, &a, &b);
+}
+
+#[test]
+fn algo_simplex_simplex_lp_chvatal_bounded_case_1() {
+    // x_1 in [0, 3] and x_2 in [1, 4]
+    let c = vec![1., 1.];
+    let a = vec![vec![1., 2.]];
+    let b = vec![10.];
+
+    let standard_form = StandardFormLP::new(c, a, b, None)
+        .unwrap()
+        .add_variable_bounds(0, 0., 3.)
+        .unwrap()
+        .add_variable_bounds(1, 1., 4.)
+        .unwrap();
+    let mut slack_form = standard_form.into_slack_form().unwrap();
+
+    let simplex_result = simplex_lp_chvatal(&mut slack_form).unwrap();
+
+    assert_optimal(&simplex_result, &[3., 3.5]);
+    assert!((slack_form.get_v() - 6.5).abs() < 1e-9);
+    // Bounds are not rows of the dictionary
+    assert_eq!(slack_form.get_B().len(), 1);
+}
+
+#[test]
+fn algo_simplex_simplex_lp_chvatal_bounded_bound_flips_only() {
+    let c = vec![1., 1.];
+    let a = vec![vec![1., 1.]];
+    let b = vec![100.];
+
+    let standard_form = StandardFormLP::new(c, a, b, None)
+        .unwrap()
+        .add_variable_bounds(0, 0., 3.)
+        .unwrap()
+        .add_variable_bounds(1, 0., 4.)
+        .unwrap();
+    let mut slack_form = standard_form.into_slack_form().unwrap();
+
+    let simplex_result = simplex_lp_chvatal(&mut slack_form).unwrap();
+
+    assert_optimal(&simplex_result, &[3., 4.]);
+    // No pivot, both variables are non basic at their upper bound
+    assert_eq!(slack_form.get_N(), &vec![0, 1]);
+    assert_eq!(slack_form.get_flipped(), &vec![true, true, false]);
+}
+
+#[test]
+fn algo_simplex_simplex_lp_chvatal_bounded_negative_lower_bounds() {
+    // x_1 in [-3, 10] and x_2 in [-2, 1]
+    let c = vec![1., -1.];
+    let a = vec![vec![1., 1.]];
+    let b = vec![2.];
+
+    let standard_form = StandardFormLP::new(c, a, b, None)
+        .unwrap()
+        .add_variable_bounds(0, -3., 10.)
+        .unwrap()
+        .add_variable_bounds(1, -2., 1.)
+        .unwrap();
+    let mut slack_form = standard_form.into_slack_form().unwrap();
+
+    let simplex_result = simplex_lp_chvatal(&mut slack_form).unwrap();
+
+    assert_optimal(&simplex_result, &[4., -2.]);
+    assert!((slack_form.get_v() - 6.).abs() < 1e-9);
+}
+
+#[test]
+fn algo_simplex_simplex_lp_chvatal_bounded_non_basic_feasible() {
+    // min x_1 + 2 . x_2 with x_1 + x_2 >= 6, x_1 <= 4 and x_2 <= 3
+    let c = vec![-1., -2.];
+    let a = vec![vec![-1., -1.]];
+    let b = vec![-6.];
+
+    let standard_form = StandardFormLP::new(c, a, b, None)
+        .unwrap()
+        .add_variable_bounds(0, 0., 4.)
+        .unwrap()
+        .add_variable_bounds(1, 0., 3.)
+        .unwrap();
+    let mut slack_form = standard_form.into_slack_form().unwrap();
+
+    let simplex_result = simplex_lp_chvatal(&mut slack_form).unwrap();
+
+    assert_optimal(&simplex_result, &[4., 2.]);
+    assert!((slack_form.get_v() + 8.).abs() < 1e-9);
+}
+
+#[test]
+fn algo_simplex_simplex_lp_chvatal_bounded_fixed_variable() {
+    let c = vec![1., 1.];
+    let a = vec![vec![1., 2.]];
+    let b = vec![10.];
+
+    let standard_form = StandardFormLP::new(c, a, b, None)
+        .unwrap()
+        .add_variable_bounds(0, 2., 2.)
+        .unwrap();
+    let mut slack_form = standard_form.into_slack_form().unwrap();
+
+    let simplex_result = simplex_lp_chvatal(&mut slack_form).unwrap();
+
+    assert_optimal(&simplex_result, &[2., 4.]);
+}
+
+#[test]
+fn algo_simplex_simplex_lp_chvatal_bounded_unfeasible() {
+    // x_1 + x_2 >= 10 with x_1 <= 4 and x_2 <= 3
+    let c = vec![1., 1.];
+    let a = vec![vec![-1., -1.]];
+    let b = vec![-10.];
+
+    let standard_form = StandardFormLP::new(c, a, b, None)
+        .unwrap()
+        .add_variable_bounds(0, 0., 4.)
+        .unwrap()
+        .add_variable_bounds(1, 0., 3.)
+        .unwrap();
+    let mut slack_form = standard_form.into_slack_form().unwrap();
+
+    let simplex_result = simplex_lp_chvatal(&mut slack_form).unwrap();
+
+    // Over the bounds, -y . (x_1 + x_2) >= -7 . y > -10 . y
+    if let SimplexResult::Unfeasible(y) = simplex_result {
+        assert_eq!(y.len(), 1);
+        assert!(y[0] > 0.);
+    } else {
+        panic!("Farkas certificate expected, got {:?}", simplex_result);
+    }
+}
+
+#[test]
+fn algo_simplex_simplex_lp_chvatal_bounded_unbounded() {
+    let c = vec![1., 1.];
+    let a = vec![vec![1., -1.]];
+    let b = vec![1.];
+
+    let standard_form =
+        StandardFormLP::new(c.clone(), a.clone(), b.clone(), None)
+            .unwrap()
+            .add_variable_bounds(0, 0., 5.)
+            .unwrap();
+    let mut slack_form = standard_form.into_slack_form().unwrap();
+
+    let simplex_result = simplex_lp_chvatal(&mut slack_form).unwrap();
+
+    // x_1 is bounded, only x_2 goes to infinity
+    if let SimplexResult::Unbounded(_, direction) = &simplex_result {
+        assert_eq!(direction[0], 0.);
+        assert!(direction[1] > 0.);
+    }
+    assert_ray(&simplex_result, &c, &a, &b);
+}
+
+#[test]
+fn algo_simplex_simplex_lp_chvatal_bounded_same_as_rows() {
+    let c = vec![3., 1., 2.];
+    let a = vec![vec![1., 1., 3.], vec![2., 2., 5.], vec![4., 1., 2.]];
+    let b = vec![30., 24., 36.];
+    let bounds = [(0, 1., 6.), (1, 0.5, 5.), (2, 0., 1.)];
+
+    let mut standard_form =
+        StandardFormLP::new(c.clone(), a.clone(), b.clone(), None).unwrap();
+    for (index, lower, upper) in bounds.iter() {
+        standard_form =
+            standard_form.add_variable_bounds(*index, *lower, *upper).unwrap();
+    }
+    let mut slack_form = standard_form.into_slack_form().unwrap();
+    let simplex_result = simplex_lp_chvatal(&mut slack_form).unwrap();
+
+    // Same linear program, with the bounds written as rows
+    let mut a_rows = a;
+    let mut b_rows = b;
+    for (index, lower, upper) in bounds.iter() {
+        let mut row = vec![0.; 3];
+        row[*index] = 1.;
+        a_rows.push(row.clone());
+        b_rows.push(*upper);
+        row[*index] = -1.;
+        a_rows.push(row);
+        b_rows.push(-lower);
+    }
+    let standard_form = StandardFormLP::new(c, a_rows, b_rows, None).unwrap();
+    let mut slack_rows = standard_form.into_slack_form().unwrap();
+    let expected = simplex_lp_chvatal(&mut slack_rows).unwrap();
+
+    if let SimplexResult::Optimal(solution) = expected {
+        let x = solution.get_x();
+        assert_optimal(&simplex_result, x);
+    } else {
+        panic!("Optimal solution expected, got {:?}", expected);
+    }
+    assert!((slack_form.get_v() - slack_rows.get_v()).abs() < 1e-9);
+}
+
+#[test]
+fn algo_simplex_simplex_lp_dual_after_setting_variable_bounds() {
+    let c = vec![1., 1.];
+    let a = vec![vec![1., 2.]];
+    let b = vec![10.];
+
+    let standard_form = StandardFormLP::new(c, a, b, None)
+        .unwrap()
+        .add_variable_bounds(0, 0., 3.)
+        .unwrap()
+        .add_variable_bounds(1, 1., 4.)
+        .unwrap();
+    let mut slack_form = standard_form.into_slack_form().unwrap();
+    simplex_lp_chvatal(&mut slack_form).unwrap();
+
+    // x_2 = 3.5 at the optimum, x_2 <= 3 now
+    slack_form.set_variable_bounds(1, 1., 3.).unwrap();
+    let simplex_result = simplex_lp_dual(&mut slack_form).unwrap();
+
+    assert_optimal(&simplex_result, &[3., 3.]);
+    assert!((slack_form.get_v() - 6.).abs() < 1e-9);
+
+    // x_1 = 3 at the optimum, x_1 >= 3.5 now, x_1 + 2 . x_2 <= 10 is still
+    // satisfied
+    slack_form.set_variable_bounds(0, 3.5, 3.5).unwrap();
+    let simplex_result = simplex_lp_dual(&mut slack_form).unwrap();
+
+    assert_optimal(&simplex_result, &[3.5, 3.]);
+}
+
+#[test]
+fn algo_simplex_simplex_lp_dual_bounded_after_adding_constraint() {
+    let c = vec![1., 1.];
+    let a = vec![vec![1., 2.]];
+    let b = vec![10.];
+
+    let standard_form = StandardFormLP::new(c, a, b, None)
+        .unwrap()
+        .add_variable_bounds(0, 0., 3.)
+        .unwrap()
+        .add_variable_bounds(1, 1., 4.)
+        .unwrap();
+    let mut slack_form = standard_form.into_slack_form().unwrap();
+    simplex_lp_chvatal(&mut slack_form).unwrap();
+
+    // x_1 + x_2 <= 5
+    slack_form.add_constraint(vec![1., 1.], 5.).unwrap();
+    let simplex_result = simplex_lp_dual(&mut slack_form).unwrap();
+
+    if let SimplexResult::Optimal(solution) = simplex_result {
+        let x = solution.get_x();
+        assert!(x[0] + x[1] <= 5. + 1e-9);
+        assert!(x[1] >= 1. - 1e-9);
+    } else {
+        panic!("Optimal solution expected, got {:?}", simplex_result);
+    }
+    assert!((slack_form.get_v() - 5.).abs() < 1e-9);
+}
+
+/// Transportation problem: 2 supplies (20, 30) and 3 demands (10, 25, 15),
+/// every supply and demand must be met exactly.
+fn transportation_builder() -> StandardFormBuilder {
+    StandardFormBuilder::new()
+        .add_min_objective(vec![2., 3., 1., 5., 4., 8.])
+        .unwrap()
+        .add_equality_constraint(vec![1., 1., 1., 0., 0., 0.], 20.)
+        .unwrap()
+        .add_equality_constraint(vec![0., 0., 0., 1., 1., 1.], 30.)
+        .unwrap()
+        .add_equality_constraint(vec![1., 0., 0., 1., 0., 0.], 10.)
+        .unwrap()
+        .add_equality_constraint(vec![0., 1., 0., 0., 1., 0.], 25.)
+        .unwrap()
+        .add_equality_constraint(vec![0., 0., 1., 0., 0., 1.], 15.)
+        .unwrap()
+}
+
+#[test]
+fn algo_simplex_simplex_lp_chvatal_equality_constraints() {
+    let standard_form = transportation_builder().build().unwrap();
+    let mut slack_form = standard_form.into_slack_form().unwrap();
+
+    let simplex_result = simplex_lp_chvatal(&mut slack_form).unwrap();
+
+    // One row per equality
+    assert_eq!(slack_form.get_B().len(), 5);
+    if let SimplexResult::Optimal(solution) = simplex_result {
+        let x = solution.get_x();
+        let supplies = [x[0] + x[1] + x[2], x[3] + x[4] + x[5]];
+        let demands = [x[0] + x[3], x[1] + x[4], x[2] + x[5]];
+        for (l, r) in supplies.iter().zip([20., 30.].iter()) {
+            assert!((l - r).abs() < 1e-9);
+        }
+        for (l, r) in demands.iter().zip([10., 25., 15.].iter()) {
+            assert!((l - r).abs() < 1e-9);
+        }
+    } else {
+        panic!("Optimal solution expected, got {:?}", simplex_result);
+    }
+    // 5 units from 1 to 1, 15 from 1 to 3, 5 from 2 to 1, 25 from 2 to 2
+    assert!((slack_form.get_v() + 150.).abs() < 1e-9);
+}
+
+#[test]
+fn algo_simplex_simplex_lp_chvatal_equality_constraints_same_as_two_rows() {
+    let c = vec![1., 2., 1.];
+    let a = vec![vec![1., 1., 1.], vec![1., -1., 2.], vec![0., 1., 3.]];
+    let b = vec![4., 1., 6.];
+
+    let standard_form =
+        StandardFormLP::new(c.clone(), a.clone(), b.clone(), None)
+            .unwrap()
+            .add_row_range(0, 0.)
+            .unwrap()
+            .add_row_range(1, 0.)
+            .unwrap();
+    let mut slack_form = standard_form.into_slack_form().unwrap();
+    let simplex_result = simplex_lp_chvatal(&mut slack_form).unwrap();
+
+    // Same linear program, each equality written as two rows
+    let a_rows = vec![
+        a[0].clone(),
+        a[0].iter().map(|v| -v).collect(),
+        a[1].clone(),
+        a[1].iter().map(|v| -v).collect(),
+        a[2].clone(),
+    ];
+    let b_rows = vec![b[0], -b[0], b[1], -b[1], b[2]];
+    let standard_form = StandardFormLP::new(c, a_rows, b_rows, None).unwrap();
+    let mut slack_rows = standard_form.into_slack_form().unwrap();
+    let expected = simplex_lp_chvatal(&mut slack_rows).unwrap();
+
+    if let SimplexResult::Optimal(solution) = expected {
+        let x = solution.get_x();
+        assert_optimal(&simplex_result, x);
+    } else {
+        panic!("Optimal solution expected, got {:?}", expected);
+    }
+    assert!((slack_form.get_v() - slack_rows.get_v()).abs() < 1e-9);
+}
+
+#[test]
+fn algo_simplex_simplex_lp_chvatal_equality_constraints_unfeasible() {
+    // x_1 + x_2 = 4 and x_1 + x_2 = 5
+    let standard_form = StandardFormBuilder::new()
+        .add_max_objective(vec![1., 1.])
+        .unwrap()
+        .add_equality_constraint(vec![1., 1.], 4.)
+        .unwrap()
+        .add_equality_constraint(vec![1., 1.], 5.)
+        .unwrap()
+        .build()
+        .unwrap();
+    let mut slack_form = standard_form.into_slack_form().unwrap();
+
+    let simplex_result = simplex_lp_chvatal(&mut slack_form).unwrap();
+
+    assert!(matches!(simplex_result, SimplexResult::Unfeasible(_)));
+}
+
+#[test]
+fn algo_simplex_simplex_lp_dual_after_shifting_equality_rhs() {
+    let standard_form = transportation_builder().build().unwrap();
+    let mut slack_form = standard_form.into_slack_form().unwrap();
+    simplex_lp_chvatal(&mut slack_form).unwrap();
+
+    // Both the first supply and the first demand increase by 5
+    slack_form.shift_rhs(0, 5.).unwrap();
+    slack_form.shift_rhs(2, 5.).unwrap();
+    let simplex_result = simplex_lp_dual(&mut slack_form).unwrap();
+
+    let standard_form = StandardFormBuilder::new()
+        .add_min_objective(vec![2., 3., 1., 5., 4., 8.])
+        .unwrap()
+        .add_equality_constraint(vec![1., 1., 1., 0., 0., 0.], 25.)
+        .unwrap()
+        .add_equality_constraint(vec![0., 0., 0., 1., 1., 1.], 30.)
+        .unwrap()
+        .add_equality_constraint(vec![1., 0., 0., 1., 0., 0.], 15.)
+        .unwrap()
+        .add_equality_constraint(vec![0., 1., 0., 0., 1., 0.], 25.)
+        .unwrap()
+        .add_equality_constraint(vec![0., 0., 1., 0., 0., 1.], 15.)
+        .unwrap()
+        .build()
+        .unwrap();
+    let mut slack_scratch = standard_form.into_slack_form().unwrap();
+    simplex_lp_chvatal(&mut slack_scratch).unwrap();
+
+    assert!(matches!(simplex_result, SimplexResult::Optimal(_)));
+    assert!((slack_form.get_v() - slack_scratch.get_v()).abs() < 1e-9);
+}
+
+#[test]
+fn algo_simplex_simplex_lp_chvatal_range_constraints() {
+    // max x_1 + 2 . x_2 with 2 <= x_1 + x_2 <= 4, 1 <= x_1 - x_2 <= 3
+    // and 1 <= x_2 <= 5 (as a range constraint)
+    let builder = StandardFormBuilder::new()
+        .add_max_objective(vec![1., 2.])
+        .unwrap()
+        .add_range_constraint(vec![1., 1.], 2., 4.)
+        .unwrap()
+        .add_range_constraint(vec![1., -1.], 1., 3.)
+        .unwrap()
+        .add_range_constraint(vec![0., 1.], 1., 5.)
+        .unwrap();
+    let standard_form = builder.build().unwrap();
+    let mut slack_form = standard_form.into_slack_form().unwrap();
+
+    let simplex_result = simplex_lp_chvatal(&mut slack_form).unwrap();
+
+    // One row per range constraint
+    assert_eq!(slack_form.get_B().len(), 3);
+    assert_optimal(&simplex_result, &[2.5, 1.5]);
+    assert!((slack_form.get_v() - 5.5).abs() < 1e-9);
+}
+
+#[test]
+fn algo_simplex_simplex_lp_chvatal_range_constraints_same_as_two_rows() {
+    let c = vec![3., 1., 2.];
+    let a = [vec![1., 1., 3.], vec![2., 2., 5.], vec![4., 1., 2.]];
+    let lower = [5., 10., 20.];
+    let upper = [30., 24., 36.];
+
+    let mut builder =
+        StandardFormBuilder::new().add_max_objective(c.clone()).unwrap();
+    let mut builder_rows =
+        StandardFormBuilder::new().add_max_objective(c).unwrap();
+    for (row, (l, u)) in a.iter().zip(lower.iter().zip(upper.iter())) {
+        builder = builder.add_range_constraint(row.clone(), *l, *u).unwrap();
+        builder_rows = builder_rows
+            .add_less_than_or_equal_constraint(row.clone(), *u)
+            .unwrap()
+            .add_greater_than_or_equal_constraint(row.clone(), *l)
+            .unwrap();
+    }
+
+    let mut slack_form = builder.build().unwrap().into_slack_form().unwrap();
+    let simplex_result = simplex_lp_chvatal(&mut slack_form).unwrap();
+
+    let mut slack_rows =
+        builder_rows.build().unwrap().into_slack_form().unwrap();
+    let expected = simplex_lp_chvatal(&mut slack_rows).unwrap();
+
+    if let SimplexResult::Optimal(solution) = expected {
+        let x = solution.get_x();
+        assert_optimal(&simplex_result, x);
+    } else {
+        panic!("Optimal solution expected, got {:?}", expected);
+    }
+    assert!((slack_form.get_v() - slack_rows.get_v()).abs() < 1e-9);
+}
+
+#[test]
+fn algo_simplex_simplex_lp_chvatal_range_constraints_unfeasible() {
+    // 5 <= x_1 + x_2 <= 6 with x_1 + 2 . x_2 <= 4
+    let standard_form = StandardFormBuilder::new()
+        .add_max_objective(vec![1., 1.])
+        .unwrap()
+        .add_range_constraint(vec![1., 1.], 5., 6.)
+        .unwrap()
+        .add_less_than_or_equal_constraint(vec![1., 2.], 4.)
+        .unwrap()
+        .add_range_constraint(vec![1., 0.], 0., 2.)
+        .unwrap()
+        .build()
+        .unwrap();
+    let mut slack_form = standard_form.into_slack_form().unwrap();
+
+    let simplex_result = simplex_lp_chvatal(&mut slack_form).unwrap();
+
+    assert!(matches!(simplex_result, SimplexResult::Unfeasible(_)));
+}
+
+#[test]
+fn algo_simplex_simplex_lp_dual_after_shifting_range_rhs() {
+    let standard_form = StandardFormBuilder::new()
+        .add_max_objective(vec![1., 2.])
+        .unwrap()
+        .add_range_constraint(vec![1., 1.], 2., 4.)
+        .unwrap()
+        .add_range_constraint(vec![1., -1.], 1., 3.)
+        .unwrap()
+        .build()
+        .unwrap();
+    let mut slack_form = standard_form.into_slack_form().unwrap();
+    simplex_lp_chvatal(&mut slack_form).unwrap();
+
+    // 2 <= x_1 + x_2 <= 4 becomes 1 <= x_1 + x_2 <= 3
+    slack_form.shift_rhs(0, -1.).unwrap();
+    let simplex_result = simplex_lp_dual(&mut slack_form).unwrap();
+
+    assert_optimal(&simplex_result, &[2., 1.]);
+    assert!((slack_form.get_v() - 4.).abs() < 1e-9);
+}
+
+#[test]
+fn algo_simplex_simplex_lp_chvatal_free_variable() {
+    // x_1 is free, it is negative at the optimum
+    let c = vec![1., 2.];
+    let a = vec![vec![-1., 1.], vec![1., 1.]];
+    let b = vec![4., 2.];
+
+    let standard_form = StandardFormLP::new(c, a, b, Some(vec![0])).unwrap();
+    let mut slack_form = standard_form.into_slack_form().unwrap();
+
+    let simplex_result = simplex_lp_chvatal(&mut slack_form).unwrap();
+
+    assert_optimal(&simplex_result, &[-1., 3.]);
+    assert!((slack_form.get_v() - 5.).abs() < 1e-9);
+}
+
+#[test]
+fn algo_simplex_simplex_lp_chvatal_free_variable_non_basic_feasible() {
+    // max -x_1 - x_2, with x_1 <= -2, x_1 >= -5 and x_2 >= 1
+    let c = vec![-1., -1.];
+    let a = vec![vec![1., 0.], vec![-1., 0.], vec![0., -1.]];
+    let b = vec![-2., 5., -1.];
+
+    let standard_form = StandardFormLP::new(c, a, b, Some(vec![0])).unwrap();
+    let mut slack_form = standard_form.into_slack_form().unwrap();
+
+    let simplex_result = simplex_lp_chvatal(&mut slack_form).unwrap();
+
+    assert_optimal(&simplex_result, &[-5., 1.]);
+    assert!((slack_form.get_v() - 4.).abs() < 1e-9);
+}
+
+#[test]
+fn algo_simplex_simplex_lp_chvatal_free_variable_unbounded() {
+    let c = vec![-1., 1.];
+    let a = vec![vec![1., 1.]];
+    let b = vec![2.];
+
+    let standard_form =
+        StandardFormLP::new(c.clone(), a.clone(), b.clone(), Some(vec![0]))
+            .unwrap();
+    let mut slack_form = standard_form.into_slack_form().unwrap();
+
+    let simplex_result = simplex_lp_chvatal(&mut slack_form).unwrap();
+
+    assert_ray(&simplex_result, &c, &a, &b);
+}
+
+#[test]
+fn algo_simplex_simplex_lp_chvatal_free_variable_from_builder() {
+    // min 2 . x_1 + x_2, with x_1 >= -3 and x_1 + x_2 >= -1, x_1 is free and
+    // there is no split column in the solution
+    let standard_form = StandardFormBuilder::new()
+        .add_non_negative_indices(vec![0])
+        .unwrap()
+        .add_min_objective(vec![2., 1.])
+        .unwrap()
+        .add_greater_than_or_equal_constraint(vec![1., 0.], -3.)
+        .unwrap()
+        .add_greater_than_or_equal_constraint(vec![1., 1.], -1.)
+        .unwrap()
+        .build()
+        .unwrap();
+    let mut slack_form = standard_form.into_slack_form().unwrap();
+
+    let simplex_result = simplex_lp_chvatal(&mut slack_form).unwrap();
+
+    assert_optimal(&simplex_result, &[-3., 2.]);
+    assert!((slack_form.get_v() - 4.).abs() < 1e-9);
+}
+
+#[test]
+fn algo_simplex_simplex_lp_dual_free_variable_after_adding_constraint() {
+    let c = vec![1., 2.];
+    let a = vec![vec![-1., 1.], vec![1., 1.]];
+    let b = vec![4., 2.];
+
+    let standard_form = StandardFormLP::new(c, a, b, Some(vec![0])).unwrap();
+    let mut slack_form = standard_form.into_slack_form().unwrap();
+    simplex_lp_chvatal(&mut slack_form).unwrap();
+
+    // x_1 = -1 at the optimum, x_1 >= -0.5 now
+    slack_form.add_constraint(vec![-1., 0.], 0.5).unwrap();
+    let simplex_result = simplex_lp_dual(&mut slack_form).unwrap();
+
+    assert_optimal(&simplex_result, &[-0.5, 2.5]);
+    assert!((slack_form.get_v() - 4.5).abs() < 1e-9);
+}
+
+#[test]
+fn algo_simplex_simplex_lp_chvatal_solution_duals_and_reduced_costs() {
+    // x_1 in [0, 3] is at its upper bound, x_2 is basic
+    let c = vec![1., 1.];
+    let a = vec![vec![1., 2.], vec![1., 0.]];
+    let b = vec![10., 5.];
+
+    let standard_form = StandardFormLP::new(c, a, b, None)
+        .unwrap()
+        .add_variable_bounds(0, 0., 3.)
+        .unwrap();
+    let mut slack_form = standard_form.into_slack_form().unwrap();
+
+    let simplex_result = simplex_lp_chvatal(&mut slack_form).unwrap();
+
+    if let SimplexResult::Optimal(solution) = simplex_result {
+        assert_eq!(solution.get_objective_value(), 6.5);
+        assert_eq!(solution.get_x(), &vec![3., 3.5]);
+        assert_eq!(solution.get_row_activities(), &vec![10., 3.]);
+        assert_eq!(solution.get_slacks(), &vec![0., 2.]);
+        assert_eq!(solution.get_duals(), &vec![0.5, 0.]);
+        assert_eq!(solution.get_reduced_costs(), &vec![0.5, 0.]);
+    } else {
+        panic!("Optimal solution expected, got {:?}", simplex_result);
+    }
+}
+
+fn anti_degeneracy_options() -> Vec<SolverOptions> {
+    let mut options = Vec::new();
+    for pricing in [Pricing::Bland, Pricing::Dantzig, Pricing::Devex].iter() {
+        for harris in [false, true].iter() {
+            for perturbation in [false, true].iter() {
+                options.push(
+                    SolverOptions::new()
+                        .set_pricing(*pricing)
+                        .set_harris_ratio_test(*harris)
+                        .set_perturbation(*perturbation),
+                );
+            }
+        }
+    }
+
+    options
+}
+
+#[test]
+fn algo_simplex_simplex_lp_chvatal_anti_degeneracy_cycling_example() {
+    let c = vec![10., -57., -9., -24.];
+    let a = vec![
+        vec![0.5, -5.5, -2.5, 9.],
+        vec![0.5, -1.5, -0.5, 1.],
+        vec![1., 0., 0., 0.],
+    ];
+    let b = vec![0., 0., 1.];
+    let standard_form = StandardFormLP::new(c, a, b, None).unwrap();
+
+    for options in anti_degeneracy_options().into_iter() {
+        let mut slack_form = standard_form
+            .clone()
+            .set_options(options)
+            .into_slack_form()
+            .unwrap();
+
+        let simplex_result = simplex_lp_chvatal(&mut slack_form).unwrap();
+
+        assert_optimal(&simplex_result, &[1., 0., 1., 0.]);
+        assert!((slack_form.get_v() - 1.).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn algo_simplex_simplex_lp_chvatal_anti_degeneracy_bounds_and_ranges() {
+    // max x_1 + 2 . x_2 + x_3 / 2, with x_1 free, 0 <= x_2 <= 3 and
+    // x_1 + x_2 + x_3 = 4, 0 <= x_1 - x_3 <= 2, x_2 + x_3 >= 1
+    let standard_form = StandardFormBuilder::new()
+        .add_max_objective(vec![1., 2., 0.5])
+        .unwrap()
+        .add_non_negative_indices(vec![0])
+        .unwrap()
+        .add_variable_bounds(1, 0., 3.)
+        .unwrap()
+        .add_equality_constraint(vec![1., 1., 1.], 4.)
+        .unwrap()
+        .add_range_constraint(vec![1., 0., -1.], 0., 2.)
+        .unwrap()
+        .add_greater_than_or_equal_constraint(vec![0., 1., 1.], 1.)
+        .unwrap()
+        .build()
+        .unwrap();
+
+    for options in anti_degeneracy_options().into_iter() {
+        let mut slack_form = standard_form
+            .clone()
+            .set_options(options)
+            .into_slack_form()
+            .unwrap();
+
+        let simplex_result = simplex_lp_chvatal(&mut slack_form).unwrap();
+
+        assert_optimal(&simplex_result, &[1., 3., 0.]);
+    }
+}
+
+#[test]
+fn algo_simplex_simplex_lp_dual_anti_degeneracy_after_adding_constraint() {
+    let c = vec![3., 1., 2.];
+    let a = vec![vec![1., 1., 3.], vec![2., 2., 5.], vec![4., 1., 2.]];
+    let b = vec![30., 24., 36.];
+    let standard_form = StandardFormLP::new(c, a, b, None).unwrap();
+
+    for options in anti_degeneracy_options().into_iter() {
+        let mut slack_form = standard_form
+            .clone()
+            .set_options(options)
+            .into_slack_form()
+            .unwrap();
+        simplex_lp_chvatal(&mut slack_form).unwrap();
+
+        // x_1 <= 6 cuts the current optimal solution (8, 4, 0)
+        slack_form.add_constraint(vec![1., 0., 0.], 6.).unwrap();
+        let simplex_result = simplex_lp_dual(&mut slack_form).unwrap();
+
+        assert_optimal(&simplex_result, &[6., 6., 0.]);
+        assert!((slack_form.get_v() - 24.).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn algo_simplex_simplex_lp_chvatal_anti_degeneracy_unbounded_and_unfeasible() {
+    let c = vec![1., 3.];
+    let a = vec![vec![-1., 1.], vec![-1., -1.], vec![-1., 4.]];
+    let b = vec![-1., -3., 2.];
+    let unbounded =
+        StandardFormLP::new(c.clone(), a.clone(), b.clone(), None).unwrap();
+
+    for options in anti_degeneracy_options().into_iter() {
+        let mut slack_form =
+            unbounded.clone().set_options(options).into_slack_form().unwrap();
+
+        let simplex_result = simplex_lp_chvatal(&mut slack_form).unwrap();
+
+        assert_ray(&simplex_result, &c, &a, &b);
+    }
+
+    let c = vec![1., -2.];
+    let a = vec![vec![1., 2.], vec![-2., -6.], vec![0., 1.]];
+    let b = vec![4., -12., 1.];
+    let unfeasible =
+        StandardFormLP::new(c, a.clone(), b.clone(), None).unwrap();
+
+    for options in anti_degeneracy_options().into_iter() {
+        let mut slack_form =
+            unfeasible.clone().set_options(options).into_slack_form().unwrap();
+
+        let simplex_result = simplex_lp_chvatal(&mut slack_form).unwrap();
+
+        assert_farkas(&simplex_result, &a, &b);
+    }
+}
+
+#[test]
+fn algo_simplex_simplex_lp_chvatal_iteration_limit() {
+    let c = vec![3., 1., 2.];
+    let a = vec![vec![1., 1., 3.], vec![2., 2., 5.], vec![4., 1., 2.]];
+    let b = vec![30., 24., 36.];
+    let standard_form = StandardFormLP::new(c, a, b, None).unwrap();
+    let mut slack_form = standard_form
+        .set_options(SolverOptions::new().set_iteration_limit(1))
+        .into_slack_form()
+        .unwrap();
+
+    let simplex_result = simplex_lp_chvatal(&mut slack_form).unwrap();
+
+    // Bland's rule: x_1 enters first, up to 9.0
+    assert_eq!(
+        simplex_result,
+        SimplexResult::Interrupted(
+            Interruption::IterationLimit,
+            vec![9., 0., 0.]
+        )
+    );
+
+    // The solve goes on from the current basis
+    slack_form.set_options(SolverOptions::new());
+    let simplex_result = simplex_lp_chvatal(&mut slack_form).unwrap();
+
+    assert_optimal(&simplex_result, &[8., 4., 0.]);
+}
+
+#[test]
+fn algo_simplex_simplex_lp_chvatal_iteration_limit_initialization() {
+    let c = vec![1., 3.];
+    let a = vec![vec![-1., 1.], vec![-1., -1.], vec![-1., 4.]];
+    let b = vec![-1., -3., 2.];
+    let standard_form =
+        StandardFormLP::new(c.clone(), a.clone(), b.clone(), None).unwrap();
+    let mut slack_form = standard_form
+        .set_options(SolverOptions::new().set_iteration_limit(0))
+        .into_slack_form()
+        .unwrap();
+
+    let simplex_result = simplex_lp_chvatal(&mut slack_form).unwrap();
+
+    match simplex_result {
+        SimplexResult::Interrupted(Interruption::IterationLimit, point) => {
+            assert_eq!(point.len(), 2);
+        },
+        _ => panic!("The solve should be interrupted"),
+    }
+    // The auxiliary variable of the initialization is removed
+    assert_eq!(slack_form.get_dim(), 2);
+
+    slack_form.set_options(SolverOptions::new());
+    let simplex_result = simplex_lp_chvatal(&mut slack_form).unwrap();
+
+    assert_ray(&simplex_result, &c, &a, &b);
+}
+
+#[test]
+fn algo_simplex_simplex_lp_chvatal_time_limit_and_cancel_flag() {
+    let c = vec![1., 1.];
+    let a = vec![vec![4., -1.], vec![2., 1.], vec![-5., 2.]];
+    let b = vec![8., 10., 2.];
+    let standard_form = StandardFormLP::new(c, a, b, None).unwrap();
+
+    let mut slack_form = standard_form
+        .clone()
+        .set_options(SolverOptions::new().set_time_limit(Duration::new(0, 0)))
+        .into_slack_form()
+        .unwrap();
+    let simplex_result = simplex_lp_chvatal(&mut slack_form).unwrap();
+
+    assert_eq!(
+        simplex_result,
+        SimplexResult::Interrupted(Interruption::TimeLimit, vec![0., 0.])
+    );
+
+    let flag = CancelFlag::new();
+    let mut slack_form = standard_form
+        .set_options(SolverOptions::new().set_cancel_flag(flag.clone()))
+        .into_slack_form()
+        .unwrap();
+    flag.cancel();
+    let simplex_result = simplex_lp_chvatal(&mut slack_form).unwrap();
+
     assert_eq!(
         simplex_result,
-        SimplexResult::Optimal(vec![11.333333333333334, 3.3333333333333335])
+        SimplexResult::Interrupted(Interruption::Cancelled, vec![0., 0.])
     );
 }
+
+#[test]
+fn algo_simplex_simplex_lp_dual_iteration_limit() {
+    let c = vec![3., 1., 2.];
+    let a = vec![vec![1., 1., 3.], vec![2., 2., 5.], vec![4., 1., 2.]];
+    let b = vec![30., 24., 36.];
+    let standard_form = StandardFormLP::new(c, a, b, None).unwrap();
+    let mut slack_form = standard_form.into_slack_form().unwrap();
+    simplex_lp_chvatal(&mut slack_form).unwrap();
+
+    // x_1 <= 6 cuts the current optimal solution (8, 4, 0)
+    slack_form.add_constraint(vec![1., 0., 0.], 6.).unwrap();
+    slack_form.set_options(SolverOptions::new().set_iteration_limit(0));
+    let simplex_result = simplex_lp_dual(&mut slack_form).unwrap();
+
+    assert_eq!(
+        simplex_result,
+        SimplexResult::Interrupted(
+            Interruption::IterationLimit,
+            vec![8., 4., 0.]
+        )
+    );
+
+    slack_form.set_options(SolverOptions::new());
+    let simplex_result = simplex_lp_dual(&mut slack_form).unwrap();
+
+    assert_optimal(&simplex_result, &[6., 6., 0.]);
+}
+
+#[test]
+fn algo_simplex_simplex_lp_chvatal_iteration_callback() {
+    let c = vec![3., 1., 2.];
+    let a = vec![vec![1., 1., 3.], vec![2., 2., 5.], vec![4., 1., 2.]];
+    let b = vec![30., 24., 36.];
+    let iterations = Arc::new(Mutex::new(Vec::new()));
+    let shared = iterations.clone();
+    let callback = IterationCallback::new(move |info: &IterationInfo| {
+        shared.lock().unwrap().push(info.clone());
+    });
+    let mut slack_form = StandardFormLP::new(c, a, b, None)
+        .unwrap()
+        .set_options(SolverOptions::new().set_iteration_callback(callback))
+        .into_slack_form()
+        .unwrap();
+
+    let simplex_result = simplex_lp_chvatal(&mut slack_form).unwrap();
+
+    assert_optimal(&simplex_result, &[8., 4., 0.]);
+    let iterations = iterations.lock().unwrap();
+    // Bland's rule: x_1 enters first, the slack variable of the third row
+    // leaves
+    assert_eq!(iterations[0], IterationInfo::new(1, 27., 0, Some(5), 0.));
+    for (i, info) in iterations.iter().enumerate() {
+        assert_eq!(info.get_iteration(), i + 1);
+        assert_eq!(info.get_primal_infeasibility(), 0.);
+    }
+    let last = iterations.last().unwrap();
+    assert!((last.get_objective() - 28.).abs() < 1e-9);
+}
+
+#[test]
+fn algo_simplex_simplex_lp_dual_iteration_callback() {
+    let c = vec![3., 1., 2.];
+    let a = vec![vec![1., 1., 3.], vec![2., 2., 5.], vec![4., 1., 2.]];
+    let b = vec![30., 24., 36.];
+    let standard_form = StandardFormLP::new(c, a, b, None).unwrap();
+    let mut slack_form = standard_form.into_slack_form().unwrap();
+    simplex_lp_chvatal(&mut slack_form).unwrap();
+
+    let iterations = Arc::new(Mutex::new(Vec::new()));
+    let shared = iterations.clone();
+    let callback = IterationCallback::new(move |info: &IterationInfo| {
+        shared.lock().unwrap().push(info.clone());
+    });
+    slack_form
+        .set_options(SolverOptions::new().set_iteration_callback(callback));
+    // x_1 <= 6 cuts the current optimal solution (8, 4, 0)
+    slack_form.add_constraint(vec![1., 0., 0.], 6.).unwrap();
+    let simplex_result = simplex_lp_dual(&mut slack_form).unwrap();
+
+    assert_optimal(&simplex_result, &[6., 6., 0.]);
+    let iterations = iterations.lock().unwrap();
+    assert!(!iterations.is_empty());
+    // The last pivot of the dual simplex reaches a primal feasible basis
+    assert!(iterations[0].get_leaving().is_some());
+    assert_eq!(iterations.last().unwrap().get_primal_infeasibility(), 0.);
+}
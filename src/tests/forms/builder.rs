@@ -1,5 +1,6 @@
 use super::super::super::forms::builder::StandardFormBuilder;
 use super::super::super::forms::standard::StandardFormLP;
+use super::super::super::types::error::SolverError;
 
 #[test]
 fn forms_builder_standardformbuilder_new_empty() {
@@ -27,14 +28,10 @@ fn forms_builder_standardformbuilder_new() {
     assert_eq!(builder.get_c(), &Some(vec![-1., -2., -3.]));
     assert_eq!(
         builder.get_a(),
-        &Some(vec![
-            vec![1., 3.3, 4.4],
-            vec![-1., -3.3, -4.4],
-            vec![2., 5.3, 2.4],
-            vec![-2., -5.3, -2.4],
-        ])
-    );
-    assert_eq!(builder.get_b(), &vec![10.01, -10.01, 12.3, -12.3]);
+        &Some(vec![vec![1., 3.3, 4.4], vec![2., 5.3, 2.4]])
+    );
+    assert_eq!(builder.get_b(), &vec![10.01, 12.3]);
+    assert_eq!(builder.get_ranges(), &vec![0., 0.]);
     assert_eq!(builder.get_non_negative_indices(), &Some(vec![1]));
     assert_eq!(builder.get_dimension_size(), &Some(3));
 }
@@ -44,7 +41,7 @@ fn forms_builder_standardformbuilder_add_min_objective_empty_vec() {
     let builder = StandardFormBuilder::new();
     let err = builder.add_min_objective(Vec::new()).unwrap_err();
 
-    assert_eq!(err, "It is not possible to add an empty vector.".to_string());
+    assert_eq!(err, SolverError::EmptyInput("added vector".into()));
 }
 
 #[test]
@@ -56,7 +53,12 @@ fn forms_builder_standardformbuilder_add_min_objective_already_added() {
         .add_min_objective(vec![10.0])
         .unwrap_err();
 
-    assert_eq!(err, "Objective function is already added.".to_string());
+    assert_eq!(
+        err,
+        SolverError::InvalidState(
+            "Objective function is already added.".into()
+        )
+    );
 }
 
 #[test]
@@ -66,8 +68,9 @@ fn forms_builder_standardformbuilder_add_min_objective_zero() {
 
     assert_eq!(
         err,
-        "It is not possible to add objective with only zero values."
-            .to_string()
+        SolverError::InvalidInput(
+            "It is not possible to add objective with only zero values.".into()
+        )
     );
 }
 
@@ -78,8 +81,9 @@ fn forms_builder_standardformbuilder_add_min_objective_zeroes() {
 
     assert_eq!(
         err,
-        "It is not possible to add objective with only zero values."
-            .to_string()
+        SolverError::InvalidInput(
+            "It is not possible to add objective with only zero values.".into()
+        )
     );
 }
 
@@ -94,8 +98,7 @@ fn forms_builder_standardformbuilder_add_min_objective_bad_dimension_smaller() {
 
     assert_eq!(
         err,
-        "The added vector does not match the current dimension size."
-            .to_string()
+        SolverError::DimensionMismatch("added vector".into(), 3, 2)
     );
 }
 
@@ -110,8 +113,7 @@ fn forms_builder_standardformbuilder_add_min_objective_bad_dimension_bigger() {
 
     assert_eq!(
         err,
-        "The added vector does not match the current dimension size."
-            .to_string()
+        SolverError::DimensionMismatch("added vector".into(), 3, 4)
     );
 }
 
@@ -125,11 +127,7 @@ fn forms_builder_standardformbuilder_add_min_objective_bad_non_negative_indices(
         .add_min_objective(vec![1.2, 2.3, 3.])
         .unwrap_err();
 
-    assert_eq!(
-        err,
-        "Non negative indices are out of bound of the added vector."
-            .to_string()
-    );
+    assert_eq!(err, SolverError::InvalidColumn(50));
 }
 
 #[test]
@@ -145,6 +143,7 @@ fn forms_builder_standardformbuilder_add_min_objective() {
     assert_eq!(builder.get_b(), &Vec::new());
     assert_eq!(builder.get_non_negative_indices(), &None);
     assert_eq!(builder.get_dimension_size(), &Some(4));
+    assert!(builder.is_min_objective());
 }
 
 #[test]
@@ -178,11 +177,9 @@ fn forms_builder_standardformbuilder_add_min_objective_with_constraint() {
 
     let builder = result.unwrap();
     assert_eq!(builder.get_c(), &Some(vec![-1.2, -2.3, -3., -10.]));
-    assert_eq!(
-        builder.get_a(),
-        &Some(vec![vec![1., 2., 3., 4.], vec![-1., -2., -3., -4.],])
-    );
-    assert_eq!(builder.get_b(), &vec![10., -10.]);
+    assert_eq!(builder.get_a(), &Some(vec![vec![1., 2., 3., 4.]]));
+    assert_eq!(builder.get_b(), &vec![10.]);
+    assert_eq!(builder.get_ranges(), &vec![0.]);
     assert_eq!(builder.get_non_negative_indices(), &None);
     assert_eq!(builder.get_dimension_size(), &Some(4));
 }
@@ -192,7 +189,7 @@ fn forms_builder_standardformbuilder_add_max_objective_empty_vec() {
     let builder = StandardFormBuilder::new();
     let err = builder.add_max_objective(Vec::new()).unwrap_err();
 
-    assert_eq!(err, "It is not possible to add an empty vector.".to_string());
+    assert_eq!(err, SolverError::EmptyInput("added vector".into()));
 }
 
 #[test]
@@ -204,7 +201,12 @@ fn forms_builder_standardformbuilder_add_max_objective_already_added() {
         .add_max_objective(vec![10.0])
         .unwrap_err();
 
-    assert_eq!(err, "Objective function is already added.".to_string());
+    assert_eq!(
+        err,
+        SolverError::InvalidState(
+            "Objective function is already added.".into()
+        )
+    );
 }
 
 #[test]
@@ -214,8 +216,9 @@ fn forms_builder_standardformbuilder_add_max_objective_zero() {
 
     assert_eq!(
         err,
-        "It is not possible to add objective with only zero values."
-            .to_string()
+        SolverError::InvalidInput(
+            "It is not possible to add objective with only zero values.".into()
+        )
     );
 }
 
@@ -226,8 +229,9 @@ fn forms_builder_standardformbuilder_add_max_objective_zeroes() {
 
     assert_eq!(
         err,
-        "It is not possible to add objective with only zero values."
-            .to_string()
+        SolverError::InvalidInput(
+            "It is not possible to add objective with only zero values.".into()
+        )
     );
 }
 
@@ -242,8 +246,7 @@ fn forms_builder_standardformbuilder_add_max_objective_bad_dimension_smaller() {
 
     assert_eq!(
         err,
-        "The added vector does not match the current dimension size."
-            .to_string()
+        SolverError::DimensionMismatch("added vector".into(), 3, 2)
     );
 }
 
@@ -258,8 +261,7 @@ fn forms_builder_standardformbuilder_add_max_objective_bad_dimension_bigger() {
 
     assert_eq!(
         err,
-        "The added vector does not match the current dimension size."
-            .to_string()
+        SolverError::DimensionMismatch("added vector".into(), 3, 4)
     );
 }
 
@@ -273,11 +275,7 @@ fn forms_builder_standardformbuilder_add_max_objective_bad_non_negative_indices(
         .add_max_objective(vec![1.2, 2.3, 3.])
         .unwrap_err();
 
-    assert_eq!(
-        err,
-        "Non negative indices are out of bound of the added vector."
-            .to_string()
-    );
+    assert_eq!(err, SolverError::InvalidColumn(50));
 }
 
 #[test]
@@ -326,11 +324,9 @@ fn forms_builder_standardformbuilder_add_max_objective_with_constraint() {
 
     let builder = result.unwrap();
     assert_eq!(builder.get_c(), &Some(vec![1.2, 2.3, 3., 10.]));
-    assert_eq!(
-        builder.get_a(),
-        &Some(vec![vec![1., 2., 3., 4.], vec![-1., -2., -3., -4.],])
-    );
-    assert_eq!(builder.get_b(), &vec![10., -10.]);
+    assert_eq!(builder.get_a(), &Some(vec![vec![1., 2., 3., 4.]]));
+    assert_eq!(builder.get_b(), &vec![10.]);
+    assert_eq!(builder.get_ranges(), &vec![0.]);
     assert_eq!(builder.get_non_negative_indices(), &None);
     assert_eq!(builder.get_dimension_size(), &Some(4));
 }
@@ -342,8 +338,7 @@ fn forms_builder_standardformbuilder_add_non_negative_indices_empty() {
 
     assert_eq!(
         err,
-        "It is not allowed to add an empty array of non negative indices."
-            .to_string()
+        SolverError::EmptyInput("array of non negative indices".into())
     );
 }
 
@@ -354,9 +349,11 @@ fn forms_builder_standardformbuilder_add_non_negative_indices_not_uniq() {
 
     assert_eq!(
         err,
-        "Non negative indices vector must be unique and sorted in ascending \
-         order."
-            .to_string()
+        SolverError::InvalidInput(
+            "Non negative indices vector must be unique and sorted in \
+             ascending order."
+                .into()
+        )
     );
 }
 
@@ -367,9 +364,11 @@ fn forms_builder_standardformbuilder_add_non_negative_indices_not_sorted() {
 
     assert_eq!(
         err,
-        "Non negative indices vector must be unique and sorted in ascending \
-         order."
-            .to_string()
+        SolverError::InvalidInput(
+            "Non negative indices vector must be unique and sorted in \
+             ascending order."
+                .into()
+        )
     );
 }
 
@@ -382,11 +381,7 @@ fn forms_builder_standardformbuilder_add_non_negative_indices_bad_dimension() {
         .add_non_negative_indices(vec![1, 10])
         .unwrap_err();
 
-    assert_eq!(
-        err,
-        "Non negative indices are out of bound of the dimension size."
-            .to_string()
-    );
+    assert_eq!(err, SolverError::InvalidColumn(10));
 }
 
 #[test]
@@ -398,7 +393,12 @@ fn forms_builder_standardformbuilder_add_non_negative_indices_already_set() {
         .add_non_negative_indices(vec![2])
         .unwrap_err();
 
-    assert_eq!(err, "Non negative indices have already been set.".to_string());
+    assert_eq!(
+        err,
+        SolverError::InvalidState(
+            "Non negative indices have already been set.".into()
+        )
+    );
 }
 
 #[test]
@@ -434,12 +434,117 @@ fn forms_builder_standardformbuilder_add_non_negative_indices_with_dimension() {
     assert_eq!(builder.get_dimension_size(), &Some(4));
 }
 
+#[test]
+fn forms_builder_standardformbuilder_add_variable_bounds_lower_greater_than_upper(
+) {
+    let builder = StandardFormBuilder::new();
+    let err = builder.add_variable_bounds(0, 2., 1.).unwrap_err();
+
+    assert_eq!(
+        err,
+        SolverError::InvalidInput(
+            "Lower bound is greater than upper bound (2 > 1)".into()
+        )
+    );
+}
+
+#[test]
+fn forms_builder_standardformbuilder_add_variable_bounds_positive_infinite_lower(
+) {
+    let builder = StandardFormBuilder::new();
+    let err = builder
+        .add_variable_bounds(0, f64::INFINITY, f64::INFINITY)
+        .unwrap_err();
+
+    assert_eq!(
+        err,
+        SolverError::InvalidInput("Lower bound should not be infinity.".into())
+    );
+}
+
+#[test]
+fn forms_builder_standardformbuilder_add_variable_bounds_negative_infinite_lower(
+) {
+    let builder = StandardFormBuilder::new()
+        .add_variable_bounds(0, f64::NEG_INFINITY, 3.)
+        .unwrap()
+        .add_variable_bounds(1, f64::NEG_INFINITY, f64::INFINITY)
+        .unwrap();
+
+    assert_eq!(
+        builder.get_variable_bounds(),
+        &vec![
+            (0, f64::NEG_INFINITY, 3.),
+            (1, f64::NEG_INFINITY, f64::INFINITY)
+        ]
+    );
+}
+
+#[test]
+fn forms_builder_standardformbuilder_add_variable_bounds_bad_dimension() {
+    let builder = StandardFormBuilder::new();
+    let err = builder
+        .add_max_objective(vec![1.2, 2.3, 3., 10.])
+        .unwrap()
+        .add_variable_bounds(4, 0., 1.)
+        .unwrap_err();
+
+    assert_eq!(err, SolverError::InvalidColumn(4));
+}
+
+#[test]
+fn forms_builder_standardformbuilder_add_variable_bounds_then_bad_dimension() {
+    let builder = StandardFormBuilder::new();
+    let err = builder
+        .add_variable_bounds(4, 0., 1.)
+        .unwrap()
+        .add_max_objective(vec![1.2, 2.3, 3., 10.])
+        .unwrap_err();
+
+    assert_eq!(err, SolverError::InvalidColumn(4));
+}
+
+#[test]
+fn forms_builder_standardformbuilder_add_variable_bounds_already_set() {
+    let builder = StandardFormBuilder::new();
+    let err = builder
+        .add_variable_bounds(1, 0., 1.)
+        .unwrap()
+        .add_variable_bounds(1, 0., 2.)
+        .unwrap_err();
+
+    assert_eq!(
+        err,
+        SolverError::InvalidState(
+            "Bounds have already been set for the variable 1.".into()
+        )
+    );
+}
+
+#[test]
+fn forms_builder_standardformbuilder_add_variable_bounds() {
+    let builder = StandardFormBuilder::new()
+        .add_max_objective(vec![1.2, 2.3, 3., 10.])
+        .unwrap()
+        .add_variable_bounds(1, -1., 1.)
+        .unwrap()
+        .add_variable_bounds(3, 2., f64::INFINITY)
+        .unwrap();
+
+    assert_eq!(
+        builder.get_variable_bounds(),
+        &vec![(1, -1., 1.), (3, 2., f64::INFINITY)]
+    );
+    assert_eq!(builder.get_a(), &None);
+    assert_eq!(builder.get_dimension_size(), &Some(4));
+}
+
 #[test]
 fn forms_builder_standardformbuilder_add_equality_constraint_empty() {
     let builder = StandardFormBuilder::new();
     let err = builder.add_equality_constraint(Vec::new(), 10.0).unwrap_err();
 
-    assert_eq!(err, "It is not possible to add an empty vector.".to_string());
+    assert_eq!(err, SolverError::EmptyInput("added vector".into()));
 }
 
 #[test]
@@ -454,8 +559,7 @@ fn forms_builder_standardformbuilder_add_equality_constraint_mismatch_dim_case_1
 
     assert_eq!(
         err,
-        "The added vector does not match the current dimension size."
-            .to_string()
+        SolverError::DimensionMismatch("added vector".into(), 3, 2)
     );
 }
 
@@ -471,8 +575,7 @@ fn forms_builder_standardformbuilder_add_equality_constraint_mismatch_dim_case_2
 
     assert_eq!(
         err,
-        "The added vector does not match the current dimension size."
-            .to_string()
+        SolverError::DimensionMismatch("added vector".into(), 3, 2)
     );
 }
 
@@ -486,11 +589,7 @@ fn forms_builder_standardformbuilder_add_equality_constraint_bad_non_negative_in
         .add_equality_constraint(vec![1., 2.], 12.0)
         .unwrap_err();
 
-    assert_eq!(
-        err,
-        "Non negative indices are out of bound of the added vector."
-            .to_string()
-    );
+    assert_eq!(err, SolverError::InvalidColumn(10));
 }
 
 #[test]
@@ -500,8 +599,10 @@ fn forms_builder_standardformbuilder_add_equality_constraint_zeroes() {
 
     assert_eq!(
         err,
-        "It is not possible to add constraint with only zero values."
-            .to_string()
+        SolverError::InvalidInput(
+            "It is not possible to add constraint with only zero values."
+                .into()
+        )
     );
 }
 
@@ -514,11 +615,9 @@ fn forms_builder_standardformbuilder_add_equality_constraint_once() {
 
     let builder = result.unwrap();
     assert_eq!(builder.get_c(), &None);
-    assert_eq!(
-        builder.get_a(),
-        &Some(vec![vec![1., 2., 3.], vec![-1., -2., -3.],])
-    );
-    assert_eq!(builder.get_b(), &vec![12.0, -12.0]);
+    assert_eq!(builder.get_a(), &Some(vec![vec![1., 2., 3.]]));
+    assert_eq!(builder.get_b(), &vec![12.0]);
+    assert_eq!(builder.get_ranges(), &vec![0.]);
     assert_eq!(builder.get_non_negative_indices(), &None);
     assert_eq!(builder.get_dimension_size(), &Some(3));
 }
@@ -537,14 +636,10 @@ fn forms_builder_standardformbuilder_add_equality_constraint_twice() {
     assert_eq!(builder.get_c(), &None);
     assert_eq!(
         builder.get_a(),
-        &Some(vec![
-            vec![1., 2., 3.],
-            vec![-1., -2., -3.],
-            vec![4., 5., 6.],
-            vec![-4., -5., -6.],
-        ])
+        &Some(vec![vec![1., 2., 3.], vec![4., 5., 6.]])
     );
-    assert_eq!(builder.get_b(), &vec![12.0, -12.0, 7., -7.]);
+    assert_eq!(builder.get_b(), &vec![12.0, 7.]);
+    assert_eq!(builder.get_ranges(), &vec![0., 0.]);
     assert_eq!(builder.get_non_negative_indices(), &None);
     assert_eq!(builder.get_dimension_size(), &Some(3));
 }
@@ -567,14 +662,10 @@ fn forms_builder_standardformbuilder_add_equality_constraint_twice_with_all() {
     assert_eq!(builder.get_c(), &Some(vec![0.1, 0.2, 0.3]));
     assert_eq!(
         builder.get_a(),
-        &Some(vec![
-            vec![1., 2., 3.],
-            vec![-1., -2., -3.],
-            vec![4., 5., 6.],
-            vec![-4., -5., -6.],
-        ])
+        &Some(vec![vec![1., 2., 3.], vec![4., 5., 6.]])
     );
-    assert_eq!(builder.get_b(), &vec![12.0, -12.0, 7., -7.]);
+    assert_eq!(builder.get_b(), &vec![12.0, 7.]);
+    assert_eq!(builder.get_ranges(), &vec![0., 0.]);
     assert_eq!(builder.get_non_negative_indices(), &Some(vec![1]));
     assert_eq!(builder.get_dimension_size(), &Some(3));
 }
@@ -586,7 +677,7 @@ fn forms_builder_standardformbuilder_add_less_than_or_equal_constraint_empty() {
         .add_less_than_or_equal_constraint(Vec::new(), 10.0)
         .unwrap_err();
 
-    assert_eq!(err, "It is not possible to add an empty vector.".to_string());
+    assert_eq!(err, SolverError::EmptyInput("added vector".into()));
 }
 
 #[test]
@@ -601,8 +692,7 @@ fn forms_builder_standardformbuilder_add_less_than_or_equal_constraint_mismatch_
 
     assert_eq!(
         err,
-        "The added vector does not match the current dimension size."
-            .to_string()
+        SolverError::DimensionMismatch("added vector".into(), 3, 2)
     );
 }
 
@@ -618,8 +708,7 @@ fn forms_builder_standardformbuilder_add_less_than_or_equal_constraint_mismatch_
 
     assert_eq!(
         err,
-        "The added vector does not match the current dimension size."
-            .to_string()
+        SolverError::DimensionMismatch("added vector".into(), 3, 2)
     );
 }
 
@@ -633,11 +722,7 @@ fn forms_builder_standardformbuilder_add_less_than_or_equal_constraint_bad_non_n
         .add_less_than_or_equal_constraint(vec![1., 2.], 12.0)
         .unwrap_err();
 
-    assert_eq!(
-        err,
-        "Non negative indices are out of bound of the added vector."
-            .to_string()
-    );
+    assert_eq!(err, SolverError::InvalidColumn(10));
 }
 
 #[test]
@@ -650,8 +735,10 @@ fn forms_builder_standardformbuilder_add_less_than_or_equal_constraint_zeroes()
 
     assert_eq!(
         err,
-        "It is not possible to add constraint with only zero values."
-            .to_string()
+        SolverError::InvalidInput(
+            "It is not possible to add constraint with only zero values."
+                .into()
+        )
     );
 }
 
@@ -726,7 +813,7 @@ fn forms_builder_standardformbuilder_add_greater_than_or_equal_constraint_empty(
         .add_greater_than_or_equal_constraint(Vec::new(), 10.0)
         .unwrap_err();
 
-    assert_eq!(err, "It is not possible to add an empty vector.".to_string());
+    assert_eq!(err, SolverError::EmptyInput("added vector".into()));
 }
 
 #[test]
@@ -741,8 +828,7 @@ fn forms_builder_standardformbuilder_add_greater_than_or_equal_constraint_mismat
 
     assert_eq!(
         err,
-        "The added vector does not match the current dimension size."
-            .to_string()
+        SolverError::DimensionMismatch("added vector".into(), 3, 2)
     );
 }
 
@@ -758,8 +844,7 @@ fn forms_builder_standardformbuilder_add_greater_than_or_equal_constraint_mismat
 
     assert_eq!(
         err,
-        "The added vector does not match the current dimension size."
-            .to_string()
+        SolverError::DimensionMismatch("added vector".into(), 3, 2)
     );
 }
 
@@ -773,11 +858,7 @@ fn forms_builder_standardformbuilder_add_greater_than_or_equal_constraint_bad_no
         .add_greater_than_or_equal_constraint(vec![1., 2.], 12.0)
         .unwrap_err();
 
-    assert_eq!(
-        err,
-        "Non negative indices are out of bound of the added vector."
-            .to_string()
-    );
+    assert_eq!(err, SolverError::InvalidColumn(10));
 }
 
 #[test]
@@ -790,8 +871,10 @@ fn forms_builder_standardformbuilder_add_greater_than_or_equal_constraint_zeroes
 
     assert_eq!(
         err,
-        "It is not possible to add constraint with only zero values."
-            .to_string()
+        SolverError::InvalidInput(
+            "It is not possible to add constraint with only zero values."
+                .into()
+        )
     );
 }
 
@@ -860,15 +943,93 @@ fn forms_builder_standardformbuilder_add_greater_than_or_equal_constraint_twice_
     assert_eq!(builder.get_dimension_size(), &Some(3));
 }
 
+#[test]
+fn forms_builder_standardformbuilder_add_range_constraint_empty() {
+    let builder = StandardFormBuilder::new();
+    let err = builder.add_range_constraint(Vec::new(), 1., 2.).unwrap_err();
+
+    assert_eq!(err, SolverError::EmptyInput("added vector".into()));
+}
+
+#[test]
+fn forms_builder_standardformbuilder_add_range_constraint_lower_greater() {
+    let builder = StandardFormBuilder::new();
+    let err =
+        builder.add_range_constraint(vec![1., 2., 3.], 3., 2.).unwrap_err();
+
+    assert_eq!(
+        err,
+        SolverError::InvalidInput(
+            "Lower bound is greater than upper bound (3 > 2)".into()
+        )
+    );
+}
+
+#[test]
+fn forms_builder_standardformbuilder_add_range_constraint_nan() {
+    let builder = StandardFormBuilder::new();
+    let err = builder
+        .add_range_constraint(vec![1., 2., 3.], f64::NAN, 2.)
+        .unwrap_err();
+
+    assert_eq!(
+        err,
+        SolverError::InvalidInput("Range bounds should be numbers.".into())
+    );
+}
+
+#[test]
+fn forms_builder_standardformbuilder_add_range_constraint_infinite_bounds() {
+    let builder = StandardFormBuilder::new();
+    let err = builder
+        .add_range_constraint(
+            vec![1., 2., 3.],
+            f64::NEG_INFINITY,
+            f64::INFINITY,
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err,
+        SolverError::InvalidInput(
+            "At least one of the range bounds should be finite.".into()
+        )
+    );
+}
+
+#[test]
+fn forms_builder_standardformbuilder_add_range_constraint() {
+    let builder = StandardFormBuilder::new();
+    let result = builder
+        .add_range_constraint(vec![1., 2., 3.], -1., 12.)
+        .unwrap()
+        .add_range_constraint(vec![4., 5., 6.], f64::NEG_INFINITY, 7.)
+        .unwrap()
+        .add_range_constraint(vec![7., 8., 9.], 2., f64::INFINITY);
+
+    assert!(result.is_ok());
+
+    let builder = result.unwrap();
+    assert_eq!(
+        builder.get_a(),
+        &Some(vec![vec![1., 2., 3.], vec![4., 5., 6.], vec![-7., -8., -9.]])
+    );
+    assert_eq!(builder.get_b(), &vec![12., 7., -2.]);
+    assert_eq!(builder.get_ranges(), &vec![13., f64::INFINITY, f64::INFINITY]);
+    assert_eq!(builder.get_dimension_size(), &Some(3));
+}
+
 #[test]
 fn forms_builder_standardformbuilder_build_missing_objective() {
     let err = StandardFormBuilder::new().build().unwrap_err();
 
     assert_eq!(
         err,
-        "Cannot build standard form for LP problem, missing objective \
-         function."
-            .to_string()
+        SolverError::InvalidState(
+            "Cannot build standard form for LP problem, missing objective \
+             function."
+                .into()
+        )
     );
 }
 
@@ -882,8 +1043,10 @@ fn forms_builder_standardformbuilder_build_missing_constraints() {
 
     assert_eq!(
         err,
-        "Cannot build standard form for LP problem, missing constraints."
-            .to_string()
+        SolverError::InvalidState(
+            "Cannot build standard form for LP problem, missing constraints."
+                .into()
+        )
     );
 }
 
@@ -891,7 +1054,6 @@ fn forms_builder_standardformbuilder_build_missing_constraints() {
 fn forms_builder_standardformbuilder_build_equality_constraint_with_max_obj() {
     let c = vec![1., 2., 3., 4., 5.];
     let row = vec![2.2, 3.3, 4.4, 19.2, 10.08];
-    let minus_row = vec![-2.2, -3.3, -4.4, -19.2, -10.08];
     let b = 2.2;
     let standard_form = StandardFormBuilder::new()
         .add_max_objective(c.clone())
@@ -901,9 +1063,11 @@ fn forms_builder_standardformbuilder_build_equality_constraint_with_max_obj() {
         .build()
         .unwrap();
 
-    let expected =
-        StandardFormLP::new(c, vec![row, minus_row], vec![b, -b], None)
-            .unwrap();
+    // A single row, with a range of 0.0
+    let expected = StandardFormLP::new(c, vec![row], vec![b], None)
+        .unwrap()
+        .add_row_range(0, 0.)
+        .unwrap();
 
     assert_eq!(standard_form, expected);
 }
@@ -922,8 +1086,9 @@ fn forms_builder_standardformbuilder_build_lt_eq_constraint_with_min_obj() {
         .build()
         .unwrap();
 
-    let expected =
-        StandardFormLP::new(minus_c, vec![row], vec![b], None).unwrap();
+    let expected = StandardFormLP::new(minus_c, vec![row], vec![b], None)
+        .unwrap()
+        .set_min_objective();
 
     assert_eq!(standard_form, expected);
 }
@@ -944,7 +1109,9 @@ fn forms_builder_standardformbuilder_build_gt_eq_constraint_with_min_obj() {
         .unwrap();
 
     let expected =
-        StandardFormLP::new(minus_c, vec![minus_row], vec![-b], None).unwrap();
+        StandardFormLP::new(minus_c, vec![minus_row], vec![-b], None)
+            .unwrap()
+            .set_min_objective();
 
     assert_eq!(standard_form, expected);
 }
@@ -954,9 +1121,8 @@ fn forms_builder_standardformbuilder_build_equality_constraint_with_nni() {
     let c = vec![1., 2., 3., 4., 5.];
     let row = vec![2.2, 3.3, 4.4, 19.2, 10.08];
     let b = 2.2;
-    let expect_minus_c = vec![-1., -2., -3., -4., -5., -3.];
-    let expect_row = vec![2.2, 3.3, 4.4, 19.2, 10.08, 4.4];
-    let expect_minus_row = vec![-2.2, -3.3, -4.4, -19.2, -10.08, -4.4];
+    let expect_minus_c = vec![-1., -2., -3., -4., -5.];
+    let expect_row = vec![2.2, 3.3, 4.4, 19.2, 10.08];
     let standard_form = StandardFormBuilder::new()
         .add_non_negative_indices(vec![2])
         .unwrap()
@@ -969,11 +1135,144 @@ fn forms_builder_standardformbuilder_build_equality_constraint_with_nni() {
 
     let expected = StandardFormLP::new(
         expect_minus_c,
-        vec![expect_row, expect_minus_row],
-        vec![b, -b],
+        vec![expect_row],
+        vec![b],
         Some(vec![2]),
     )
-    .unwrap();
+    .unwrap()
+    .add_row_range(0, 0.)
+    .unwrap()
+    .set_min_objective();
 
     assert_eq!(standard_form, expected);
 }
+
+#[test]
+fn forms_builder_standardformbuilder_build_with_variable_bounds() {
+    let c = vec![1., 2., 3.];
+    let row = vec![2.2, 3.3, 4.4];
+    let b = 2.2;
+    let standard_form = StandardFormBuilder::new()
+        .add_max_objective(c.clone())
+        .unwrap()
+        .add_variable_bounds(2, 1., 5.)
+        .unwrap()
+        .add_less_than_or_equal_constraint(row.clone(), b)
+        .unwrap()
+        .build()
+        .unwrap();
+
+    // Bounds are not rows of the matrix 'a'
+    let expected = StandardFormLP::new(c, vec![row], vec![b], None)
+        .unwrap()
+        .add_variable_bounds(2, 1., 5.)
+        .unwrap();
+
+    assert_eq!(standard_form, expected);
+}
+
+#[test]
+fn forms_builder_standardformbuilder_build_variable_bounds_with_nni() {
+    let mut standard_form = StandardFormBuilder::new()
+        .add_variable_bounds(1, -2., 5.)
+        .unwrap()
+        .add_non_negative_indices(vec![0, 1])
+        .unwrap()
+        .add_max_objective(vec![1., -2., 3.])
+        .unwrap()
+        .add_less_than_or_equal_constraint(vec![2., 1., 4.], 4.)
+        .unwrap()
+        .add_less_than_or_equal_constraint(vec![-1., 0., 0.], 0.)
+        .unwrap()
+        .build()
+        .unwrap();
+
+    // The bounded variable is no longer free, whatever the order of the calls
+    assert_eq!(standard_form.get_non_negative_indices(), &Some(vec![0]));
+    assert_eq!(standard_form.get_lower(), &vec![f64::NEG_INFINITY, -2., 0.]);
+    assert_eq!(
+        standard_form.get_upper(),
+        &vec![f64::INFINITY, 5., f64::INFINITY]
+    );
+
+    // x_1 stops at its lower bound instead of decreasing without limit
+    standard_form.solve().unwrap();
+    assert_eq!(standard_form.get_x(), vec![0., -2., 1.5]);
+}
+
+#[test]
+fn forms_builder_standardformbuilder_build_range_constraint() {
+    let c = vec![1., 2., 3.];
+    let row = vec![2.2, 3.3, 4.4];
+    let standard_form = StandardFormBuilder::new()
+        .add_max_objective(c.clone())
+        .unwrap()
+        .add_range_constraint(row.clone(), 1.5, 2.2)
+        .unwrap()
+        .build()
+        .unwrap();
+
+    let expected = StandardFormLP::new(c, vec![row], vec![2.2], None)
+        .unwrap()
+        .add_row_range(0, 2.2 - 1.5)
+        .unwrap();
+
+    assert_eq!(standard_form, expected);
+}
+
+#[test]
+fn forms_builder_standardformbuilder_build_with_integer_indices() {
+    let c = vec![1., 2., 3.];
+    let row = vec![2.2, 3.3, 4.4];
+    let builder = StandardFormBuilder::new()
+        .add_integer_indices(vec![0, 2])
+        .unwrap()
+        .add_max_objective(c.clone())
+        .unwrap()
+        .add_less_than_or_equal_constraint(row.clone(), 2.2)
+        .unwrap();
+
+    assert_eq!(builder.get_integer_indices(), &Some(vec![0, 2]));
+
+    let standard_form = builder.build().unwrap();
+    let expected = StandardFormLP::new(c, vec![row], vec![2.2], None)
+        .unwrap()
+        .add_integer_indices(vec![0, 2])
+        .unwrap();
+
+    assert_eq!(standard_form, expected);
+    assert_eq!(standard_form.get_integer_indices(), &vec![0, 2]);
+}
+
+#[test]
+fn forms_builder_standardformbuilder_add_integer_indices_wrong() {
+    let err =
+        StandardFormBuilder::new().add_integer_indices(vec![2, 1]).unwrap_err();
+    assert_eq!(
+        err,
+        SolverError::InvalidInput(
+            "Integer indices vector must be unique and sorted in ascending \
+             order."
+                .into()
+        )
+    );
+
+    let err = StandardFormBuilder::new()
+        .add_integer_indices(vec![3])
+        .unwrap()
+        .add_max_objective(vec![1., 2., 3.])
+        .unwrap_err();
+    assert_eq!(err, SolverError::InvalidColumn(3));
+
+    let err = StandardFormBuilder::new()
+        .add_integer_indices(vec![0])
+        .unwrap()
+        .add_integer_indices(vec![1])
+        .unwrap_err();
+    assert_eq!(
+        err,
+        SolverError::InvalidState(
+            "Integer indices have already been set.".into()
+        )
+    );
+}
@@ -1,3 +1,5 @@
 mod builder;
+mod options;
 mod slack;
+mod solution;
 mod standard;
@@ -0,0 +1,153 @@
+use std::time::Duration;
+
+use super::super::super::forms::options::{
+    CancelFlag, IterationCallback, MilpOptions, NodeSelection, SolverOptions,
+};
+use super::super::super::types::error::SolverError;
+
+#[test]
+fn forms_options_solveroptions_default() {
+    let options = SolverOptions::default();
+
+    assert_eq!(options, SolverOptions::new());
+    assert_eq!(options.get_primal_feasibility_tolerance(), 1e-9);
+    assert_eq!(options.get_dual_feasibility_tolerance(), 1e-9);
+    assert_eq!(options.get_pivot_tolerance(), 1e-9);
+    assert!(!options.is_harris_ratio_test());
+    assert!(!options.is_perturbation());
+    assert_eq!(options.get_iteration_limit(), None);
+    assert_eq!(options.get_time_limit(), None);
+    assert_eq!(options.get_cancel_flag(), None);
+    assert!(options.get_iteration_callback().is_none());
+}
+
+#[test]
+fn forms_options_solveroptions_set_tolerances() {
+    let options = SolverOptions::new()
+        .set_primal_feasibility_tolerance(1e-7)
+        .unwrap()
+        .set_dual_feasibility_tolerance(1e-6)
+        .unwrap()
+        .set_pivot_tolerance(0.)
+        .unwrap();
+
+    assert_eq!(options.get_primal_feasibility_tolerance(), 1e-7);
+    assert_eq!(options.get_dual_feasibility_tolerance(), 1e-6);
+    assert_eq!(options.get_pivot_tolerance(), 0.);
+}
+
+#[test]
+fn forms_options_solveroptions_set_wrong_tolerances() {
+    let expected = SolverError::InvalidInput(
+        "Tolerance should be a non negative number.".into(),
+    );
+
+    assert_eq!(
+        SolverOptions::new()
+            .set_primal_feasibility_tolerance(-1e-9)
+            .unwrap_err(),
+        expected
+    );
+    assert_eq!(
+        SolverOptions::new()
+            .set_dual_feasibility_tolerance(f64::NAN)
+            .unwrap_err(),
+        expected
+    );
+    assert_eq!(
+        SolverOptions::new().set_pivot_tolerance(f64::INFINITY).unwrap_err(),
+        expected
+    );
+}
+
+#[test]
+fn forms_options_solveroptions_set_limits() {
+    let flag = CancelFlag::new();
+    let options = SolverOptions::new()
+        .set_iteration_limit(100)
+        .set_time_limit(Duration::from_secs(2))
+        .set_cancel_flag(flag.clone());
+
+    assert_eq!(options.get_iteration_limit(), Some(100));
+    assert_eq!(options.get_time_limit(), Some(Duration::from_secs(2)));
+    assert_eq!(options.get_cancel_flag(), Some(&flag));
+    assert_ne!(
+        options,
+        SolverOptions::new().set_cancel_flag(CancelFlag::new())
+    );
+}
+
+#[test]
+fn forms_options_cancelflag_cancel() {
+    let flag = CancelFlag::new();
+    let shared = flag.clone();
+
+    assert!(!flag.is_cancelled());
+    shared.cancel();
+    assert!(flag.is_cancelled());
+    assert!(!CancelFlag::new().is_cancelled());
+}
+
+#[test]
+fn forms_options_solveroptions_set_iteration_callback() {
+    let callback = IterationCallback::new(|_| {});
+    let options = SolverOptions::new().set_iteration_callback(callback.clone());
+
+    assert_eq!(options.get_iteration_callback(), Some(&callback));
+    assert_eq!(options.clone(), options);
+    assert_ne!(
+        options,
+        SolverOptions::new()
+            .set_iteration_callback(IterationCallback::new(|_| {}))
+    );
+}
+
+#[test]
+fn forms_options_milpoptions_default() {
+    let options = MilpOptions::default();
+
+    assert_eq!(options, MilpOptions::new());
+    assert_eq!(options.get_integrality_tolerance(), 1e-6);
+    assert_eq!(options.get_mip_gap_tolerance(), 1e-9);
+    assert_eq!(options.get_node_selection(), NodeSelection::DepthFirst);
+    assert_eq!(options.get_cut_rounds(), 0);
+}
+
+#[test]
+fn forms_options_milpoptions_set_tolerances() {
+    let options = MilpOptions::new()
+        .set_integrality_tolerance(1e-5)
+        .unwrap()
+        .set_mip_gap_tolerance(1e-4)
+        .unwrap();
+
+    assert_eq!(options.get_integrality_tolerance(), 1e-5);
+    assert_eq!(options.get_mip_gap_tolerance(), 1e-4);
+}
+
+#[test]
+fn forms_options_milpoptions_set_wrong_tolerances() {
+    let expected = SolverError::InvalidInput(
+        "Tolerance should be a non negative number.".into(),
+    );
+
+    assert_eq!(
+        MilpOptions::new().set_integrality_tolerance(f64::NAN).unwrap_err(),
+        expected
+    );
+    assert_eq!(
+        MilpOptions::new().set_mip_gap_tolerance(-1.).unwrap_err(),
+        expected
+    );
+}
+
+#[test]
+fn forms_options_milpoptions_set_node_selection() {
+    let options = MilpOptions::new()
+        .set_node_selection(NodeSelection::BestBound)
+        .set_cut_rounds(2);
+
+    assert_eq!(options.get_node_selection(), NodeSelection::BestBound);
+    assert_eq!(options.get_cut_rounds(), 2);
+    assert_ne!(options, MilpOptions::new());
+}
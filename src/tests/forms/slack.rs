@@ -1,4 +1,34 @@
-use super::super::super::forms::slack::SlackFormLP;
+use super::super::super::algo::simplex::simplex_lp_chvatal;
+use super::super::super::forms::options::SolverOptions;
+use super::super::super::forms::slack::{SimplexRound, SlackFormLP};
+use super::super::super::forms::standard::StandardFormLP;
+use super::super::super::types::error::SolverError;
+
+fn assert_ranges(ranges: &[(f64, f64)], expected: &[(f64, f64)]) {
+    assert_eq!(ranges.len(), expected.len());
+    for ((l1, h1), (l2, h2)) in ranges.iter().zip(expected.iter()) {
+        let close = |v1: &f64, v2: &f64| v1 == v2 || (v1 - v2).abs() < 1e-9;
+        assert!(
+            close(l1, l2) && close(h1, h2),
+            "{:?} != {:?}",
+            ranges,
+            expected
+        );
+    }
+}
+
+fn optimal_slack_form() -> SlackFormLP {
+    // z = 28 - x_3 / 6 - x_5 / 6 - 2 . x_6 / 3 at the optimum
+    let c = vec![3., 1., 2.];
+    let a = vec![vec![1., 1., 3.], vec![2., 2., 5.], vec![4., 1., 2.]];
+    let b = vec![30., 24., 36.];
+
+    let standard_form = StandardFormLP::new(c, a, b, None).unwrap();
+    let mut slack_form = standard_form.into_slack_form().unwrap();
+    simplex_lp_chvatal(&mut slack_form).unwrap();
+
+    slack_form
+}
 
 #[test]
 fn forms_slack_slackformlp_empty_matrix_A() {
@@ -8,7 +38,7 @@ fn forms_slack_slackformlp_empty_matrix_A() {
 
     let err = SlackFormLP::new(A, b, c).unwrap_err();
 
-    assert_eq!(err, "Matrix 'A' should not be empty".to_string());
+    assert_eq!(err, SolverError::EmptyInput("matrix 'A'".into()));
 }
 
 #[test]
@@ -19,7 +49,7 @@ fn forms_slack_slackformlp_empty_vector_b() {
 
     let err = SlackFormLP::new(A, b, c).unwrap_err();
 
-    assert_eq!(err, "Vector 'b' should not be empty".to_string());
+    assert_eq!(err, SolverError::EmptyInput("vector 'b'".into()));
 }
 
 #[test]
@@ -30,7 +60,7 @@ fn forms_slack_slackformlp_empty_vector_c() {
 
     let err = SlackFormLP::new(A, b, c).unwrap_err();
 
-    assert_eq!(err, "Vector 'c' should not be empty".to_string());
+    assert_eq!(err, SolverError::EmptyInput("vector 'c'".into()));
 }
 
 #[test]
@@ -43,8 +73,7 @@ fn forms_slack_slackformlp_mismatch_matrix_A_vector_c() {
 
     assert_eq!(
         err,
-        "Matrix 'A' rows count does not match with vector 'c' size (0 != 2)"
-            .to_string()
+        SolverError::DimensionMismatch("row 0 of the matrix 'A'".into(), 2, 0)
     );
 }
 
@@ -58,8 +87,7 @@ fn forms_slack_slackformlp_mismatch_matrix_A() {
 
     assert_eq!(
         err,
-        "Each and every row in the matrix 'A' should have the same size"
-            .to_string()
+        SolverError::DimensionMismatch("row 1 of the matrix 'A'".into(), 2, 1)
     );
 }
 
@@ -71,11 +99,7 @@ fn forms_slack_slackformlp_mismatch_matrix_A_vector_b() {
 
     let err = SlackFormLP::new(A, b, c).unwrap_err();
 
-    assert_eq!(
-        err,
-        "Matrix 'A' columns count does not match with vector 'b' size (2 != 1)"
-            .to_string()
-    );
+    assert_eq!(err, SolverError::DimensionMismatch("vector 'b'".into(), 2, 1));
 }
 
 #[test]
@@ -89,6 +113,71 @@ fn forms_slack_slackformlp_should_be_created() {
     assert!(slack_form.is_ok());
 }
 
+#[test]
+fn forms_slack_slackformlp_objective_ranging() {
+    let slack_form = optimal_slack_form();
+
+    let ranges = slack_form.objective_ranging().unwrap();
+
+    assert_ranges(
+        &ranges,
+        &[(-2., 1.), (-1. / 16., 2.), (f64::NEG_INFINITY, 1. / 6.)],
+    );
+}
+
+#[test]
+fn forms_slack_slackformlp_rhs_ranging() {
+    let slack_form = optimal_slack_form();
+
+    let ranges = slack_form.rhs_ranging().unwrap();
+
+    assert_ranges(&ranges, &[(-18., f64::INFINITY), (-6., 36.), (-24., 12.)]);
+}
+
+#[test]
+fn forms_slack_slackformlp_ranging_bounded_variable() {
+    // x_1 in [0, 3] is at its upper bound, its cost can increase freely
+    // while x_2 is basic
+    let c = vec![1., 1.];
+    let a = vec![vec![1., 2.]];
+    let b = vec![10.];
+
+    let standard_form = StandardFormLP::new(c, a, b, None)
+        .unwrap()
+        .add_variable_bounds(0, 0., 3.)
+        .unwrap();
+    let mut slack_form = standard_form.into_slack_form().unwrap();
+    simplex_lp_chvatal(&mut slack_form).unwrap();
+
+    assert_ranges(
+        &slack_form.objective_ranging().unwrap(),
+        &[(-0.5, f64::INFINITY), (-1., 1.)],
+    );
+    assert_ranges(&slack_form.rhs_ranging().unwrap(), &[(-7., f64::INFINITY)]);
+}
+
+#[test]
+fn forms_slack_slackformlp_ranging_not_optimal() {
+    let A = vec![vec![-1., -2.]];
+    let b = vec![10.];
+    let c = vec![1., 1.];
+
+    let slack_form = SlackFormLP::new(A, b, c).unwrap();
+
+    assert_eq!(
+        slack_form.objective_ranging(),
+        Err(SolverError::InvalidState(
+            "Sensitivity analysis requires an optimal basis.".into()
+        ))
+    );
+    assert_eq!(
+        slack_form.rhs_ranging(),
+        Err(SolverError::InvalidState(
+            "Sensitivity analysis requires an optimal basis.".into()
+        ))
+    );
+}
+
 #[ignore]
 #[test]
 fn forms_slack_slackformlp_just_print() {
@@ -99,3 +188,85 @@ fn forms_slack_slackformlp_just_print() {
     let slack_form = SlackFormLP::new(A, b, c).unwrap();
     println!("{}", slack_form);
 }
+
+#[test]
+fn forms_slack_slackformlp_round_off_within_tolerances() {
+    // b_1 and c_1 are round off errors
+    let a = vec![vec![-1., 1.], vec![1., 1.]];
+    let b = vec![-1e-12, 4.];
+    let c = vec![1e-12, -1.];
+    let mut slack_form = SlackFormLP::new(a, b, c).unwrap();
+
+    assert!(slack_form.is_primal_feasible());
+    assert!(slack_form.is_dual_feasible());
+    assert_eq!(
+        slack_form.find_entering_and_leaving(),
+        Ok(SimplexRound::Finished)
+    );
+
+    // Exact comparisons
+    let options = SolverOptions::new()
+        .set_primal_feasibility_tolerance(0.)
+        .unwrap()
+        .set_dual_feasibility_tolerance(0.)
+        .unwrap();
+    slack_form.set_options(options);
+
+    assert!(!slack_form.is_primal_feasible());
+    assert!(!slack_form.is_dual_feasible());
+    assert_ne!(
+        slack_form.find_entering_and_leaving(),
+        Ok(SimplexRound::Finished)
+    );
+}
+
+#[test]
+fn forms_slack_slackformlp_options_from_standard_form() {
+    let c = vec![1., 1.];
+    let a = vec![vec![1., 1.]];
+    let b = vec![2.];
+    let options = SolverOptions::new().set_pivot_tolerance(1e-6).unwrap();
+
+    let slack_form = StandardFormLP::new(c, a, b, None)
+        .unwrap()
+        .set_options(options.clone())
+        .into_slack_form()
+        .unwrap();
+
+    assert_eq!(slack_form.get_options(), &options);
+}
+
+#[test]
+fn forms_slack_slackformlp_harris_ratio_test() {
+    // Both rows block x_0 at 0, the second one has the largest pivot element
+    let a = vec![vec![-1e-3, 1.], vec![-1., 1.]];
+    let b = vec![0., 0.];
+    let c = vec![1., -1.];
+    let mut slack_form = SlackFormLP::new(a, b, c).unwrap();
+
+    assert_eq!(
+        slack_form.find_entering_and_leaving(),
+        Ok(SimplexRound::Switch(0, 0))
+    );
+
+    slack_form.set_options(SolverOptions::new().set_harris_ratio_test(true));
+
+    assert_eq!(
+        slack_form.find_entering_and_leaving(),
+        Ok(SimplexRound::Switch(0, 1))
+    );
+}
+
+#[test]
+fn forms_slack_slackformlp_display() {
+    let c = vec![1., 1.];
+    let a = vec![vec![1., 2.]];
+    let b = vec![2.];
+    let slack_form =
+        StandardFormLP::new(c, a, b, None).unwrap().into_slack_form().unwrap();
+
+    let display = format!("{}", slack_form);
+
+    assert!(display.starts_with("LP - Slack form:\nN: [0, 1]\nB: [2]\n"));
+    assert!(display.contains("\t[-1.0, -2.0]\n"));
+}
@@ -0,0 +1,47 @@
+use super::super::super::forms::solution::Solution;
+use super::super::super::types::error::SolverError;
+
+#[test]
+fn forms_solution_solution_new_rows_mismatch() {
+    let err =
+        Solution::new(1., vec![1.], vec![1., 2.], vec![0.], vec![0.], vec![0.])
+            .unwrap_err();
+
+    assert_eq!(
+        err,
+        SolverError::DimensionMismatch("row activities".into(), 1, 2)
+    );
+}
+
+#[test]
+fn forms_solution_solution_new_reduced_costs_mismatch() {
+    let err =
+        Solution::new(1., vec![1.], vec![1.], vec![0.], vec![0.], vec![0., 1.])
+            .unwrap_err();
+
+    assert_eq!(
+        err,
+        SolverError::DimensionMismatch("reduced costs".into(), 1, 2)
+    );
+}
+
+#[test]
+fn forms_solution_solution_into_min_objective() {
+    let solution = Solution::new(
+        3.,
+        vec![1., 2.],
+        vec![3.],
+        vec![1.],
+        vec![0.5],
+        vec![0., -1.],
+    )
+    .unwrap()
+    .into_min_objective();
+
+    assert_eq!(solution.get_objective_value(), -3.);
+    assert_eq!(solution.get_x(), &vec![1., 2.]);
+    assert_eq!(solution.get_row_activities(), &vec![3.]);
+    assert_eq!(solution.get_slacks(), &vec![1.]);
+    assert_eq!(solution.get_duals(), &vec![-0.5]);
+    assert_eq!(solution.get_reduced_costs(), &vec![0., 1.]);
+}
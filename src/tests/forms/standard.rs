@@ -1,6 +1,11 @@
+use super::super::super::forms::builder::StandardFormBuilder;
+use super::super::super::forms::slack::SimplexResult;
 use super::super::super::forms::standard::{
-    check_non_negative_indices, StandardFormLP,
+    check_integer_indices, check_non_negative_indices, check_variable_bounds,
+    StandardFormLP,
 };
+use super::super::super::types::error::SolverError;
+use super::super::helpers::assert_close;
 
 #[test]
 fn forms_standard_check_non_negative_indices_ok() {
@@ -23,8 +28,7 @@ fn forms_standard_check_non_negative_indices_empty_vec() {
 
     assert_eq!(
         err,
-        "It is not allowed to add an empty array of non negative indices."
-            .to_string()
+        SolverError::EmptyInput("array of non negative indices".into())
     );
 }
 
@@ -35,9 +39,11 @@ fn forms_standard_check_non_negative_indices_not_uniq() {
 
     assert_eq!(
         err,
-        "Non negative indices vector must be unique and sorted in ascending \
-         order."
-            .to_string()
+        SolverError::InvalidInput(
+            "Non negative indices vector must be unique and sorted in \
+             ascending order."
+                .into()
+        )
     );
 }
 
@@ -48,9 +54,11 @@ fn forms_standard_check_non_negative_indices_not_sorted() {
 
     assert_eq!(
         err,
-        "Non negative indices vector must be unique and sorted in ascending \
-         order."
-            .to_string()
+        SolverError::InvalidInput(
+            "Non negative indices vector must be unique and sorted in \
+             ascending order."
+                .into()
+        )
     );
 }
 
@@ -59,11 +67,7 @@ fn forms_standard_check_non_negative_indices_bad_dim_case_1() {
     let err =
         check_non_negative_indices(&Some(vec![0, 2]), Some(2)).unwrap_err();
 
-    assert_eq!(
-        err,
-        "Non negative indices are out of bound of the dimension size."
-            .to_string()
-    );
+    assert_eq!(err, SolverError::InvalidColumn(2));
 }
 
 #[test]
@@ -71,11 +75,7 @@ fn forms_standard_check_non_negative_indices_bad_dim_case_2() {
     let err =
         check_non_negative_indices(&Some(vec![0, 2]), Some(1)).unwrap_err();
 
-    assert_eq!(
-        err,
-        "Non negative indices are out of bound of the dimension size."
-            .to_string()
-    );
+    assert_eq!(err, SolverError::InvalidColumn(2));
 }
 
 #[test]
@@ -86,7 +86,7 @@ fn forms_standard_standardformlp_empty_vector_c() {
 
     let err = StandardFormLP::new(c, a, b, None).unwrap_err();
 
-    assert_eq!(err, "Vector 'c' should not be empty".to_string());
+    assert_eq!(err, SolverError::EmptyInput("vector 'c'".into()));
 }
 
 #[test]
@@ -97,7 +97,7 @@ fn forms_standard_standardformlp_empty_vector_b() {
 
     let err = StandardFormLP::new(c, a, b, None).unwrap_err();
 
-    assert_eq!(err, "Vector 'b' should not be empty".to_string());
+    assert_eq!(err, SolverError::EmptyInput("vector 'b'".into()));
 }
 
 #[test]
@@ -108,7 +108,7 @@ fn forms_standard_standardformlp_empty_matrix_a() {
 
     let err = StandardFormLP::new(c, a, b, None).unwrap_err();
 
-    assert_eq!(err, "Matrix 'a' should not be empty".to_string());
+    assert_eq!(err, SolverError::EmptyInput("matrix 'a'".into()));
 }
 
 #[test]
@@ -121,8 +121,7 @@ fn forms_standard_standardformlp_mismatch_matrix_a() {
 
     assert_eq!(
         err,
-        "Each and every row in the matrix 'a' should have the same size"
-            .to_string()
+        SolverError::DimensionMismatch("row 1 of the matrix 'a'".into(), 3, 1)
     );
 }
 
@@ -134,11 +133,7 @@ fn forms_standard_standardformlp_mismatch_matrix_a_vector_b() {
 
     let err = StandardFormLP::new(c, a, b, None).unwrap_err();
 
-    assert_eq!(
-        err,
-        "Matrix 'a' columns count does not match with vector 'b' size (2 != 3)"
-            .to_string()
-    );
+    assert_eq!(err, SolverError::DimensionMismatch("vector 'b'".into(), 2, 3));
 }
 
 #[test]
@@ -151,8 +146,7 @@ fn forms_standard_standardformlp_mismatch_matrix_a_vector_c() {
 
     assert_eq!(
         err,
-        "Matrix 'a' rows count does not match with vector 'c' size (3 != 4)"
-            .to_string()
+        SolverError::DimensionMismatch("row 0 of the matrix 'a'".into(), 4, 3)
     );
 }
 
@@ -170,8 +164,7 @@ fn forms_standard_standardformlp_empty_non_negative_indices() {
 
     assert_eq!(
         err,
-        "It is not allowed to add an empty array of non negative indices."
-            .to_string()
+        SolverError::EmptyInput("array of non negative indices".into())
     );
 }
 
@@ -189,9 +182,11 @@ fn forms_standard_standardformlp_unsorted_non_negative_indices() {
 
     assert_eq!(
         err,
-        "Non negative indices vector must be unique and sorted in ascending \
-         order."
-            .to_string()
+        SolverError::InvalidInput(
+            "Non negative indices vector must be unique and sorted in \
+             ascending order."
+                .into()
+        )
     );
 }
 
@@ -209,9 +204,11 @@ fn forms_standard_standardformlp_non_uniq_non_negative_indices() {
 
     assert_eq!(
         err,
-        "Non negative indices vector must be unique and sorted in ascending \
-         order."
-            .to_string()
+        SolverError::InvalidInput(
+            "Non negative indices vector must be unique and sorted in \
+             ascending order."
+                .into()
+        )
     );
 }
 
@@ -229,8 +226,10 @@ fn forms_standard_standardformlp_more_indices_non_negative_indices() {
 
     assert_eq!(
         err,
-        "Cannot be more non negative indices than the dimension size."
-            .to_string()
+        SolverError::InvalidInput(
+            "Cannot be more non negative indices than the dimension size."
+                .into()
+        )
     );
 }
 
@@ -244,13 +243,9 @@ fn forms_standard_standardformlp_out_of_bound_non_negative_indices() {
         vec![4.2, 8.5, 7.6188918901, 1., 2., 3.],
     ];
 
-    let err = StandardFormLP::new(c, a, b, Some(vec![0, 1, 3])).unwrap_err();
+    let err = StandardFormLP::new(c, a, b, Some(vec![0, 1, 6])).unwrap_err();
 
-    assert_eq!(
-        err,
-        "Non negative indices are out of bound of the dimension size."
-            .to_string()
-    );
+    assert_eq!(err, SolverError::InvalidColumn(6));
 }
 
 #[test]
@@ -279,6 +274,398 @@ fn forms_standard_standardformlp_with_non_negative_indices_should_be_created() {
     assert!(standard_form.is_ok());
 }
 
+#[test]
+fn forms_standard_check_variable_bounds_ok() {
+    assert!(check_variable_bounds(-1., 1.).is_ok());
+    assert!(check_variable_bounds(2., 2.).is_ok());
+    assert!(check_variable_bounds(0., f64::INFINITY).is_ok());
+    assert!(check_variable_bounds(f64::NEG_INFINITY, 1.).is_ok());
+    assert!(check_variable_bounds(f64::NEG_INFINITY, f64::INFINITY).is_ok());
+}
+
+#[test]
+fn forms_standard_check_variable_bounds_nan_lower() {
+    let err = check_variable_bounds(f64::NAN, 1.).unwrap_err();
+
+    assert_eq!(
+        err,
+        SolverError::InvalidInput("Lower bound should be a number.".into())
+    );
+}
+
+#[test]
+fn forms_standard_check_variable_bounds_infinite_lower() {
+    let err = check_variable_bounds(f64::INFINITY, f64::INFINITY).unwrap_err();
+
+    assert_eq!(
+        err,
+        SolverError::InvalidInput("Lower bound should not be infinity.".into())
+    );
+}
+
+#[test]
+fn forms_standard_check_variable_bounds_nan_upper() {
+    let err = check_variable_bounds(0., f64::NAN).unwrap_err();
+
+    assert_eq!(
+        err,
+        SolverError::InvalidInput("Upper bound should be a number.".into())
+    );
+}
+
+#[test]
+fn forms_standard_check_variable_bounds_infinite_upper() {
+    let err = check_variable_bounds(f64::NEG_INFINITY, f64::NEG_INFINITY)
+        .unwrap_err();
+
+    assert_eq!(
+        err,
+        SolverError::InvalidInput(
+            "Upper bound should not be minus infinity.".into()
+        )
+    );
+}
+
+#[test]
+fn forms_standard_check_variable_bounds_lower_greater_than_upper() {
+    let err = check_variable_bounds(1.5, 1.).unwrap_err();
+
+    assert_eq!(
+        err,
+        SolverError::InvalidInput(
+            "Lower bound is greater than upper bound (1.5 > 1)".into()
+        )
+    );
+}
+
+#[test]
+fn forms_standard_standardformlp_add_variable_bounds() {
+    let c = vec![10.2, 13.3, 14.18];
+    let b = vec![6.2, 8.12];
+    let a = vec![vec![1.1, 2.289719871981, 3.3], vec![4.4, 5.5, 6.6188918901]];
+
+    let standard_form = StandardFormLP::new(c, a, b, None).unwrap();
+    assert!(!standard_form.has_variable_bounds());
+
+    let standard_form = standard_form.add_variable_bounds(1, -2., 3.).unwrap();
+
+    assert!(standard_form.has_variable_bounds());
+    assert_eq!(standard_form.get_lower(), &vec![0., -2., 0.]);
+    assert_eq!(
+        standard_form.get_upper(),
+        &vec![f64::INFINITY, 3., f64::INFINITY]
+    );
+}
+
+#[test]
+fn forms_standard_standardformlp_add_variable_bounds_out_of_bound() {
+    let c = vec![10.2, 13.3, 14.18, 1.];
+    let b = vec![6.2, 8.12];
+    let a = vec![
+        vec![1.1, 2.289719871981, 3.3, 1.],
+        vec![4.4, 5.5, 6.6188918901, 1.],
+    ];
+
+    let err = StandardFormLP::new(c, a, b, Some(vec![0]))
+        .unwrap()
+        .add_variable_bounds(4, 0., 1.)
+        .unwrap_err();
+
+    assert_eq!(err, SolverError::InvalidColumn(4));
+}
+
+#[test]
+fn forms_standard_standardformlp_add_variable_bounds_non_negative_indices() {
+    let c = vec![10.2, 13.3, 14.18, 1.];
+    let b = vec![6.2, 8.12];
+    let a = vec![
+        vec![1.1, 2.289719871981, 3.3, 1.],
+        vec![4.4, 5.5, 6.6188918901, 1.],
+    ];
+
+    let standard_form = StandardFormLP::new(c, a, b, Some(vec![0, 2]))
+        .unwrap()
+        .add_variable_bounds(0, -1., 1.)
+        .unwrap();
+
+    // x_0 is bounded and no longer free, x_2 stays free
+    assert_eq!(standard_form.get_non_negative_indices(), &Some(vec![2]));
+    assert_eq!(
+        standard_form.get_lower(),
+        &vec![-1., 0., f64::NEG_INFINITY, 0.]
+    );
+    assert_eq!(
+        standard_form.get_upper(),
+        &vec![1., f64::INFINITY, f64::INFINITY, f64::INFINITY]
+    );
+
+    let standard_form = standard_form.add_variable_bounds(2, 0., 4.).unwrap();
+
+    assert_eq!(standard_form.get_non_negative_indices(), &None);
+    assert_eq!(standard_form.get_lower(), &vec![-1., 0., 0., 0.]);
+}
+
+#[test]
+fn forms_standard_standardformlp_into_slack_form_with_variable_bounds() {
+    let c = vec![1., 2.];
+    let b = vec![10.];
+    let a = vec![vec![1., 3.]];
+
+    let slack_form = StandardFormLP::new(c, a, b, None)
+        .unwrap()
+        .add_variable_bounds(0, 2., 4.)
+        .unwrap()
+        .into_slack_form()
+        .unwrap();
+
+    // x_2 = 10 - x_0 - 3 . x_1 = 8 - (x_0 - 2) - 3 . x_1
+    assert_eq!(slack_form.get_b(), &vec![8.]);
+    assert_eq!(slack_form.get_v(), 2.);
+    assert_eq!(slack_form.get_lower(), &vec![2., 0., 0.]);
+    assert_eq!(slack_form.get_upper(), &vec![4., f64::INFINITY, f64::INFINITY]);
+}
+
+#[test]
+fn forms_standard_standardformlp_add_row_range() {
+    let c = vec![10.2, 13.3, 14.18];
+    let b = vec![6.2, 8.12];
+    let a = vec![vec![1.1, 2.289719871981, 3.3], vec![4.4, 5.5, 6.6188918901]];
+
+    let standard_form = StandardFormLP::new(c, a, b, None).unwrap();
+    assert!(!standard_form.has_row_ranges());
+
+    let standard_form = standard_form.add_row_range(1, 0.).unwrap();
+
+    assert!(standard_form.has_row_ranges());
+    assert_eq!(standard_form.get_ranges(), &vec![f64::INFINITY, 0.]);
+}
+
+#[test]
+fn forms_standard_standardformlp_add_row_range_out_of_bound() {
+    let c = vec![10.2, 13.3, 14.18];
+    let b = vec![6.2, 8.12];
+    let a = vec![vec![1.1, 2.289719871981, 3.3], vec![4.4, 5.5, 6.6188918901]];
+
+    let err = StandardFormLP::new(c, a, b, None)
+        .unwrap()
+        .add_row_range(2, 0.)
+        .unwrap_err();
+
+    assert_eq!(err, SolverError::InvalidRow(2));
+}
+
+#[test]
+fn forms_standard_standardformlp_add_row_range_negative() {
+    let c = vec![10.2, 13.3, 14.18];
+    let b = vec![6.2, 8.12];
+    let a = vec![vec![1.1, 2.289719871981, 3.3], vec![4.4, 5.5, 6.6188918901]];
+
+    let err = StandardFormLP::new(c, a, b, None)
+        .unwrap()
+        .add_row_range(0, -1.)
+        .unwrap_err();
+
+    assert_eq!(
+        err,
+        SolverError::InvalidInput(
+            "Row range should be a non negative number.".into()
+        )
+    );
+}
+
+#[test]
+fn forms_standard_check_integer_indices() {
+    assert!(check_integer_indices(&[0, 2], Some(3)).is_ok());
+    assert_eq!(
+        check_integer_indices(&[], None).unwrap_err(),
+        SolverError::EmptyInput("array of integer indices".into())
+    );
+    assert_eq!(
+        check_integer_indices(&[1, 1], None).unwrap_err(),
+        SolverError::InvalidInput(
+            "Integer indices vector must be unique and sorted in ascending \
+             order."
+                .into()
+        )
+    );
+    assert_eq!(
+        check_integer_indices(&[0, 2], Some(2)).unwrap_err(),
+        SolverError::InvalidColumn(2)
+    );
+}
+
+#[test]
+fn forms_standard_standardformlp_add_integer_indices() {
+    let c = vec![1., 2.];
+    let b = vec![10., 4.];
+    let a = vec![vec![1., 3.], vec![1., -1.]];
+
+    let standard_form = StandardFormLP::new(c, a, b, None).unwrap();
+    assert!(!standard_form.has_integer_indices());
+
+    let standard_form = standard_form.add_integer_indices(vec![1]).unwrap();
+    assert!(standard_form.has_integer_indices());
+    assert_eq!(standard_form.get_integer_indices(), &vec![1]);
+    assert_eq!(
+        standard_form.select_rows(&[1]).unwrap().get_integer_indices(),
+        &vec![1]
+    );
+
+    assert_eq!(
+        standard_form.clone().add_integer_indices(vec![0]).unwrap_err(),
+        SolverError::InvalidState(
+            "Integer indices have already been set.".into()
+        )
+    );
+}
+
+#[test]
+fn forms_standard_standardformlp_select_rows() {
+    let c = vec![1., 2.];
+    let b = vec![10., 4., 3.];
+    let a = vec![vec![1., 3.], vec![1., -1.], vec![0., 1.]];
+
+    let standard_form = StandardFormLP::new(c, a, b, None)
+        .unwrap()
+        .add_row_range(2, 1.)
+        .unwrap()
+        .add_variable_bounds(0, 0., 5.)
+        .unwrap();
+    let selected = standard_form.select_rows(&[2, 0]).unwrap();
+
+    assert_eq!(selected.get_a(), &vec![vec![0., 1.], vec![1., 3.]]);
+    assert_eq!(selected.get_b(), &vec![3., 10.]);
+    assert_eq!(selected.get_ranges(), &vec![1., f64::INFINITY]);
+    assert_eq!(selected.get_upper(), &vec![5., f64::INFINITY]);
+}
+
+#[test]
+fn forms_standard_standardformlp_select_rows_out_of_bound() {
+    let c = vec![1., 2.];
+    let b = vec![10., 4.];
+    let a = vec![vec![1., 3.], vec![1., -1.]];
+
+    let standard_form = StandardFormLP::new(c, a, b, None).unwrap();
+
+    assert_eq!(
+        standard_form.select_rows(&[0, 2]).unwrap_err(),
+        SolverError::InvalidRow(2)
+    );
+    assert_eq!(
+        standard_form.select_rows(&[]).unwrap_err(),
+        SolverError::EmptyInput("selection of rows".into())
+    );
+}
+
+#[test]
+fn forms_standard_standardformlp_into_slack_form_with_row_ranges() {
+    let c = vec![1., 2.];
+    let b = vec![10., 4.];
+    let a = vec![vec![1., 3.], vec![1., -1.]];
+
+    let slack_form = StandardFormLP::new(c, a, b, None)
+        .unwrap()
+        .add_row_range(0, 0.)
+        .unwrap()
+        .into_slack_form()
+        .unwrap();
+
+    // Still one row per constraint, the slack variable x_2 is fixed to 0.0
+    assert_eq!(slack_form.get_B(), &vec![2, 3]);
+    assert_eq!(slack_form.get_lower(), &vec![0., 0., 0., 0.]);
+    assert_eq!(
+        slack_form.get_upper(),
+        &vec![f64::INFINITY, f64::INFINITY, 0., f64::INFINITY]
+    );
+}
+
+#[test]
+fn forms_standard_standardformlp_solve_max_objective() {
+    let c = vec![3., 1., 2.];
+    let a = vec![vec![1., 1., 3.], vec![2., 2., 5.], vec![4., 1., 2.]];
+    let b = vec![30., 24., 36.];
+
+    let mut standard_form = StandardFormLP::new(c, a, b, None).unwrap();
+    let result = standard_form.solve().unwrap();
+
+    if let SimplexResult::Optimal(solution) = result {
+        assert_eq!(solution.get_objective_value(), 28.);
+        assert_eq!(solution.get_x(), &vec![8., 4., 0.]);
+        assert_eq!(solution.get_row_activities(), &vec![12., 24., 36.]);
+        assert_eq!(solution.get_slacks(), &vec![18., 0., 0.]);
+        assert_close(solution.get_duals(), &[0., 1. / 6., 2. / 3.]);
+        assert_close(solution.get_reduced_costs(), &[0., 0., -1. / 6.]);
+    } else {
+        panic!("Optimal solution expected, got {:?}", result);
+    }
+    assert_eq!(standard_form.get_objective_value(), Some(28.));
+    assert_eq!(standard_form.get_x(), vec![8., 4., 0.]);
+}
+
+#[test]
+fn forms_standard_standardformlp_solve_min_objective() {
+    // min x_1 + x_2, with x_1 + 2 . x_2 >= 4 and 3 . x_1 + x_2 >= 6
+    let mut standard_form = StandardFormBuilder::new()
+        .add_min_objective(vec![1., 1.])
+        .unwrap()
+        .add_greater_than_or_equal_constraint(vec![1., 2.], 4.)
+        .unwrap()
+        .add_greater_than_or_equal_constraint(vec![3., 1.], 6.)
+        .unwrap()
+        .build()
+        .unwrap();
+    let result = standard_form.solve().unwrap();
+
+    // The duals are the ones of the min objective
+    if let SimplexResult::Optimal(solution) = result {
+        assert!((solution.get_objective_value() - 2.8).abs() < 1e-9);
+        assert_close(solution.get_duals(), &[-0.4, -0.2]);
+        assert_close(solution.get_reduced_costs(), &[0., 0.]);
+    } else {
+        panic!("Optimal solution expected, got {:?}", result);
+    }
+    let objective_value = standard_form.get_objective_value().unwrap();
+    assert!((objective_value - 2.8).abs() < 1e-9);
+    let x = standard_form.get_x();
+    assert!((x[0] - 1.6).abs() < 1e-9 && (x[1] - 1.2).abs() < 1e-9);
+}
+
+#[test]
+fn forms_standard_standardformlp_solve_free_variables() {
+    // min 2 . x_1 + x_2, with x_1 >= -3 and x_1 + x_2 >= -1, x_1 is free
+    let mut standard_form = StandardFormBuilder::new()
+        .add_non_negative_indices(vec![0])
+        .unwrap()
+        .add_min_objective(vec![2., 1.])
+        .unwrap()
+        .add_greater_than_or_equal_constraint(vec![1., 0.], -3.)
+        .unwrap()
+        .add_greater_than_or_equal_constraint(vec![1., 1.], -1.)
+        .unwrap()
+        .build()
+        .unwrap();
+    standard_form.solve().unwrap();
+
+    // One value per variable of the builder
+    assert_eq!(standard_form.get_objective_value(), Some(-4.));
+    assert_eq!(standard_form.get_x(), vec![-3., 2.]);
+}
+
+#[test]
+fn forms_standard_standardformlp_solve_unfeasible() {
+    let c = vec![1., 1.];
+    let a = vec![vec![1., 1.], vec![-1., -1.]];
+    let b = vec![1., -2.];
+
+    let mut standard_form = StandardFormLP::new(c, a, b, None).unwrap();
+    let result = standard_form.solve().unwrap();
+
+    // Sum of both rows: 0 <= -1 (scaled by the auxiliary problem)
+    assert_eq!(result, SimplexResult::Unfeasible(vec![0.5, 0.5]));
+    assert_eq!(standard_form.get_objective_value(), None);
+    assert_eq!(standard_form.get_x(), Vec::<f64>::new());
+}
+
 #[ignore]
 #[test]
 fn forms_standard_standardformlp_just_print() {
@@ -289,3 +676,17 @@ fn forms_standard_standardformlp_just_print() {
     let standard_form = StandardFormLP::new(c, a, b, None).unwrap();
     println!("{}", standard_form);
 }
+
+#[test]
+fn forms_standard_standardformlp_display() {
+    let c = vec![1., 1.];
+    let a = vec![vec![1., 2.]];
+    let b = vec![2.];
+    let standard_form = StandardFormLP::new(c, a, b, None).unwrap();
+
+    assert_eq!(
+        format!("{}", standard_form),
+        "LP - Standard form:\nc: [1.0, 1.0]\na:\n\t[1.0, 2.0]\nb: \
+         [2.0]\nlower: [0.0, 0.0]\nupper: [inf, inf]\nranges: [inf]\n"
+    );
+}
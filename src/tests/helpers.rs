@@ -0,0 +1,90 @@
+use super::super::forms::slack::SimplexResult;
+
+/// Tolerance of the comparisons, for the results of the simplex.
+pub(crate) const TOLERANCE: f64 = 1e-9;
+/// Tolerance of the comparisons, for the results of the interior point
+/// method (and of the crossover that starts from them).
+pub(crate) const INTERIOR_TOLERANCE: f64 = 1e-6;
+
+fn dot(u: &[f64], v: &[f64]) -> f64 {
+    u.iter().zip(v.iter()).map(|(l, r)| l * r).sum()
+}
+
+pub(crate) fn assert_close(values: &[f64], expected: &[f64]) {
+    assert_close_within(values, expected, TOLERANCE);
+}
+
+pub(crate) fn assert_close_within(
+    values: &[f64],
+    expected: &[f64],
+    tolerance: f64,
+) {
+    assert_eq!(values.len(), expected.len());
+    for (l, r) in values.iter().zip(expected.iter()) {
+        assert!((l - r).abs() < tolerance, "{:?} != {:?}", values, expected);
+    }
+}
+
+pub(crate) fn assert_optimal(result: &SimplexResult, expected: &[f64]) {
+    assert_optimal_within(result, expected, TOLERANCE);
+}
+
+pub(crate) fn assert_optimal_within(
+    result: &SimplexResult,
+    expected: &[f64],
+    tolerance: f64,
+) {
+    if let SimplexResult::Optimal(solution) = result {
+        assert_close_within(solution.get_x(), expected, tolerance);
+    } else {
+        panic!("Optimal solution expected, got {:?}", result);
+    }
+}
+
+pub(crate) fn assert_optimal_value(
+    result: &SimplexResult,
+    expected: &[f64],
+    value: f64,
+) {
+    assert_optimal(result, expected);
+    if let SimplexResult::Optimal(solution) = result {
+        assert!((solution.get_objective_value() - value).abs() < TOLERANCE);
+    }
+}
+
+/// y >= 0, y^T . a >= 0 and y^T . b < 0
+pub(crate) fn assert_farkas(result: &SimplexResult, a: &[Vec<f64>], b: &[f64]) {
+    if let SimplexResult::Unfeasible(y) = result {
+        assert_eq!(y.len(), b.len());
+        assert!(y.iter().all(|y_i| *y_i >= 0.), "{:?}", y);
+        for j in 0..a[0].len() {
+            let y_a: f64 =
+                a.iter().zip(y.iter()).map(|(row, y_i)| row[j] * y_i).sum();
+            assert!(y_a >= -TOLERANCE, "{:?}", y);
+        }
+        assert!(dot(b, y) < 0., "{:?}", y);
+    } else {
+        panic!("Farkas certificate expected, got {:?}", result);
+    }
+}
+
+/// a . point <= b (unless the point is empty), a . direction <= 0 and
+/// c^T . direction > 0
+pub(crate) fn assert_ray(
+    result: &SimplexResult,
+    c: &[f64],
+    a: &[Vec<f64>],
+    b: &[f64],
+) {
+    if let SimplexResult::Unbounded(point, direction) = result {
+        for (row, b_i) in a.iter().zip(b.iter()) {
+            if !point.is_empty() {
+                assert!(dot(row, point) <= b_i + TOLERANCE, "{:?}", point);
+            }
+            assert!(dot(row, direction) <= TOLERANCE, "{:?}", direction);
+        }
+        assert!(dot(c, direction) > 0., "{:?}", direction);
+    } else {
+        panic!("Unbounded ray expected, got {:?}", result);
+    }
+}
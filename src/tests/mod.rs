@@ -1,3 +1,5 @@
 mod algo;
 mod forms;
+mod helpers;
 mod shared;
+mod types;
@@ -0,0 +1,49 @@
+use super::super::super::shared::cholesky::CholeskyFactorization;
+use super::super::super::types::error::SolverError;
+
+#[test]
+fn shared_cholesky_choleskyfactorization_empty_matrix() {
+    let err = CholeskyFactorization::new(&Vec::new()).unwrap_err();
+
+    assert_eq!(err, SolverError::EmptyInput("matrix to factorize".into()));
+}
+
+#[test]
+fn shared_cholesky_choleskyfactorization_not_square_matrix() {
+    let err =
+        CholeskyFactorization::new(&[vec![1., 2.], vec![3.]]).unwrap_err();
+
+    assert_eq!(
+        err,
+        SolverError::DimensionMismatch(
+            "row 1 of the matrix to factorize".into(),
+            2,
+            1
+        )
+    );
+}
+
+#[test]
+fn shared_cholesky_choleskyfactorization_solve() {
+    let matrix = vec![vec![4., 2., -2.], vec![2., 10., 4.], vec![-2., 4., 9.]];
+    let cholesky = CholeskyFactorization::new(&matrix).unwrap();
+
+    let x = cholesky.solve(&[2., 16., 11.]);
+
+    for (row, expected) in matrix.iter().zip([2., 16., 11.].iter()) {
+        let value: f64 = row.iter().zip(x.iter()).map(|(a, b)| a * b).sum();
+        assert!((value - expected).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn shared_cholesky_choleskyfactorization_semi_definite() {
+    // Second row is twice the first one, its component is neutralized
+    let matrix = vec![vec![1., 2.], vec![2., 4.]];
+    let cholesky = CholeskyFactorization::new(&matrix).unwrap();
+
+    let x = cholesky.solve(&[3., 6.]);
+
+    assert!((x[0] - 3.).abs() < 1e-9);
+    assert!(x[1].abs() < 1e-9);
+}
@@ -0,0 +1,141 @@
+use super::super::super::shared::lu::LUFactorization;
+use super::super::super::types::error::SolverError;
+use super::super::helpers::assert_close;
+
+fn multiply(matrix: &[Vec<f64>], x: &[f64]) -> Vec<f64> {
+    matrix
+        .iter()
+        .map(|row| row.iter().zip(x).map(|(a, b)| a * b).sum())
+        .collect()
+}
+
+fn multiply_transpose(matrix: &[Vec<f64>], y: &[f64]) -> Vec<f64> {
+    (0..matrix.len())
+        .map(|j| matrix.iter().zip(y).map(|(row, b)| row[j] * b).sum())
+        .collect()
+}
+
+#[test]
+fn shared_lu_lufactorization_empty_matrix() {
+    let err = LUFactorization::new(Vec::new()).unwrap_err();
+
+    assert_eq!(err, SolverError::EmptyInput("matrix to factorize".into()));
+}
+
+#[test]
+fn shared_lu_lufactorization_not_square_matrix() {
+    let err =
+        LUFactorization::new(vec![vec![1., 2.], vec![3., 4., 5.]]).unwrap_err();
+
+    assert_eq!(
+        err,
+        SolverError::DimensionMismatch(
+            "row 1 of the matrix to factorize".into(),
+            2,
+            3
+        )
+    );
+}
+
+#[test]
+fn shared_lu_lufactorization_singular_matrix() {
+    let err =
+        LUFactorization::new(vec![vec![1., 2.], vec![2., 4.]]).unwrap_err();
+
+    assert_eq!(
+        err,
+        SolverError::NumericalBreakdown(
+            "Matrix is singular, no pivot found at column 1.".into()
+        )
+    );
+}
+
+#[test]
+fn shared_lu_lufactorization_solve() {
+    let matrix = vec![vec![0., 2., 1.], vec![4., -1., 3.], vec![2., 5., -2.]];
+    let lu = LUFactorization::new(matrix.clone()).unwrap();
+
+    let x = lu.solve(&[3., 1., 7.]);
+    assert_close(&multiply(&matrix, &x), &[3., 1., 7.]);
+
+    let y = lu.solve_transpose(&[-1., 2., 0.5]);
+    assert_close(&multiply_transpose(&matrix, &y), &[-1., 2., 0.5]);
+}
+
+#[test]
+fn shared_lu_lufactorization_from_columns() {
+    let matrix = vec![
+        vec![0., 2., 0., 1.],
+        vec![4., 0., 0., 3.],
+        vec![0., 5., -2., 0.],
+        vec![1., 0., 0., 0.],
+    ];
+    let columns = [
+        vec![(1, 4.), (3, 1.)],
+        vec![(0, 2.), (2, 5.)],
+        vec![(2, -2.)],
+        vec![(0, 1.), (1, 3.)],
+    ];
+    let columns: Vec<&[(usize, f64)]> =
+        columns.iter().map(|column| column.as_slice()).collect();
+    let lu = LUFactorization::from_columns(4, &columns).unwrap();
+
+    assert_eq!(lu, LUFactorization::new(matrix.clone()).unwrap());
+
+    let x = lu.solve(&[3., 1., 7., -1.]);
+    assert_close(&multiply(&matrix, &x), &[3., 1., 7., -1.]);
+
+    let y = lu.solve_transpose(&[-1., 2., 0.5, 4.]);
+    assert_close(&multiply_transpose(&matrix, &y), &[-1., 2., 0.5, 4.]);
+}
+
+#[test]
+fn shared_lu_lufactorization_from_columns_invalid_row() {
+    let columns = [vec![(0, 1.)], vec![(2, 1.)]];
+    let columns: Vec<&[(usize, f64)]> =
+        columns.iter().map(|column| column.as_slice()).collect();
+
+    let err = LUFactorization::from_columns(2, &columns).unwrap_err();
+
+    assert_eq!(err, SolverError::InvalidRow(2));
+}
+
+#[test]
+fn shared_lu_lufactorization_update() {
+    let mut matrix =
+        vec![vec![0., 2., 1.], vec![4., -1., 3.], vec![2., 5., -2.]];
+    let mut lu = LUFactorization::new(matrix.clone()).unwrap();
+
+    // Replace the columns 1 then 0
+    let new_columns = vec![(1, vec![1., 1., 1.]), (0, vec![3., 0., -1.])];
+    for (col, column) in new_columns.into_iter() {
+        let transformed = lu.solve(&column);
+        lu.update(col, transformed).unwrap();
+        for (row, value) in matrix.iter_mut().zip(column.iter()) {
+            row[col] = *value;
+        }
+    }
+    assert_eq!(lu.get_eta_count(), 2);
+
+    let x = lu.solve(&[3., 1., 7.]);
+    assert_close(&multiply(&matrix, &x), &[3., 1., 7.]);
+
+    let y = lu.solve_transpose(&[-1., 2., 0.5]);
+    assert_close(&multiply_transpose(&matrix, &y), &[-1., 2., 0.5]);
+}
+
+#[test]
+fn shared_lu_lufactorization_update_zero_pivot() {
+    let mut lu =
+        LUFactorization::new(vec![vec![1., 0.], vec![0., 1.]]).unwrap();
+
+    let err = lu.update(0, vec![0., 1.]).unwrap_err();
+
+    assert_eq!(
+        err,
+        SolverError::NumericalBreakdown(
+            "Pivot element is zero, the updated matrix would be singular."
+                .into()
+        )
+    );
+}
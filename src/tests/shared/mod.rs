@@ -1 +1,3 @@
+mod cholesky;
+mod lu;
 mod utils;
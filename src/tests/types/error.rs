@@ -0,0 +1,61 @@
+use std::error::Error;
+
+use super::super::super::forms::builder::StandardFormBuilder;
+use super::super::super::forms::slack::Interruption;
+use super::super::super::forms::standard::StandardFormLP;
+use super::super::super::types::error::SolverError;
+
+#[test]
+fn types_error_solvererror_display() {
+    assert_eq!(
+        SolverError::EmptyInput("vector 'b'".into()).to_string(),
+        "The vector 'b' should not be empty."
+    );
+    assert_eq!(
+        SolverError::DimensionMismatch("row 1 of the matrix 'a'".into(), 3, 1)
+            .to_string(),
+        "The size of the row 1 of the matrix 'a' does not match (3 expected, \
+         1 found)."
+    );
+    assert_eq!(
+        SolverError::InvalidRow(2).to_string(),
+        "Row index 2 is out of bound."
+    );
+    assert_eq!(
+        SolverError::InvalidColumn(4).to_string(),
+        "Variable index 4 is out of bound."
+    );
+    assert_eq!(
+        SolverError::InvalidInput("Lower bound should be a number.".into())
+            .to_string(),
+        "Lower bound should be a number."
+    );
+    assert_eq!(
+        SolverError::Interrupted(Interruption::TimeLimit).to_string(),
+        "The solve has been interrupted (time limit)."
+    );
+    assert_eq!(
+        SolverError::Internal("slack variable has not been found.".into())
+            .to_string(),
+        "The impossible happened, slack variable has not been found."
+    );
+}
+
+#[test]
+fn types_error_solvererror_as_std_error() {
+    let build = || -> Result<StandardFormLP, Box<dyn Error>> {
+        let standard_form = StandardFormBuilder::new()
+            .add_max_objective(vec![1., 1.])?
+            .add_less_than_or_equal_constraint(vec![1., 1., 1.], 2.)?
+            .build()?;
+
+        Ok(standard_form)
+    };
+
+    let err = build().unwrap_err();
+
+    assert_eq!(
+        err.downcast_ref::<SolverError>(),
+        Some(&SolverError::DimensionMismatch("added vector".into(), 2, 3))
+    );
+}
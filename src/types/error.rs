@@ -0,0 +1,55 @@
+use std::error::Error;
+use std::fmt;
+
+use super::super::forms::slack::Interruption;
+
+/// Error of the solver.
+///
+/// The indices are the ones of the input: rows of the matrix 'a' (or of the
+/// dictionary) and variables (or columns of the dictionary).
+#[derive(Debug, PartialEq, Clone)]
+pub enum SolverError {
+    EmptyInput(String),                      // Input
+    DimensionMismatch(String, usize, usize), // Input, expected, found
+    InvalidRow(usize),                       // Row index
+    InvalidColumn(usize),                    // Variable (or column) index
+    InvalidInput(String),                    // Value which is not accepted
+    InvalidState(String),                    // Operation not possible yet
+    NumericalBreakdown(String),              // Singular matrix, no convergence
+    Unsolvable(String),                      // No result for this program
+    Interrupted(Interruption),               // Limit of the solver options
+    Internal(String),                        // The impossible happened
+}
+
+impl fmt::Display for SolverError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SolverError::EmptyInput(input) => {
+                write!(f, "The {} should not be empty.", input)
+            },
+            SolverError::DimensionMismatch(input, expected, found) => write!(
+                f,
+                "The size of the {} does not match ({} expected, {} found).",
+                input, expected, found
+            ),
+            SolverError::InvalidRow(row) => {
+                write!(f, "Row index {} is out of bound.", row)
+            },
+            SolverError::InvalidColumn(col) => {
+                write!(f, "Variable index {} is out of bound.", col)
+            },
+            SolverError::InvalidInput(msg)
+            | SolverError::InvalidState(msg)
+            | SolverError::NumericalBreakdown(msg)
+            | SolverError::Unsolvable(msg) => write!(f, "{}", msg),
+            SolverError::Interrupted(interruption) => {
+                write!(f, "The solve has been interrupted ({}).", interruption)
+            },
+            SolverError::Internal(msg) => {
+                write!(f, "The impossible happened, {}", msg)
+            },
+        }
+    }
+}
+
+impl Error for SolverError {}
@@ -1 +1 @@
-
+pub mod error;